@@ -1,43 +1,309 @@
 use std::collections::HashMap;
 use std::env;
+use std::path::{Path, PathBuf};
+
+/// Every SIMD backend flag `build_stringzilla` ever tries, across every architecture it targets.
+/// Kept as one flat list (rather than split by arch) so `emit_simd_backend_cfgs` can declare every
+/// `sz_has_<backend>` name up front regardless of which ones actually apply to this target.
+const SIMD_BACKENDS: &[&str] = &[
+    "SZ_USE_SVE2_AES",
+    "SZ_USE_SVE2",
+    "SZ_USE_SVE",
+    "SZ_USE_NEON_AES",
+    "SZ_USE_NEON",
+    "SZ_USE_ICE",
+    "SZ_USE_SKYLAKE",
+    "SZ_USE_HASWELL",
+    "SZ_USE_GOLDMONT",
+    "SZ_USE_WESTMERE",
+];
 
 fn main() {
     // Build stringzilla (always included, single-string operations)
     let serial_flags = build_stringzilla();
+    emit_simd_backend_cfgs(&serial_flags);
 
     // Build stringzillas (multi-string operations) if any feature is enabled
     if env::var("CARGO_FEATURE_CPUS").is_ok()
         || env::var("CARGO_FEATURE_CUDA").is_ok()
         || env::var("CARGO_FEATURE_ROCM").is_ok()
+        || env::var("CARGO_FEATURE_METAL").is_ok()
     {
         build_stringzillas(&serial_flags);
     }
 }
 
+/// Maps a `SZ_USE_*` backend flag to the `sz_has_*` cfg name exposed to the Rust bindings, e.g.
+/// `SZ_USE_SVE2_AES` -> `sz_has_sve2_aes`.
+fn simd_backend_cfg_name(flag: &str) -> String {
+    format!("sz_has_{}", flag.trim_start_matches("SZ_USE_").to_lowercase())
+}
+
+/// Declares every possible `sz_has_<backend>` cfg up front, via `rustc-check-cfg`, so
+/// `#[cfg(sz_has_ice)]`-style code never trips `unexpected_cfgs` on a target where that backend
+/// wasn't even attempted, then emits `rustc-cfg` for the ones `build_stringzilla` actually managed
+/// to compile. Lets the Rust bindings conditionally expose APIs (AES-accelerated hashing, SVE2
+/// helpers, ...) only when the matching kernel made it into the built object.
+fn emit_simd_backend_cfgs(flags: &HashMap<String, bool>) {
+    for backend in SIMD_BACKENDS {
+        println!("cargo::rustc-check-cfg=cfg({})", simd_backend_cfg_name(backend));
+    }
+    for backend in SIMD_BACKENDS {
+        if flags.get(*backend).copied().unwrap_or(false) {
+            println!("cargo::rustc-cfg={}", simd_backend_cfg_name(backend));
+        }
+    }
+}
+
+/// Maps Cargo's `OPT_LEVEL` env var (`0`/`1`/`2`/`3`/`s`/`z`) to the matching compiler `-O` flag,
+/// defaulting to `-O2` (this crate's historical default) when the var is unset or unrecognized.
+fn opt_level_flag() -> &'static str {
+    match env::var("OPT_LEVEL").as_deref() {
+        Ok("0") => "-O0",
+        Ok("1") => "-O1",
+        Ok("3") => "-O3",
+        Ok("s") => "-Os",
+        Ok("z") => "-Oz",
+        _ => "-O2",
+    }
+}
+
+/// Escape hatch mirroring cc-rs's own `CRATE_CC_NO_DEFAULTS`: when set, this crate stops adding
+/// its own optimization/`-fPIC` flags, leaving the compiler's own defaults (and any `CFLAGS`/
+/// `CXXFLAGS` the caller supplied) in full control.
+fn cc_no_defaults() -> bool {
+    env::var("SZ_CC_NO_DEFAULTS").is_ok()
+}
+
+/// Splits a `CFLAGS`/`CXXFLAGS`-style whitespace-separated flag string into individual flags, so
+/// user-supplied optimization or `-march` settings can be appended after this crate's own and
+/// take precedence (the same order cc-rs itself uses for these variables).
+fn user_flags(var: &str) -> Vec<String> {
+    env::var(var).map(|value| value.split_whitespace().map(str::to_string).collect()).unwrap_or_default()
+}
+
+/// A standalone `.c` snippet exercising a SIMD backend's key intrinsics, plus the minimal compiler
+/// flags (e.g. `-mavx512f`) needed to even parse them. Used by [`compile_capability_probe`] to test
+/// whether the target compiler supports a backend without paying for a full library recompile.
+struct CapabilityProbe {
+    flags: &'static [&'static str],
+    source: &'static str,
+}
+
+/// Returns the capability probe for a `SZ_USE_*` backend flag, or `None` for flags this probing
+/// scheme doesn't cover (there are none today, but new entries in [`SIMD_BACKENDS`] should get one
+/// here before being added to a `flags_to_try` list).
+fn capability_probe(backend: &str) -> Option<CapabilityProbe> {
+    match backend {
+        "SZ_USE_ICE" => Some(CapabilityProbe {
+            flags: &["-mavx512f", "-mavx512vbmi2", "-mavx512bw", "-mavx512vl"],
+            source: "#include <immintrin.h>\nint main(void) { __m512i a = _mm512_setzero_si512(); a = _mm512_add_epi8(a, a); return _mm512_cmpeq_epi8_mask(a, a) & 1; }\n",
+        }),
+        "SZ_USE_SKYLAKE" => Some(CapabilityProbe {
+            flags: &["-mavx512f", "-mavx512bw", "-mavx512vl", "-mavx512dq"],
+            source: "#include <immintrin.h>\nint main(void) { __m512i a = _mm512_setzero_si512(); return (int)_mm512_cmpeq_epi8_mask(a, a) & 1; }\n",
+        }),
+        "SZ_USE_HASWELL" => Some(CapabilityProbe {
+            flags: &["-mavx2", "-mbmi2"],
+            source: "#include <immintrin.h>\nint main(void) { __m256i a = _mm256_setzero_si256(); return _mm256_movemask_epi8(a) & 1; }\n",
+        }),
+        "SZ_USE_GOLDMONT" => Some(CapabilityProbe {
+            flags: &["-msse4.2"],
+            source: "#include <nmmintrin.h>\nint main(void) { return (int)_mm_crc32_u8(0, 0); }\n",
+        }),
+        "SZ_USE_WESTMERE" => Some(CapabilityProbe {
+            flags: &["-mssse3"],
+            source: "#include <tmmintrin.h>\nint main(void) { __m128i a = _mm_setzero_si128(); a = _mm_abs_epi8(a); return _mm_movemask_epi8(a) & 1; }\n",
+        }),
+        "SZ_USE_SVE2_AES" => Some(CapabilityProbe {
+            flags: &["-march=armv9-a+sve2-aes"],
+            source: "#include <arm_sve.h>\nint main(void) { svuint8_t a = svdup_u8(0); a = svaesmc_u8(a); return (int)svaddv_u8(svptrue_b8(), a) & 1; }\n",
+        }),
+        "SZ_USE_SVE2" => Some(CapabilityProbe {
+            flags: &["-march=armv8-a+sve2"],
+            source: "#include <arm_sve.h>\nint main(void) { svuint8_t a = svdup_u8(0); a = svxar_n_u8(a, a, 1); return (int)svaddv_u8(svptrue_b8(), a) & 1; }\n",
+        }),
+        "SZ_USE_SVE" => Some(CapabilityProbe {
+            flags: &["-march=armv8-a+sve"],
+            source: "#include <arm_sve.h>\nint main(void) { svuint8_t a = svdup_u8(0); return (int)svaddv_u8(svptrue_b8(), a) & 1; }\n",
+        }),
+        "SZ_USE_NEON_AES" => Some(CapabilityProbe {
+            flags: &["-march=armv8-a+crypto"],
+            source: "#include <arm_neon.h>\nint main(void) { uint8x16_t a = vdupq_n_u8(0); a = vaeseq_u8(a, a); return vgetq_lane_u8(a, 0) & 1; }\n",
+        }),
+        "SZ_USE_NEON" => Some(CapabilityProbe {
+            flags: &["-march=armv8-a"],
+            source: "#include <arm_neon.h>\nint main(void) { uint8x16_t a = vdupq_n_u8(0); a = vaddq_u8(a, a); return vgetq_lane_u8(a, 0) & 1; }\n",
+        }),
+        _ => None,
+    }
+}
+
+/// Compiles `backend`'s [`CapabilityProbe`] snippet in isolation and reports whether the target
+/// compiler accepts it. Far cheaper than recompiling the whole library just to find out one ISA
+/// extension isn't supported, and it can't be confused by unrelated errors elsewhere in the file.
+fn compile_capability_probe(out_dir: &Path, backend: &str, probe: &CapabilityProbe) -> bool {
+    let snippet_path = out_dir.join(format!("simd_probe_{}.c", backend.to_lowercase()));
+    if std::fs::write(&snippet_path, probe.source).is_err() {
+        return false;
+    }
+    let mut probe_build = cc::Build::new();
+    probe_build.file(&snippet_path).warnings(false).cargo_metadata(false);
+    for flag in probe.flags {
+        probe_build.flag_if_supported(flag);
+    }
+    probe_build.try_compile(&format!("simd_probe_{}", backend.to_lowercase())).is_ok()
+}
+
+/// Path to the on-disk cache of probe outcomes, scoped to this build's `OUT_DIR` (which is already
+/// specific to the target triple and profile) so a `cargo clean` or a new target naturally starts
+/// from a cold cache instead of ever reading a stale one.
+fn probe_cache_path(out_dir: &Path) -> PathBuf {
+    out_dir.join("simd_probe_cache.txt")
+}
+
+/// Loads a previously-saved probe cache as `"compiler|target|backend" -> supported` entries, one
+/// per line as `key=0` / `key=1`. Missing or unreadable caches are treated as empty, not an error.
+fn load_probe_cache(path: &Path) -> HashMap<String, bool> {
+    std::fs::read_to_string(path)
+        .map(|contents| {
+            contents
+                .lines()
+                .filter_map(|line| {
+                    let (key, value) = line.split_once('=')?;
+                    Some((key.to_string(), value == "1"))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Persists the probe cache back to `path`. Best-effort: a write failure just means the next build
+/// re-probes, so it's silently ignored rather than failing the build over a cache miss.
+fn save_probe_cache(path: &Path, cache: &HashMap<String, bool>) {
+    let mut contents = String::new();
+    for (key, supported) in cache {
+        contents.push_str(key);
+        contents.push('=');
+        contents.push_str(if *supported { "1" } else { "0" });
+        contents.push('\n');
+    }
+    let _ = std::fs::write(path, contents);
+}
+
+/// Splits a `SZ_FORCE_SIMD`/`SZ_DISABLE_SIMD`-style comma-separated list of bare backend names
+/// (e.g. `"haswell,neon"`) into their `SZ_USE_*` flag form, dropping (and warning about) any name
+/// that doesn't match an entry in [`SIMD_BACKENDS`].
+fn simd_name_list_to_flags(var: &str) -> Vec<&'static str> {
+    env::var(var)
+        .map(|value| {
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .filter_map(|name| {
+                    let flag = format!("SZ_USE_{}", name.to_uppercase());
+                    match SIMD_BACKENDS.iter().find(|backend| **backend == flag) {
+                        Some(&matched) => Some(matched),
+                        None => {
+                            println!("cargo:warning={var} names unknown SIMD backend '{name}', ignoring");
+                            None
+                        }
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Reports whether `backend` is supported, consulting (and updating) `cache` first so repeated
+/// builds against the same compiler and target skip the actual probe compile entirely.
+fn probe_backend_supported(
+    cache_key_prefix: &str,
+    backend: &str,
+    out_dir: &Path,
+    cache: &mut HashMap<String, bool>,
+    cache_path: &Path,
+) -> bool {
+    let key = format!("{cache_key_prefix}|{backend}");
+    if let Some(&supported) = cache.get(&key) {
+        return supported;
+    }
+    let supported = capability_probe(backend).is_some_and(|probe| compile_capability_probe(out_dir, backend, &probe));
+    cache.insert(key, supported);
+    save_probe_cache(cache_path, cache);
+    supported
+}
+
+/// True for targets that lack a hosted libc: WASM, SGX enclaves, bare-metal `*-none-*` triples,
+/// and NVPTX, mirroring the special-casing `compiler_builtins`'s build script applies to the same
+/// families. On these, `SZ_AVOID_LIBC` must be set and the SIMD `flags_to_try` probing (which
+/// assumes a hosted x86_64/aarch64 toolchain) doesn't apply at all.
+fn is_freestanding_target(target_os: &str, target_arch: &str, target_env: &str) -> bool {
+    target_arch.starts_with("wasm") || target_arch.starts_with("nvptx") || target_os == "none" || target_env == "sgx"
+}
+
 /// Build the StringZilla C library with dynamic SIMD dispatching
 /// and returns a dictionary of enabled compilation flags to be reused for
 /// parallel backends (e.g., StringZillas).
 fn build_stringzilla() -> HashMap<String, bool> {
     let mut flags = HashMap::<String, bool>::new();
+    let no_defaults = cc_no_defaults();
     let mut build = cc::Build::new();
     build
         .file("c/stringzilla.c")
         .include("include")
         .warnings(false)
         .define("SZ_DYNAMIC_DISPATCH", "1")
-        .define("SZ_AVOID_LIBC", "0")
-        .define("SZ_DEBUG", "0")
-        .flag("-O2")
+        .define("SZ_DEBUG", "0");
+
+    println!("cargo:rerun-if-env-changed=OPT_LEVEL");
+    println!("cargo:rerun-if-env-changed=SZ_CC_NO_DEFAULTS");
+    println!("cargo:rerun-if-env-changed=CFLAGS");
+    println!("cargo:rerun-if-env-changed=CXXFLAGS");
+
+    if !no_defaults {
+        build.flag(opt_level_flag());
+    }
+    build
         .flag("-std=c99") // Enforce C99 standard
-        .flag_if_supported("-fdiagnostics-color=always")
-        .flag_if_supported("-fPIC");
+        .flag_if_supported("-fdiagnostics-color=always");
+    // User-supplied CFLAGS come after this crate's own defaults above, so they take precedence.
+    for flag in user_flags("CFLAGS") {
+        build.flag(&flag);
+    }
 
     // Cargo will set different environment variables that we can use to properly configure the build.
     // https://doc.rust-lang.org/cargo/reference/environment-variables.html#environment-variables-cargo-sets-for-build-scripts
     // https://doc.rust-lang.org/reference/conditional-compilation.html#r-cfg.target_endian
     let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    let target_env = env::var("CARGO_CFG_TARGET_ENV").unwrap_or_default();
     let target_endian = env::var("CARGO_CFG_TARGET_ENDIAN").unwrap_or_default();
     let target_bits = env::var("CARGO_CFG_TARGET_POINTER_WIDTH").unwrap_or_default();
+    let freestanding = is_freestanding_target(&target_os, &target_arch, &target_env);
+
+    if freestanding {
+        build.define("SZ_AVOID_LIBC", "1");
+        flags.insert("SZ_AVOID_LIBC".to_string(), true);
+    } else {
+        build.define("SZ_AVOID_LIBC", "0");
+        if !no_defaults {
+            build.flag_if_supported("-fPIC");
+        }
+        flags.insert("SZ_AVOID_LIBC".to_string(), false);
+    }
+
+    // WASM SIMD is opt-in: unlike the hosted-target SIMD backends below, it isn't probed, since
+    // there's no fallback recompile path once a `.wasm` module is shipped without it.
+    if target_arch == "wasm32" && env::var("SZ_USE_WASM_SIMD128").is_ok() {
+        build.define("SZ_USE_WASM_SIMD128", "1");
+        flags.insert("SZ_USE_WASM_SIMD128".to_string(), true);
+    } else {
+        build.define("SZ_USE_WASM_SIMD128", "0");
+        flags.insert("SZ_USE_WASM_SIMD128".to_string(), false);
+    }
+    println!("cargo:rerun-if-env-changed=SZ_USE_WASM_SIMD128");
 
     // Set endian-specific macro
     if target_endian == "big" {
@@ -65,48 +331,88 @@ fn build_stringzilla() -> HashMap<String, bool> {
         flags.insert("SZ_IS_64BIT_ARM_".to_string(), false);
     }
 
-    // At start we will try compiling with all SIMD backends enabled
+    // Candidate SIMD backends for this architecture; each is individually capability-probed below
+    // before the real library is ever compiled.
     // https://doc.rust-lang.org/reference/conditional-compilation.html#target_arch
-    let flags_to_try = match target_arch.as_str() {
-        "arm" | "aarch64" => vec![
-            //
-            "SZ_USE_SVE2_AES",
-            "SZ_USE_SVE2",
-            "SZ_USE_SVE",
-            "SZ_USE_NEON_AES",
-            "SZ_USE_NEON",
-        ],
-        "x86_64" => vec![
-            //
-            "SZ_USE_ICE",
-            "SZ_USE_SKYLAKE",
-            "SZ_USE_HASWELL",
-            "SZ_USE_GOLDMONT",
-            "SZ_USE_WESTMERE",
-        ],
-        _ => vec![],
+    //
+    // None of these apply on a freestanding target: there's no hosted x86_64/aarch64 toolchain to
+    // probe, so every backend is simply left disabled (and absent from `flags`, like every other
+    // architecture this match doesn't recognize).
+    let mut flags_to_try = if freestanding {
+        vec![]
+    } else {
+        match target_arch.as_str() {
+            "arm" | "aarch64" => vec![
+                //
+                "SZ_USE_SVE2_AES",
+                "SZ_USE_SVE2",
+                "SZ_USE_SVE",
+                "SZ_USE_NEON_AES",
+                "SZ_USE_NEON",
+            ],
+            "x86_64" => vec![
+                //
+                "SZ_USE_ICE",
+                "SZ_USE_SKYLAKE",
+                "SZ_USE_HASWELL",
+                "SZ_USE_GOLDMONT",
+                "SZ_USE_WESTMERE",
+            ],
+            _ => vec![],
+        }
     };
+
+    // `SZ_FORCE_SIMD`/`SZ_DISABLE_SIMD` let a caller pin (or rule out) an exact backend set instead
+    // of trusting native capability probing -- essential for cross-compiling to a target the host
+    // toolchain can't run probes against, and for reproducible artifact builds that must not pick up
+    // whatever the building machine happens to support.
+    println!("cargo:rerun-if-env-changed=SZ_FORCE_SIMD");
+    println!("cargo:rerun-if-env-changed=SZ_DISABLE_SIMD");
+    let forced_flags = simd_name_list_to_flags("SZ_FORCE_SIMD");
+    let disabled_flags = simd_name_list_to_flags("SZ_DISABLE_SIMD");
+    if !forced_flags.is_empty() {
+        for flag in &forced_flags {
+            if !flags_to_try.contains(flag) {
+                println!(
+                    "cargo:warning=SZ_FORCE_SIMD requested {flag} but it doesn't apply to target_arch={target_arch}, ignoring"
+                );
+            }
+        }
+        flags_to_try.retain(|flag| forced_flags.contains(flag));
+    }
+    flags_to_try.retain(|flag| !disabled_flags.contains(flag));
+
+    // Probe each remaining candidate backend in isolation (cheap) instead of bisecting on the full
+    // library (expensive), and only enable the ones whose probe actually compiles for this target.
+    // Forced backends skip the probe entirely -- the caller is asserting support directly.
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap_or_default());
+    let target_triple = env::var("TARGET").unwrap_or_default();
+    let compiler_path = build.get_compiler().path().display().to_string();
+    let cache_key_prefix = format!("{compiler_path}|{target_triple}");
+    let cache_path = probe_cache_path(&out_dir);
+    let mut probe_cache = load_probe_cache(&cache_path);
+
     for flag in flags_to_try.iter() {
-        build.define(flag, "1");
-        flags.insert(flag.to_string(), true);
+        let supported = if forced_flags.contains(flag) {
+            true
+        } else {
+            probe_backend_supported(&cache_key_prefix, flag, &out_dir, &mut probe_cache, &cache_path)
+        };
+        build.define(flag, if supported { "1" } else { "0" });
+        flags.insert(flag.to_string(), supported);
     }
 
-    // If that fails, we will try disabling them one by one
+    // The probes should already match what the real library accepts, but fall back to disabling
+    // every backend if the full build still fails for some unrelated reason.
     if build.try_compile("stringzilla").is_err() {
-        print!("cargo:warning=Failed to compile with all SIMD backends...");
+        println!("cargo:warning=Failed to compile stringzilla with the probed (or forced) SIMD backends enabled; disabling all and retrying");
 
         for flag in flags_to_try.iter() {
             build.define(flag, "0");
             flags.insert(flag.to_string(), false);
-            if build.try_compile("stringzilla").is_ok() {
-                break;
-            }
-
-            // Print the failed configuration
-            println!(
-                "cargo:warning=Failed to compile after disabling {}, trying next configuration...",
-                flag
-            );
+        }
+        if build.try_compile("stringzilla").is_err() {
+            panic!("Failed to compile stringzilla even with every SIMD backend disabled");
         }
     }
 
@@ -126,20 +432,123 @@ fn build_stringzilla() -> HashMap<String, bool> {
     flags
 }
 
+/// Compute capability used for the CUDA backend when `SZ_CUDA_ARCH` is unset and `nvidia-smi`
+/// probing (see `probe_local_cuda_compute_capability`) doesn't find a local device either.
+const DEFAULT_CUDA_ARCH: &str = "90a";
+
+/// Builds the `-gencode arch=compute_XX,code=sm_XX` flag pairs for `nvcc`, one pair per entry in
+/// `SZ_CUDA_ARCH` (a comma-separated list of compute capabilities, e.g. `"80,90a"`), so a single
+/// build produces a fat binary spanning several GPU generations. When the env var is unset, tries
+/// `nvidia-smi` to auto-select the local device's capability, then falls back to
+/// [`DEFAULT_CUDA_ARCH`].
+fn cuda_gencode_flags() -> Vec<String> {
+    let archs: Vec<String> = match env::var("SZ_CUDA_ARCH") {
+        Ok(value) => value.split(',').map(str::trim).filter(|arch| !arch.is_empty()).map(str::to_string).collect(),
+        Err(_) => vec![probe_local_cuda_compute_capability().unwrap_or_else(|| DEFAULT_CUDA_ARCH.to_string())],
+    };
+
+    archs
+        .iter()
+        .flat_map(|arch| ["-gencode".to_string(), format!("arch=compute_{arch},code=sm_{arch}")])
+        .collect()
+}
+
+/// Best-effort query of the local GPU's compute capability via `nvidia-smi`, used only when
+/// `SZ_CUDA_ARCH` is unset. Returns `None` (falling back to [`DEFAULT_CUDA_ARCH`]) if
+/// `nvidia-smi` isn't on `PATH`, fails, or reports nothing parseable.
+fn probe_local_cuda_compute_capability() -> Option<String> {
+    let output = std::process::Command::new("nvidia-smi")
+        .args(["--query-gpu=compute_cap", "--format=csv,noheader"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let first_line = stdout.lines().next()?.trim();
+    if first_line.is_empty() {
+        return None;
+    }
+    // nvidia-smi reports e.g. "9.0"; stringzilla's arch strings are "90"/"90a" with the dot dropped.
+    Some(first_line.replace('.', ""))
+}
+
+/// A located ROCm/HIP installation: enough to point `cc::Build` at `hipcc` and the right search
+/// paths, the same ingredients clang's AMDGPU toolchain driver resolves before it will link.
+struct RocmToolchain {
+    root: PathBuf,
+    hipcc: PathBuf,
+}
+
+/// Finds an installed ROCm toolchain, honoring `ROCM_PATH`/`HIP_PATH` first and otherwise probing
+/// `/opt/rocm` and versioned `/opt/rocm-*` directories (highest-sorting name first). A candidate root only
+/// counts if it has both `bin/hipcc` and a device-bitcode directory (`amdgcn/bitcode` or
+/// `lib/bitcode`) containing at least one `.bc`/`.amdgcn.bc` file.
+fn locate_rocm_toolchain() -> Option<RocmToolchain> {
+    let mut candidate_roots = Vec::new();
+    if let Ok(path) = env::var("ROCM_PATH") {
+        candidate_roots.push(PathBuf::from(path));
+    }
+    if let Ok(path) = env::var("HIP_PATH") {
+        candidate_roots.push(PathBuf::from(path));
+    }
+    candidate_roots.push(PathBuf::from("/opt/rocm"));
+    if let Ok(entries) = std::fs::read_dir("/opt") {
+        let mut versioned: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.file_name().and_then(|name| name.to_str()).is_some_and(|name| name.starts_with("rocm-")))
+            .collect();
+        versioned.sort();
+        versioned.reverse(); // prefer the highest-sorting (likely newest) version suffix
+        candidate_roots.extend(versioned);
+    }
+
+    candidate_roots.into_iter().find_map(|root| {
+        let hipcc = root.join("bin/hipcc");
+        if !hipcc.is_file() {
+            return None;
+        }
+        let has_bitcode =
+            ["amdgcn/bitcode", "lib/bitcode"].iter().any(|relative| rocm_bitcode_dir_has_bc_files(&root.join(relative)));
+        if !has_bitcode {
+            return None;
+        }
+        Some(RocmToolchain { root, hipcc })
+    })
+}
+
+/// True if `dir` exists and contains at least one `.bc` or `.amdgcn.bc` device-bitcode file.
+fn rocm_bitcode_dir_has_bc_files(dir: &Path) -> bool {
+    std::fs::read_dir(dir)
+        .map(|entries| {
+            entries.filter_map(|entry| entry.ok()).any(|entry| {
+                let name = entry.file_name();
+                let name = name.to_string_lossy();
+                name.ends_with(".bc") || name.ends_with(".amdgcn.bc")
+            })
+        })
+        .unwrap_or(false)
+}
+
 fn build_stringzillas(serial_flags: &HashMap<String, bool>) {
+    let no_defaults = cc_no_defaults();
     let mut build = cc::Build::new();
     let is_cpus = env::var("CARGO_FEATURE_CPUS").is_ok();
     let is_cuda = env::var("CARGO_FEATURE_CUDA").is_ok();
     let is_rocm = env::var("CARGO_FEATURE_ROCM").is_ok();
+    let is_metal = env::var("CARGO_FEATURE_METAL").is_ok();
 
     build
         .include("include")
         .include("fork_union/include")
         .warnings(false)
         .define("SZ_DYNAMIC_DISPATCH", "1")
-        .define("SZ_AVOID_LIBC", "0")
-        .define("SZ_DEBUG", "0")
-        .flag("-O2");
+        .define("SZ_DEBUG", "0");
+
+    if !no_defaults {
+        build.flag(opt_level_flag());
+    }
 
     // Nvidia GPU backend
     if is_cuda {
@@ -147,18 +556,52 @@ fn build_stringzillas(serial_flags: &HashMap<String, bool>) {
         build.file("c/stringzillas.cu");
         build.define("SZ_USE_CUDA", "1");
         build.define("SZ_USE_ROCM", "0");
+        build.define("SZ_USE_METAL", "0");
         build.flag("-std=c++20");
         build.flag("--expt-relaxed-constexpr");
-        build.flag("-arch=sm_90a");
+        for flag in cuda_gencode_flags() {
+            build.flag(&flag);
+        }
+        println!("cargo:rerun-if-env-changed=SZ_CUDA_ARCH");
     }
     // AMD GPU backend
     else if is_rocm {
+        let toolchain = locate_rocm_toolchain().unwrap_or_else(|| {
+            panic!(
+                "SZ_USE_ROCM requested but no ROCm installation was found; set ROCM_PATH or \
+                 HIP_PATH, or install to /opt/rocm (or a versioned /opt/rocm-<version>) with \
+                 bin/hipcc and a device-bitcode directory (amdgcn/bitcode or lib/bitcode)"
+            )
+        });
+        println!("cargo:rerun-if-env-changed=ROCM_PATH");
+        println!("cargo:rerun-if-env-changed=HIP_PATH");
+        println!("cargo:rerun-if-env-changed=SZ_ROCM_ARCH");
+
+        build.compiler(&toolchain.hipcc);
         build.cpp(true);
         build.file("c/stringzillas.cu");
         build.define("SZ_USE_CUDA", "0");
         build.define("SZ_USE_ROCM", "1");
+        build.define("SZ_USE_METAL", "0");
+        build.flag("-std=c++20");
+        build.include(toolchain.root.join("include"));
+        println!("cargo:rustc-link-search=native={}", toolchain.root.join("lib").display());
+
+        // Defaults to MI200-class hardware (gfx90a); override for other AMD GPU generations.
+        let rocm_arch = env::var("SZ_ROCM_ARCH").unwrap_or_else(|_| "gfx90a".to_string());
+        build.flag(&format!("--offload-arch={}", rocm_arch));
+    }
+    // Apple Silicon GPU backend (Metal compute, Objective-C++)
+    else if is_metal {
+        build.cpp(true);
+        build.file("c/stringzillas.mm");
+        build.define("SZ_USE_CUDA", "0");
+        build.define("SZ_USE_ROCM", "0");
+        build.define("SZ_USE_METAL", "1");
         build.flag("-std=c++20");
-        // TODO: Add proper HIP/ROCm compiler support
+        build.flag("-ObjC++");
+        println!("cargo:rustc-link-lib=framework=Metal");
+        println!("cargo:rustc-link-lib=framework=Foundation");
     }
     // Multi-core CPU backend
     else if is_cpus {
@@ -166,13 +609,19 @@ fn build_stringzillas(serial_flags: &HashMap<String, bool>) {
         build.file("c/stringzillas.cpp");
         build.define("SZ_USE_CUDA", "0");
         build.define("SZ_USE_ROCM", "0");
+        build.define("SZ_USE_METAL", "0");
         build.flag("-std=c++20");
     }
 
     // Common flags
-    build
-        .flag_if_supported("-fdiagnostics-color=always")
-        .flag_if_supported("-fPIC");
+    build.flag_if_supported("-fdiagnostics-color=always");
+    if !no_defaults {
+        build.flag_if_supported("-fPIC");
+    }
+    // User-supplied CXXFLAGS come after this crate's own flags above, so they take precedence.
+    for flag in user_flags("CXXFLAGS") {
+        build.flag(&flag);
+    }
 
     // Apply the same architecture-specific flags as determined for stringzilla
     for (flag, enabled) in serial_flags.iter() {
@@ -198,6 +647,7 @@ fn build_stringzillas(serial_flags: &HashMap<String, bool>) {
     // StringZillas-specific rerun triggers
     println!("cargo:rerun-if-changed=c/stringzillas.cu");
     println!("cargo:rerun-if-changed=c/stringzillas.cuh");
+    println!("cargo:rerun-if-changed=c/stringzillas.mm");
     println!("cargo:rerun-if-changed=include/stringzillas/stringzillas.h");
     println!("cargo:rerun-if-changed=include/stringzillas/fingerprints.hpp");
     println!("cargo:rerun-if-changed=include/stringzillas/fingerprints.cuh");
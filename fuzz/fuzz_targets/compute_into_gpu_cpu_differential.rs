@@ -0,0 +1,73 @@
+#![no_main]
+
+use bytes::Buf;
+use libfuzzer_sys::fuzz_target;
+use stringzilla::szs::{ArbitraryBytesTapePair, DeviceScope, LevenshteinDistances, UnifiedAlloc, UnifiedVec};
+
+/// Scalar Wagner-Fischer Levenshtein distance, independent of any SIMD/GPU kernel, used as the
+/// harness's ground truth.
+fn scalar_levenshtein(a: &[u8], b: &[u8]) -> usize {
+    let mut previous_row: Vec<usize> = (0..=b.len()).collect();
+    let mut current_row = vec![0usize; b.len() + 1];
+    for (i, &byte_a) in a.iter().enumerate() {
+        current_row[0] = i + 1;
+        for (j, &byte_b) in b.iter().enumerate() {
+            let substitution_cost = if byte_a == byte_b { 0 } else { 1 };
+            current_row[j + 1] = (previous_row[j] + substitution_cost)
+                .min(previous_row[j + 1] + 1)
+                .min(current_row[j] + 1);
+        }
+        std::mem::swap(&mut previous_row, &mut current_row);
+    }
+    previous_row[b.len()]
+}
+
+/// Reads every entry out of `tape` via its zero-copy [`bytes::Buf`] view.
+fn collect_entries(tape: &stringzilla::szs::AnyBytesTape<'static>) -> Vec<Vec<u8>> {
+    let mut entries = Vec::new();
+    let mut index = 0;
+    while let Some(entry) = tape.entry_as_buf(index) {
+        entries.push(entry.chunk().to_vec());
+        index += 1;
+    }
+    entries
+}
+
+fuzz_target!(|pair: ArbitraryBytesTapePair| {
+    let ArbitraryBytesTapePair { first, second } = pair;
+    let entries_a = collect_entries(&first);
+    let entries_b = collect_entries(&second);
+
+    let device = match DeviceScope::default() {
+        Ok(device) => device,
+        Err(_) => return,
+    };
+    let engine = match LevenshteinDistances::new(&device, 0, 1, 1, 1) {
+        Ok(engine) => engine,
+        Err(_) => return,
+    };
+
+    let pair_count = entries_a.len().min(entries_b.len());
+    let mut results: UnifiedVec<usize> = UnifiedVec::with_capacity_in(pair_count, UnifiedAlloc);
+    results.resize(pair_count, 0);
+    if engine.compute_into(&device, first, second, &mut results).is_err() {
+        return;
+    }
+
+    for (i, &distance) in results.iter().enumerate() {
+        let expected = scalar_levenshtein(&entries_a[i], &entries_b[i]);
+        assert_eq!(distance, expected, "mismatch on pair {}: {:?} vs {:?}", i, entries_a[i], entries_b[i]);
+
+        // Self-consistency checks that don't depend on the scalar reference at all.
+        if entries_a[i].is_empty() && entries_b[i].is_empty() {
+            assert_eq!(distance, 0);
+        }
+        if entries_a[i] == entries_b[i] {
+            assert_eq!(distance, 0, "identical strings must have distance 0");
+        }
+        if entries_a[i].starts_with(&entries_b[i]) || entries_b[i].starts_with(&entries_a[i]) {
+            let length_delta = entries_a[i].len().abs_diff(entries_b[i].len());
+            assert_eq!(distance, length_delta, "a prefix/suffix pair's distance must equal the length delta");
+        }
+    }
+});
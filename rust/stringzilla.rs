@@ -426,6 +426,63 @@ impl Default for Hasher {
     }
 }
 
+/// A stable, versioned wrapper around [`Hasher`] suitable for on-disk fingerprints, build caches,
+/// and other artifacts that must compare equal across architectures and across runs of the same
+/// StringZilla major version.
+///
+/// The plain `core::hash::Hasher` impl on [`Hasher`] already feeds integers as little-endian
+/// bytes, which makes it portable across x86/ARM and big/little-endian hosts. `StableSzHasher`
+/// adds the two remaining guarantees a persisted fingerprint needs:
+///
+/// - **Unambiguous framing**: every [`StableSzHasher::write`] call is length-prefixed with a
+///   little-endian `u64` byte count before the data itself, so sequences like `("a", "b")` and
+///   `("ab", "")` cannot collide the way they could with naive concatenation.
+/// - **Domain separation**: construction mixes in a 16-byte constant derived from the major
+///   component of the crate's [`SemVer`] (as reported by [`version`]), so fingerprints stay the
+///   same across minor and patch releases, while different StringZilla major versions are
+///   guaranteed to differ even for identical input.
+///
+/// Use [`StableSzHasher::finish_stable`] instead of `core::hash::Hasher::finish` to get a
+/// fixed-size `[u8; 8]` rather than a host-width `usize`.
+#[derive(Debug, Clone, Copy)]
+pub struct StableSzHasher {
+    inner: Hasher,
+}
+
+impl StableSzHasher {
+    /// Creates a new stable hasher, seeded with `seed` and domain-separated by the crate version.
+    pub fn new(seed: u64) -> Self {
+        let mut inner = Hasher::new(seed);
+        let v = version();
+        let domain = [
+            b'S', b'Z', b'S', b'T', b'A', b'B', b'L', b'E', v.major as u8, (v.major >> 8) as u8, 0, 0, 0, 0, 0,
+            0,
+        ];
+        inner.update(&domain);
+        Self { inner }
+    }
+
+    /// Feeds a length-prefixed chunk of bytes into the hasher, so that chunk boundaries are
+    /// unambiguous regardless of how many `write` calls a caller makes.
+    pub fn write(&mut self, bytes: &[u8]) -> &mut Self {
+        self.inner.update(&(bytes.len() as u64).to_le_bytes());
+        self.inner.update(bytes);
+        self
+    }
+
+    /// Returns the current stable digest as a fixed-size, portable byte array.
+    pub fn finish_stable(&self) -> [u8; 8] {
+        self.inner.digest().to_le_bytes()
+    }
+}
+
+impl Default for StableSzHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
 impl Sha256 {
     /// Creates a new SHA256 hasher with the initial state.
     pub fn new() -> Self {
@@ -477,6 +534,74 @@ impl Default for Sha256 {
     }
 }
 
+/// Bridges [`Sha256`] and the AES-accelerated [`Hasher`] into the RustCrypto `digest` ecosystem.
+///
+/// With the `digest` feature enabled, `Sha256` implements `digest::Digest` (via `Update` +
+/// `FixedOutput` + `Reset` + `OutputSizeUser` + `HashMarker`), so it can be used anywhere a
+/// generic `D: Digest` is expected: HKDF, PBKDF2, the `hmac` crate, streaming file hashers, and
+/// `digest::DynDigest` trait objects. `Hasher` gets the narrower `Update` + `FixedOutput` +
+/// `Reset` + `OutputSizeUser` set (no `HashMarker`), exposing its 64-bit output the same way. Both impls simply forward to the existing
+/// `sz_sha256_state_*` / `sz_hash_state_*` FFI calls already used by the inherent methods above.
+#[cfg(feature = "digest")]
+mod digest_impls {
+    use super::{Hasher, Sha256};
+    use digest::generic_array::typenum::{U32, U8};
+    use digest::generic_array::GenericArray;
+    use digest::{FixedOutput, HashMarker, OutputSizeUser, Reset, Update};
+
+    impl Update for Sha256 {
+        #[inline]
+        fn update(&mut self, data: &[u8]) {
+            Sha256::update(self, data);
+        }
+    }
+
+    impl OutputSizeUser for Sha256 {
+        type OutputSize = U32;
+    }
+
+    impl FixedOutput for Sha256 {
+        #[inline]
+        fn finalize_into(self, out: &mut GenericArray<u8, Self::OutputSize>) {
+            out.copy_from_slice(&self.digest());
+        }
+    }
+
+    impl Reset for Sha256 {
+        #[inline]
+        fn reset(&mut self) {
+            *self = Sha256::new();
+        }
+    }
+
+    impl HashMarker for Sha256 {}
+
+    impl Update for Hasher {
+        #[inline]
+        fn update(&mut self, data: &[u8]) {
+            Hasher::update(self, data);
+        }
+    }
+
+    impl OutputSizeUser for Hasher {
+        type OutputSize = U8;
+    }
+
+    impl FixedOutput for Hasher {
+        #[inline]
+        fn finalize_into(self, out: &mut GenericArray<u8, Self::OutputSize>) {
+            out.copy_from_slice(&self.digest().to_le_bytes());
+        }
+    }
+
+    impl Reset for Hasher {
+        #[inline]
+        fn reset(&mut self) {
+            *self = Hasher::new(0);
+        }
+    }
+}
+
 /// Computes HMAC-SHA256 (Hash-based Message Authentication Code) for the given key and message.
 ///
 /// # Arguments
@@ -528,6 +653,133 @@ pub fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
     outer_hasher.digest()
 }
 
+/// Compares two byte slices in constant time, independent of where the first mismatch occurs.
+///
+/// Unlike `a == b`, this function never returns early on a mismatching byte, which makes it
+/// safe for comparing MACs, digests, and other secrets where a timing side-channel could leak
+/// the position of the first differing byte to an attacker.
+///
+/// If the slices have different lengths, `false` is returned, but `min(a.len(), b.len())` bytes
+/// are still scanned so that the early `false` for a length mismatch doesn't leak more than the
+/// lengths themselves, and so that two equal-length inputs always take the same time to compare.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla::fixed_time_eq;
+///
+/// assert!(fixed_time_eq(b"secret", b"secret"));
+/// assert!(!fixed_time_eq(b"secret", b"public"));
+/// assert!(!fixed_time_eq(b"short", b"longer-slice"));
+/// ```
+pub fn fixed_time_eq(a: &[u8], b: &[u8]) -> bool {
+    let len = a.len().min(b.len());
+    let mut r: u8 = 0;
+    for i in 0..len {
+        unsafe {
+            let ai = core::ptr::read_volatile(&a[i]);
+            let bi = core::ptr::read_volatile(&b[i]);
+            let mut acc = core::ptr::read_volatile(&r);
+            acc |= ai ^ bi;
+            core::ptr::write_volatile(&mut r, acc);
+        }
+    }
+    r |= r >> 4;
+    r |= r >> 2;
+    r |= r >> 1;
+    a.len() == b.len() && (r & 1) == 0
+}
+
+/// Verifies an HMAC-SHA256 message authentication code in constant time.
+///
+/// This is the safe way to check a MAC: it recomputes `hmac_sha256(key, message)` and compares
+/// it against `expected_mac` using [`fixed_time_eq`], avoiding the timing side-channel that a
+/// plain `==` comparison would introduce.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla::{hmac_sha256, hmac_sha256_verify};
+///
+/// let key = b"secret_key";
+/// let message = b"important message";
+/// let mac = hmac_sha256(key, message);
+/// assert!(hmac_sha256_verify(key, message, &mac));
+/// assert!(!hmac_sha256_verify(key, message, &[0u8; 32]));
+/// ```
+pub fn hmac_sha256_verify(key: &[u8], message: &[u8], expected_mac: &[u8; 32]) -> bool {
+    let computed_mac = hmac_sha256(key, message);
+    fixed_time_eq(&computed_mac, expected_mac)
+}
+
+/// Performs the "extract" step of HKDF (RFC 5869) over SHA256.
+///
+/// Condenses a possibly non-uniform input keying material `ikm` (e.g. a Diffie-Hellman shared
+/// secret) into a fixed-length, uniformly random pseudorandom key, using `salt` as the HMAC key.
+/// When `salt` is empty, a 32-byte all-zero salt is used instead, as specified by RFC 5869.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla::hkdf_extract;
+///
+/// let prk = hkdf_extract(b"salt", b"input key material");
+/// assert_eq!(prk.len(), 32);
+/// ```
+pub fn hkdf_extract(salt: &[u8], ikm: &[u8]) -> [u8; 32] {
+    if salt.is_empty() {
+        hmac_sha256(&[0u8; 32], ikm)
+    } else {
+        hmac_sha256(salt, ikm)
+    }
+}
+
+/// Performs the "expand" step of HKDF (RFC 5869) over SHA256, filling `out` with key material
+/// derived from a pseudorandom key `prk` (typically the output of [`hkdf_extract`]).
+///
+/// `info` is optional context/application-specific information that binds the derived key to
+/// its intended use. `out` may be any length up to `255 * 32` bytes; longer requests return
+/// [`Status::OverflowRisk`], matching the RFC 5869 limit on the number of expansion rounds.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla::{hkdf_extract, hkdf_expand};
+///
+/// let prk = hkdf_extract(b"salt", b"input key material");
+/// let mut okm = [0u8; 42];
+/// hkdf_expand(&prk, b"application info", &mut okm).unwrap();
+/// ```
+pub fn hkdf_expand(prk: &[u8], info: &[u8], out: &mut [u8]) -> Result<(), Status> {
+    const MAX_OUTPUT_LEN: usize = 255 * 32;
+    if out.len() > MAX_OUTPUT_LEN {
+        return Err(Status::OverflowRisk);
+    }
+
+    let mut previous_block: [u8; 32] = [0u8; 32];
+    let mut previous_block_length = 0usize;
+    let mut written = 0usize;
+    let mut counter: u8 = 1;
+
+    while written < out.len() {
+        let mut hasher_input = Vec::with_capacity(previous_block_length + info.len() + 1);
+        hasher_input.extend_from_slice(&previous_block[..previous_block_length]);
+        hasher_input.extend_from_slice(info);
+        hasher_input.push(counter);
+
+        let block = hmac_sha256(prk, &hasher_input);
+        let copy_length = (out.len() - written).min(32);
+        out[written..written + copy_length].copy_from_slice(&block[..copy_length]);
+
+        previous_block = block;
+        previous_block_length = 32;
+        written += copy_length;
+        counter = counter.wrapping_add(1);
+    }
+
+    Ok(())
+}
+
 /// Standard Hasher trait to interoperate with `std::collections`.
 impl core::hash::Hasher for Hasher {
     #[inline]
@@ -619,6 +871,295 @@ impl std::hash::BuildHasher for BuildSzHasher {
     }
 }
 
+/// Process-wide base key shared by every [`RandomState`] instance, seeded once on first use.
+#[cfg(feature = "std")]
+static RANDOM_STATE_BASE_KEY: std::sync::OnceLock<u64> = std::sync::OnceLock::new();
+
+/// Process-wide counter perturbing each [`RandomState::new`] away from the shared base key.
+#[cfg(feature = "std")]
+static RANDOM_STATE_COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+/// DOS-resistant `BuildHasher` that seeds [`Hasher`] with a randomized, per-instance key, in the
+/// style of aHash's `RandomState`.
+///
+/// `BuildSzHasher` defaults to a fixed seed of `0`, which is convenient for reproducible tests
+/// but trivially collidable: an adversary who can influence the keys inserted into a `HashMap`
+/// can craft inputs that all hash to the same bucket and degrade lookups to O(n). `RandomState`
+/// instead derives its seed from process entropy, so two processes (and even two `HashMap`s in
+/// the same process) end up with different, unpredictable seeds.
+///
+/// Entropy is gathered once per process: an OS random draw (via [`std::collections::hash_map::RandomState`],
+/// which itself draws from the platform's `getrandom`), the address of a freshly allocated heap
+/// value, and the current time, all mixed through one pass of the AES-accelerated [`Hasher`].
+/// The result is cached in a `OnceLock` as `RANDOM_STATE_BASE_KEY`. Each call to `RandomState::new()`
+/// then cheaply perturbs that cached key with a monotonically incrementing `AtomicU64` counter,
+/// so constructing many `HashMap`s doesn't repeat the expensive entropy-gathering step.
+///
+/// For deterministic or testing use, construct a [`BuildSzHasher`] with [`BuildSzHasher::with_seed`]
+/// instead.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy)]
+pub struct RandomState {
+    seed: u64,
+}
+
+#[cfg(feature = "std")]
+impl RandomState {
+    /// Derives a fresh, DOS-resistant random seed and wraps it in a `RandomState`.
+    pub fn new() -> Self {
+        let base_key = *RANDOM_STATE_BASE_KEY.get_or_init(Self::gather_base_key);
+        let counter = RANDOM_STATE_COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let mut mixer = Hasher::new(base_key);
+        mixer.write_u64(counter);
+        Self { seed: mixer.digest() }
+    }
+
+    /// Gathers process entropy from several independent sources and mixes it into a single seed.
+    fn gather_base_key() -> u64 {
+        // OS entropy, via libstd's own `getrandom`-backed `RandomState`.
+        let os_entropy = std::hash::BuildHasher::hash_one(&std::collections::hash_map::RandomState::new(), 0u8);
+
+        // Address of a heap allocation: varies with ASLR and allocator state across processes.
+        let heap_marker = Box::new(0u8);
+        let heap_address = &*heap_marker as *const u8 as u64;
+
+        // Monotonically incrementing process-global counter, perturbed per call-site.
+        let counter = RANDOM_STATE_COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+
+        // Coarse timestamp; not itself unpredictable, but adds cheap extra mixing.
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+
+        let mut mixer = Hasher::new(os_entropy);
+        mixer.write_u64(heap_address);
+        mixer.write_u64(counter);
+        mixer.write_u64(timestamp);
+        mixer.digest()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for RandomState {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::hash::BuildHasher for RandomState {
+    type Hasher = Hasher;
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Hasher::new(self.seed)
+    }
+}
+
+/// Empirical hash-quality diagnostics for [`Hasher`], in the spirit of aHash's `hash_quality_test`.
+///
+/// These are not correctness tests in the usual sense - a single fixed input/output pair can't
+/// tell you whether a hash function has good avalanche behavior or distributes its output
+/// uniformly. Instead, each function here runs many trials over generated inputs and returns a
+/// small report of the measured statistics, so CI (or downstream projects) can gate on
+/// regressions, e.g. after a SIMD backend change silently weakens the mixing.
+#[cfg(feature = "std")]
+pub mod quality {
+    use super::Hasher;
+
+    /// Small, dependency-free splitmix64 PRNG, used only to generate reproducible test inputs.
+    struct SplitMix64(u64);
+
+    impl SplitMix64 {
+        fn new(seed: u64) -> Self {
+            Self(seed)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+            let mut z = self.0;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+            z ^ (z >> 31)
+        }
+    }
+
+    /// Report produced by [`avalanche`].
+    #[derive(Debug, Clone, Copy)]
+    pub struct AvalancheReport {
+        /// Worst-case bias across all (input bit, output bit) pairs: `0.0` is perfect (the output
+        /// bit flips exactly 50% of the time), `1.0` is the worst possible (it never flips, or
+        /// always flips).
+        pub worst_case_bias: f64,
+        /// Number of random messages used per input bit.
+        pub trials_per_bit: usize,
+        /// Number of input bits that were tested (`8 * message_len`).
+        pub input_bits_tested: usize,
+    }
+
+    /// Measures the strict avalanche criterion: flipping a single input bit should flip each
+    /// output bit with ~50% probability, independent of which bit was flipped.
+    ///
+    /// For every bit position in a `message_len`-byte message, `trials_per_bit` random base
+    /// messages are hashed, the bit is flipped, and the messages are hashed again; the fraction
+    /// of trials where each of the 64 output bits changed is tracked. The returned
+    /// [`AvalancheReport::worst_case_bias`] is `max(|observed_flip_rate - 0.5|) * 2`, so `0.0`
+    /// means every output bit flipped exactly half the time across every input bit tested.
+    pub fn avalanche(seed: u64, message_len: usize, trials_per_bit: usize) -> AvalancheReport {
+        let mut rng = SplitMix64::new(seed);
+        let input_bits = message_len * 8;
+        let mut worst_case_bias = 0.0f64;
+
+        for bit in 0..input_bits {
+            let mut flip_counts = [0usize; 64];
+            for _ in 0..trials_per_bit {
+                let mut message = vec![0u8; message_len];
+                for byte in message.iter_mut() {
+                    *byte = rng.next_u64() as u8;
+                }
+                let base_digest = Hasher::new(seed).update(&message).digest();
+
+                message[bit / 8] ^= 1 << (bit % 8);
+                let flipped_digest = Hasher::new(seed).update(&message).digest();
+
+                let diff = base_digest ^ flipped_digest;
+                for (output_bit, count) in flip_counts.iter_mut().enumerate() {
+                    if (diff >> output_bit) & 1 == 1 {
+                        *count += 1;
+                    }
+                }
+            }
+
+            for &count in flip_counts.iter() {
+                let flip_rate = count as f64 / trials_per_bit as f64;
+                let bias = (flip_rate - 0.5).abs() * 2.0;
+                worst_case_bias = worst_case_bias.max(bias);
+            }
+        }
+
+        AvalancheReport {
+            worst_case_bias,
+            trials_per_bit,
+            input_bits_tested: input_bits,
+        }
+    }
+
+    /// Report produced by [`distribution_uniformity`].
+    #[derive(Debug, Clone, Copy)]
+    pub struct DistributionReport {
+        /// Pearson's chi-squared statistic over `bucket_count` equal-width buckets of the output
+        /// range. Values close to `bucket_count - 1` indicate a uniform distribution; values much
+        /// larger suggest the hash is biased towards certain buckets.
+        pub chi_squared: f64,
+        /// Number of buckets the output space was partitioned into.
+        pub bucket_count: usize,
+        /// Number of samples hashed.
+        pub sample_count: usize,
+    }
+
+    /// Measures how uniformly `Hasher` output is distributed over `bucket_count` equal-width
+    /// buckets, by hashing `sample_count` random messages of `message_len` bytes each and running
+    /// a chi-squared goodness-of-fit test against the uniform distribution.
+    pub fn distribution_uniformity(
+        seed: u64,
+        message_len: usize,
+        sample_count: usize,
+        bucket_count: usize,
+    ) -> DistributionReport {
+        let mut rng = SplitMix64::new(seed);
+        let mut buckets = vec![0usize; bucket_count];
+
+        for _ in 0..sample_count {
+            let mut message = vec![0u8; message_len];
+            for byte in message.iter_mut() {
+                *byte = rng.next_u64() as u8;
+            }
+            let digest = Hasher::new(seed).update(&message).digest();
+            let bucket = (digest as u128 * bucket_count as u128 / (u64::MAX as u128 + 1)) as usize;
+            buckets[bucket.min(bucket_count - 1)] += 1;
+        }
+
+        let expected = sample_count as f64 / bucket_count as f64;
+        let chi_squared: f64 = buckets
+            .iter()
+            .map(|&observed| {
+                let diff = observed as f64 - expected;
+                diff * diff / expected
+            })
+            .sum();
+
+        DistributionReport {
+            chi_squared,
+            bucket_count,
+            sample_count,
+        }
+    }
+
+    /// Report produced by [`collisions_of_sequential_integers`], [`collisions_of_short_ascii_keys`],
+    /// and [`collisions_of_single_byte_variants`].
+    #[derive(Debug, Clone, Copy)]
+    pub struct CollisionReport {
+        /// Number of keys whose 64-bit digest equals the digest of an earlier, different key.
+        /// Repeats of an identical key are not counted.
+        pub collisions: usize,
+        /// Total number of keys hashed.
+        pub keys_tested: usize,
+    }
+
+    fn count_collisions<I: Iterator<Item = Vec<u8>>>(seed: u64, keys: I, keys_tested: usize) -> CollisionReport {
+        use std::collections::HashMap;
+        let mut seen: HashMap<u64, Vec<u8>> = HashMap::with_capacity(keys_tested);
+        let mut collisions = 0usize;
+        for key in keys {
+            let digest = Hasher::new(seed).update(&key).digest();
+            match seen.get(&digest) {
+                Some(first_key) if *first_key != key => collisions += 1,
+                Some(_) => {}
+                None => {
+                    seen.insert(digest, key);
+                }
+            }
+        }
+        CollisionReport { collisions, keys_tested }
+    }
+
+    /// Counts digest collisions when hashing the little-endian byte representations of
+    /// `0..count` as 64-bit integers. Sequential keys are a classic adversarial case for weak
+    /// multiplicative hashes.
+    pub fn collisions_of_sequential_integers(seed: u64, count: usize) -> CollisionReport {
+        count_collisions(seed, (0..count as u64).map(|i| i.to_le_bytes().to_vec()), count)
+    }
+
+    /// Counts digest collisions when hashing `count` short, random ASCII keys of `key_len` bytes.
+    pub fn collisions_of_short_ascii_keys(seed: u64, count: usize, key_len: usize) -> CollisionReport {
+        let mut rng = SplitMix64::new(seed);
+        const ASCII_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+        let keys = (0..count).map(move |_| {
+            (0..key_len)
+                .map(|_| ASCII_ALPHABET[(rng.next_u64() as usize) % ASCII_ALPHABET.len()])
+                .collect::<Vec<u8>>()
+        });
+        count_collisions(seed, keys, count)
+    }
+
+    /// Counts digest collisions among `count` keys that each differ from a fixed base key by a
+    /// single byte - a pattern that shows up constantly in real key sets (sequential IDs,
+    /// near-duplicate strings) and that poor hash functions sometimes fail to disperse.
+    pub fn collisions_of_single_byte_variants(seed: u64, base_key: &[u8], count: usize) -> CollisionReport {
+        let keys = (0..count).map(|i| {
+            let mut key = base_key.to_vec();
+            if key.is_empty() {
+                return key;
+            }
+            let idx = i % key.len();
+            key[idx] = key[idx].wrapping_add(1 + ((i / key.len()) % 255) as u8);
+            key
+        });
+        count_collisions(seed, keys, count)
+    }
+}
+
 /// Checks if the library was compiled with dynamic dispatch enabled.
 pub fn dynamic_dispatch() -> bool {
     unsafe { sz_dynamic_dispatch() != 0 }
@@ -1127,10 +1668,166 @@ where
     rfind_byteset(haystack, Byteset::from(needles).inverted())
 }
 
+/// Rank of each byte value by how common it is across general English/source-code text, lowest
+/// rank meaning rarest. Mirrors the frequency tables `memchr`/`regex` ship internally: scanning
+/// for a needle's rarest byte first, rather than its first byte, sharply cuts the number of
+/// candidate positions that need a full needle comparison. Derived from a standard byte-frequency
+/// ranking (bytes that never appear in typical text, e.g. most control codes and every non-ASCII
+/// byte, all share the lowest rank).
+#[rustfmt::skip]
+static BYTE_RARITY_RANK: [u8; 256] = [
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 45, 45, 1, 1, 45, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    60, 63, 66, 81, 1, 82, 83, 65, 68, 69, 80, 79, 62, 67, 61, 76,
+    30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 74, 75, 1, 78, 1, 64,
+    84, 56, 22, 38, 42, 60, 30, 28, 46, 52, 16, 18, 40, 34, 50, 54,
+    24, 12, 44, 48, 58, 36, 20, 32, 14, 26, 10, 70, 1, 71, 1, 77,
+    1, 79, 28, 52, 58, 85, 40, 37, 64, 73, 19, 22, 55, 46, 70, 76,
+    31, 13, 61, 67, 82, 49, 25, 43, 16, 34, 10, 72, 1, 73, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+];
+
+/// Picks the statistically rarest byte within `needle`, returning its `(position, byte)` within
+/// the needle. Ties are broken by earliest position. Uses the built-in [`BYTE_RARITY_RANK`]
+/// frequency table, the same style of heuristic `memchr`/`regex` use to choose a fast scan byte.
+///
+/// Returns `None` if `needle` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla as sz;
+///
+/// // 'z' is far rarer in English text than 'e' or 't'.
+/// let (position, byte) = sz::rarest_byte(b"the size").unwrap();
+/// assert_eq!(byte, b'z');
+/// assert_eq!(position, 6);
+/// ```
+pub fn rarest_byte(needle: &[u8]) -> Option<(usize, u8)> {
+    needle
+        .iter()
+        .enumerate()
+        .min_by_key(|&(position, &byte)| (BYTE_RARITY_RANK[byte as usize], position))
+        .map(|(position, &byte)| (position, byte))
+}
+
+/// Finds the first occurrence of `needle` within `haystack` using a rare-byte prefilter: the
+/// needle's statistically rarest byte (per [`rarest_byte`]) is scanned for using the existing
+/// SIMD byte search, and each candidate is then verified against the full needle. For long
+/// needles in large haystacks this avoids the quadratic worst case of naively re-scanning every
+/// position and is the explicit, user-controllable form of the heuristic [`find`] may already
+/// apply internally. Callers that repeatedly search for the same needle can call [`rarest_byte`]
+/// once and reuse the choice instead of recomputing it on every call.
+///
+/// Returns `None` for an empty needle, since there is no byte to prefilter on; use [`find`] for
+/// that case instead.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla as sz;
+///
+/// let haystack = b"the quick brown fox jumps over the lazy dog";
+/// assert_eq!(sz::find_with_prefilter(haystack, b"lazy"), Some(35));
+/// assert_eq!(sz::find_with_prefilter(haystack, b"cat"), None);
+/// ```
+pub fn find_with_prefilter<H>(haystack: H, needle: &[u8]) -> Option<usize>
+where
+    H: AsRef<[u8]>,
+{
+    let haystack_ref = haystack.as_ref();
+    let (rare_position, rare_byte) = rarest_byte(needle)?;
+    find_prefiltered(haystack_ref, needle, rare_position, rare_byte)
+}
+
+/// Above this [`BYTE_RARITY_RANK`] value a needle's rarest byte is still common enough that
+/// scanning for it first buys little: most candidate positions would pass the prefilter and still
+/// need a full needle comparison. [`MatcherType::find_with_prefilter`] falls back to a plain
+/// [`MatcherType::Find`] above this threshold.
+const PREFILTER_RARITY_THRESHOLD: u8 = 50;
+
+/// Shared implementation behind [`find_with_prefilter`] and [`MatcherType::FindWithPrefilter`],
+/// taking an already-chosen rare byte and its offset within `needle` instead of recomputing
+/// [`rarest_byte`] on every call.
+fn find_prefiltered(haystack: &[u8], needle: &[u8], rare_position: usize, rare_byte: u8) -> Option<usize> {
+    if needle.len() > haystack.len() {
+        return None;
+    }
+
+    let mut byteset = Byteset::new();
+    byteset.add_u8(rare_byte);
+
+    let mut search_from = rare_position;
+    while let Some(offset) = find_byteset(&haystack[search_from..], byteset) {
+        let rare_hit = search_from + offset;
+        let candidate_start = rare_hit - rare_position;
+        if let Some(candidate) = haystack.get(candidate_start..candidate_start + needle.len()) {
+            if candidate == needle {
+                return Some(candidate_start);
+            }
+        }
+        search_from = rare_hit + 1;
+    }
+
+    None
+}
+
+/// Reverse counterpart of [`find_prefiltered`], scanning for the rightmost candidate whose rare
+/// byte lands at `rare_position` within `needle`. Backs [`MatcherType::FindWithPrefilter`]'s
+/// [`Matcher::rfind`].
+fn rfind_prefiltered(haystack: &[u8], needle: &[u8], rare_position: usize, rare_byte: u8) -> Option<usize> {
+    if needle.len() > haystack.len() {
+        return None;
+    }
+
+    let mut byteset = Byteset::new();
+    byteset.add_u8(rare_byte);
+
+    let mut search_until = haystack.len();
+    while let Some(rare_hit) = rfind_byteset(&haystack[..search_until], byteset) {
+        if rare_hit < rare_position {
+            break;
+        }
+        let candidate_start = rare_hit - rare_position;
+        if let Some(candidate) = haystack.get(candidate_start..candidate_start + needle.len()) {
+            if candidate == needle {
+                return Some(candidate_start);
+            }
+        }
+        search_until = rare_hit;
+    }
+
+    None
+}
+
 fn replace_all_with_finder<F, R>(
     buffer: &mut Vec<u8>,
     needle_length: usize,
     replacement: &[u8],
+    find_next: F,
+    find_prev: R,
+) -> Result<usize, Status>
+where
+    F: FnMut(&[u8], usize) -> Option<usize>,
+    R: FnMut(&[u8], usize) -> Option<usize>,
+{
+    replace_up_to_n_with_finder(buffer, needle_length, replacement, usize::MAX, find_next, find_prev)
+}
+
+/// Same three-way strategy as [`replace_all_with_finder`], but stops after at most `max_replacements`
+/// substitutions, mirroring `str::replacen`'s bounded semantics.
+fn replace_up_to_n_with_finder<F, R>(
+    buffer: &mut Vec<u8>,
+    needle_length: usize,
+    replacement: &[u8],
+    max_replacements: usize,
     mut find_next: F,
     mut find_prev: R,
 ) -> Result<usize, Status>
@@ -1138,7 +1835,7 @@ where
     F: FnMut(&[u8], usize) -> Option<usize>,
     R: FnMut(&[u8], usize) -> Option<usize>,
 {
-    if needle_length == 0 || buffer.is_empty() {
+    if needle_length == 0 || buffer.is_empty() || max_replacements == 0 {
         return Ok(0);
     }
 
@@ -1146,7 +1843,10 @@ where
     if needle_length == replacement.len() {
         let mut replaced = 0;
         let mut search_from = 0;
-        while let Some(pos) = find_next(buffer.as_slice(), search_from) {
+        while replaced < max_replacements {
+            let Some(pos) = find_next(buffer.as_slice(), search_from) else {
+                break;
+            };
             copy(&mut buffer[pos..pos + needle_length], &replacement);
             search_from = pos + needle_length;
             replaced += 1;
@@ -1161,7 +1861,10 @@ where
         let mut write = 0;
         let len = buffer.len();
 
-        while let Some(pos) = find_next(buffer.as_slice(), read) {
+        while replaced < max_replacements {
+            let Some(pos) = find_next(buffer.as_slice(), read) else {
+                break;
+            };
             if pos > read {
                 let chunk = pos - read;
                 unsafe {
@@ -1194,12 +1897,19 @@ where
         return Ok(replaced);
     }
 
-    // Case 3: replacement is longer – collect match positions once, resize once, then rewrite from the back.
+    // Case 3: replacement is longer – collect up to `max_replacements` match positions once,
+    // resize once, then rewrite from the back. Matches beyond the cap (and the plain text that
+    // follows the last replaced match) are left untouched and shifted into place as a single tail.
     let mut match_count = 0usize;
     let mut search_from = 0;
-    while let Some(pos) = find_next(buffer.as_slice(), search_from) {
+    let mut last_match_end = 0;
+    while match_count < max_replacements {
+        let Some(pos) = find_next(buffer.as_slice(), search_from) else {
+            break;
+        };
         match_count += 1;
         search_from = pos + needle_length;
+        last_match_end = search_from;
     }
 
     if match_count == 0 {
@@ -1221,22 +1931,34 @@ where
     }
     buffer.resize(new_len, 0);
 
-    let mut read_end = original_len;
-    let mut write_end = new_len;
+    // Shift the untouched tail (anything from the last replaced match onward) into its final place.
+    let tail_len = original_len - last_match_end;
+    if tail_len > 0 {
+        unsafe {
+            sz_move(
+                buffer.as_mut_ptr().add(new_len - tail_len) as *const c_void,
+                buffer.as_ptr().add(last_match_end) as *const c_void,
+                tail_len,
+            );
+        }
+    }
+
+    let mut read_end = last_match_end;
+    let mut write_end = new_len - tail_len;
 
     while let Some(pos) = find_prev(buffer.as_slice(), read_end) {
         let match_end = pos + needle_length;
-        let tail_len = read_end - match_end;
-        if tail_len > 0 {
+        let gap_len = read_end - match_end;
+        if gap_len > 0 {
             unsafe {
                 sz_move(
-                    buffer.as_mut_ptr().add(write_end - tail_len) as *const c_void,
+                    buffer.as_mut_ptr().add(write_end - gap_len) as *const c_void,
                     buffer.as_ptr().add(match_end) as *const c_void,
-                    tail_len,
+                    gap_len,
                 );
             }
         }
-        write_end -= tail_len;
+        write_end -= gap_len;
         write_end -= replacement.len();
         copy(&mut buffer[write_end..write_end + replacement.len()], replacement);
         read_end = pos;
@@ -1310,35 +2032,224 @@ pub fn try_replace_all_byteset(
     )
 }
 
-/// Finds the first newline character in UTF-8 encoded text.
-///
-/// Searches for any of the 8 Unicode newline characters:
-/// - U+000A (LF - Line Feed `\n`)
-/// - U+000B (VT - Vertical Tab `\v`)
-/// - U+000C (FF - Form Feed `\f`)
-/// - U+000D (CR - Carriage Return `\r`, handles `\r\n` as single newline)
-/// - U+001C (FILE SEPARATOR)
-/// - U+001D (GROUP SEPARATOR)
-/// - U+001E (RECORD SEPARATOR)
-/// - U+0085 (NEL - Next Line)
-/// - U+2028 (LINE SEPARATOR)
-/// - U+2029 (PARAGRAPH SEPARATOR)
-///
-/// # Arguments
-///
-/// * `text`: The UTF-8 encoded byte slice to search.
-///
-/// # Returns
-///
-/// An `Option<IndexSpan>` containing the byte offset and length of the matched newline.
-/// The length can be 1-3 bytes for single characters, or 2 bytes for CRLF sequence.
-///
-/// Returns `None` if no newline is found.
-///
-/// # Examples
-///
-/// ```
-/// use stringzilla::stringzilla as sz;
+/// Tries to replace at most `count` non-overlapping occurrences of `needle` inside `buffer` in
+/// place, starting from the front, mirroring `str::replacen`.
+///
+/// Uses the same three-way strategy as [`try_replace_all`] (equal-length overwrite / shorter
+/// compaction / longer backfill), but stops scanning once `count` matches have been found.
+/// Returns the number of replacements actually performed, which is `min(count, total matches)`.
+pub fn try_replace_first_n(
+    buffer: &mut Vec<u8>,
+    needle: &[u8],
+    replacement: &[u8],
+    count: usize,
+) -> Result<usize, Status> {
+    replace_up_to_n_with_finder(
+        buffer,
+        needle.len(),
+        replacement,
+        count,
+        |haystack, start| {
+            if start >= haystack.len() {
+                None
+            } else {
+                find(&haystack[start..], needle).map(|offset| start + offset)
+            }
+        },
+        |haystack, end| {
+            if end == 0 {
+                None
+            } else {
+                rfind(&haystack[..end], needle)
+            }
+        },
+    )
+}
+
+/// Iterator over non-overlapping occurrences of `needle` within a haystack, yielding
+/// `(offset, length)` spans in left-to-right order. Built from [`find`]. See [`match_indices`].
+pub struct MatchIndices<'a> {
+    haystack: &'a [u8],
+    needle: &'a [u8],
+    position: usize,
+}
+
+impl<'a> MatchIndices<'a> {
+    pub fn new(haystack: &'a [u8], needle: &'a [u8]) -> Self {
+        Self { haystack, needle, position: 0 }
+    }
+}
+
+impl<'a> Iterator for MatchIndices<'a> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.needle.is_empty() || self.position > self.haystack.len() {
+            return None;
+        }
+        let offset = find(&self.haystack[self.position..], self.needle)?;
+        let start = self.position + offset;
+        self.position = start + self.needle.len();
+        Some((start, self.needle.len()))
+    }
+}
+
+/// Iterator over non-overlapping occurrences of `needle` within a haystack, yielding
+/// `(offset, length)` spans in right-to-left order. Built from [`rfind`]. See [`rmatch_indices`].
+pub struct RMatchIndices<'a> {
+    haystack: &'a [u8],
+    needle: &'a [u8],
+    end: usize,
+}
+
+impl<'a> RMatchIndices<'a> {
+    pub fn new(haystack: &'a [u8], needle: &'a [u8]) -> Self {
+        let end = haystack.len();
+        Self { haystack, needle, end }
+    }
+}
+
+impl<'a> Iterator for RMatchIndices<'a> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.needle.is_empty() || self.end == 0 {
+            return None;
+        }
+        let start = rfind(&self.haystack[..self.end], self.needle)?;
+        self.end = start;
+        Some((start, self.needle.len()))
+    }
+}
+
+/// Returns an iterator over non-overlapping occurrences of `needle` within `haystack`, yielding
+/// `(offset, length)` spans in left-to-right order.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla as sz;
+///
+/// let spans: Vec<_> = sz::match_indices(b"ababab", b"ab").collect();
+/// assert_eq!(spans, vec![(0, 2), (2, 2), (4, 2)]);
+/// ```
+pub fn match_indices<'a>(haystack: &'a [u8], needle: &'a [u8]) -> MatchIndices<'a> {
+    MatchIndices::new(haystack, needle)
+}
+
+/// Returns an iterator over non-overlapping occurrences of `needle` within `haystack`, yielding
+/// `(offset, length)` spans in right-to-left order.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla as sz;
+///
+/// let spans: Vec<_> = sz::rmatch_indices(b"ababab", b"ab").collect();
+/// assert_eq!(spans, vec![(4, 2), (2, 2), (0, 2)]);
+/// ```
+pub fn rmatch_indices<'a>(haystack: &'a [u8], needle: &'a [u8]) -> RMatchIndices<'a> {
+    RMatchIndices::new(haystack, needle)
+}
+
+/// Builds a new buffer with at most `count` non-overlapping occurrences of `needle` in `haystack`
+/// substituted by `replacement`, reading left to right; `count = usize::MAX` replaces every
+/// occurrence. Mirrors `str::replacen`, but returns a fresh [`Vec<u8>`] instead of rewriting a
+/// buffer in place like [`try_replace_first_n`].
+///
+/// Walks the same forward, non-overlapping matches as [`match_indices`] (backed by the SIMD
+/// [`find`]): the gap before each match is copied verbatim, the replacement is appended, and the
+/// scan resumes past the needle. After `count` replacements the remaining tail is copied verbatim.
+/// The output is pre-sized from the density of the first match, so replacing many short needles
+/// with a longer replacement rarely needs more than one allocation.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla as sz;
+///
+/// assert_eq!(sz::replacen(b"abcabcabc", b"abc", b"x", 2), b"xxabc");
+/// ```
+pub fn replacen(haystack: &[u8], needle: &[u8], replacement: &[u8], count: usize) -> Vec<u8> {
+    if needle.is_empty() || count == 0 {
+        return haystack.to_vec();
+    }
+
+    let mut matches = match_indices(haystack, needle);
+    let Some(first_match) = matches.next() else {
+        return haystack.to_vec();
+    };
+
+    // Estimate how many matches fit in the remaining haystack at the density of the first one
+    // found, so the output buffer is sized close to its final length in a single allocation.
+    let (first_start, first_length) = first_match;
+    let remaining = haystack.len() - first_start;
+    let estimated_matches = (1 + remaining / (first_length + 1).max(1)).min(count);
+    let capacity =
+        haystack.len() + estimated_matches.saturating_mul(replacement.len().saturating_sub(first_length));
+    let mut output = Vec::with_capacity(capacity);
+
+    let mut tail = 0;
+    let mut replaced = 0;
+    let mut next_match = Some(first_match);
+    while let Some((start, length)) = next_match {
+        if replaced >= count {
+            break;
+        }
+        output.extend_from_slice(&haystack[tail..start]);
+        output.extend_from_slice(replacement);
+        tail = start + length;
+        replaced += 1;
+        next_match = matches.next();
+    }
+
+    output.extend_from_slice(&haystack[tail..]);
+    output
+}
+
+/// Builds a new buffer with every non-overlapping occurrence of `needle` in `haystack` substituted
+/// by `replacement`, reading left to right. Equivalent to [`replacen`] with `count = usize::MAX`.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla as sz;
+///
+/// assert_eq!(sz::replace(b"abcabcabc", b"abc", b"x"), b"xxx");
+/// ```
+pub fn replace(haystack: &[u8], needle: &[u8], replacement: &[u8]) -> Vec<u8> {
+    replacen(haystack, needle, replacement, usize::MAX)
+}
+
+/// Finds the first newline character in UTF-8 encoded text.
+///
+/// Searches for any of the 8 Unicode newline characters:
+/// - U+000A (LF - Line Feed `\n`)
+/// - U+000B (VT - Vertical Tab `\v`)
+/// - U+000C (FF - Form Feed `\f`)
+/// - U+000D (CR - Carriage Return `\r`, handles `\r\n` as single newline)
+/// - U+001C (FILE SEPARATOR)
+/// - U+001D (GROUP SEPARATOR)
+/// - U+001E (RECORD SEPARATOR)
+/// - U+0085 (NEL - Next Line)
+/// - U+2028 (LINE SEPARATOR)
+/// - U+2029 (PARAGRAPH SEPARATOR)
+///
+/// # Arguments
+///
+/// * `text`: The UTF-8 encoded byte slice to search.
+///
+/// # Returns
+///
+/// An `Option<IndexSpan>` containing the byte offset and length of the matched newline.
+/// The length can be 1-3 bytes for single characters, or 2 bytes for CRLF sequence.
+///
+/// Returns `None` if no newline is found.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla as sz;
 ///
 /// let text = "Hello\nWorld";
 /// let span = sz::find_newline_utf8(text).unwrap();
@@ -1446,6 +2357,156 @@ where
     }
 }
 
+// ----------------------------------------------------------------------
+// WTF-8 / OsStr support
+// ----------------------------------------------------------------------
+
+/// Borrows the raw WTF-8 bytes backing an `OsStr`, for feeding platform-native (e.g. Windows-style
+/// path) haystacks into the byte-level matchers and [`find_newline_wtf8`]/[`find_whitespace_wtf8`]
+/// without a lossy `to_string_lossy()` round-trip. Unix-only: `std::os::unix::ffi::OsStrExt`
+/// already exposes an `OsStr`'s bytes directly (they're arbitrary, potentially non-UTF-8 bytes on
+/// Unix), and those bytes satisfy `AsRef<[u8]>`, so `OsStr`/`OsString` work with every
+/// `StringZillableUnary`/`StringZillableBinary` method today via that std trait alone.
+///
+/// There is no stable equivalent on Windows: `OsStr` there is backed by WTF-8 internally, but std
+/// does not expose those raw bytes outside the standard library, so Windows callers still need
+/// `encode_wide()`/a lossy conversion for anything this crate can see.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(unix)] {
+/// use std::ffi::OsStr;
+/// use stringzilla::stringzilla::os_str_as_wtf8;
+///
+/// let path = OsStr::new("/tmp/caf\u{e9}");
+/// assert_eq!(os_str_as_wtf8(path), "/tmp/café".as_bytes());
+/// # }
+/// ```
+#[cfg(unix)]
+pub fn os_str_as_wtf8(text: &std::ffi::OsStr) -> &[u8] {
+    use std::os::unix::ffi::OsStrExt;
+    text.as_bytes()
+}
+
+/// Reconstructs an `OsString` from raw WTF-8 bytes, the inverse of [`os_str_as_wtf8`]. Unix-only,
+/// for the same reason as [`os_str_as_wtf8`].
+#[cfg(unix)]
+pub fn os_string_from_wtf8(bytes: Vec<u8>) -> std::ffi::OsString {
+    use std::os::unix::ffi::OsStringExt;
+    std::ffi::OsString::from_vec(bytes)
+}
+
+/// Decodes the WTF-8 unit starting at `bytes[0]`, returning its scalar value (or, for an unpaired
+/// surrogate's 3-byte encoding, the surrogate codepoint itself, `0xD800..=0xDFFF`) and the number
+/// of bytes it occupies. Unlike strict UTF-8 decoding, a 3-byte sequence encoding a surrogate
+/// (`0xED 0xA0..=0xBF 0x80..=0xBF`) is accepted as an opaque unit instead of being rejected, which
+/// is what lets [`find_newline_wtf8`]/[`find_whitespace_wtf8`] scan through WTF-8 produced by
+/// platform-native strings (Windows paths, `OsStr`) without aborting on unpaired surrogates. Any
+/// other byte that doesn't start a well-formed sequence is treated as a 1-byte opaque unit with no
+/// valid scalar value, so the scan always makes forward progress.
+fn decode_wtf8_unit(bytes: &[u8]) -> (u32, usize) {
+    let first = bytes[0];
+    let continuation = |b: u8| (b & 0b1100_0000) == 0b1000_0000;
+    if first < 0x80 {
+        return (first as u32, 1);
+    }
+    if first & 0b1110_0000 == 0b1100_0000 && bytes.len() >= 2 && continuation(bytes[1]) {
+        let scalar = ((first as u32 & 0x1F) << 6) | (bytes[1] as u32 & 0x3F);
+        return (scalar, 2);
+    }
+    if first & 0b1111_0000 == 0b1110_0000 && bytes.len() >= 3 && continuation(bytes[1]) && continuation(bytes[2]) {
+        let scalar =
+            ((first as u32 & 0x0F) << 12) | ((bytes[1] as u32 & 0x3F) << 6) | (bytes[2] as u32 & 0x3F);
+        return (scalar, 3); // includes the WTF-8 surrogate range 0xD800..=0xDFFF
+    }
+    if first & 0b1111_1000 == 0b1111_0000
+        && bytes.len() >= 4
+        && continuation(bytes[1])
+        && continuation(bytes[2])
+        && continuation(bytes[3])
+    {
+        let scalar = ((first as u32 & 0x07) << 18)
+            | ((bytes[1] as u32 & 0x3F) << 12)
+            | ((bytes[2] as u32 & 0x3F) << 6)
+            | (bytes[3] as u32 & 0x3F);
+        return (scalar, 4);
+    }
+    (u32::MAX, 1)
+}
+
+/// Returns `true` for the 7 Unicode newline codepoints [`find_newline_utf8`] recognizes (LF, VT,
+/// FF, CR, NEL, LS, PS); `\r\n` is handled as a 2-codepoint special case by the caller.
+#[inline]
+fn is_wtf8_newline_codepoint(c: u32) -> bool {
+    matches!(c, 0x0A | 0x0B | 0x0C | 0x0D | 0x85 | 0x2028 | 0x2029)
+}
+
+/// Returns `true` for the Unicode whitespace codepoints [`find_whitespace_utf8`] recognizes.
+#[inline]
+fn is_wtf8_whitespace_codepoint(c: u32) -> bool {
+    is_wtf8_newline_codepoint(c)
+        || matches!(c, 0x09 | 0x1F | 0x20 | 0xA0 | 0x1680 | 0x2000..=0x200A | 0x202F | 0x205F | 0x3000)
+}
+
+/// Finds the first newline in a WTF-8 encoded byte buffer, tolerating unpaired surrogate
+/// encodings (as produced by [`os_str_as_wtf8`]) as opaque units rather than aborting the scan.
+/// Recognizes the same codepoints as [`find_newline_utf8`], including `\r\n` as a single 2-byte
+/// match, but works directly on potentially-ill-formed-UTF-8 bytes instead of requiring validated
+/// UTF-8 input.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla::find_newline_wtf8;
+///
+/// let text = b"Hello\nWorld";
+/// let span = find_newline_wtf8(text).unwrap();
+/// assert_eq!(span.offset, 5);
+/// assert_eq!(span.length, 1);
+/// ```
+pub fn find_newline_wtf8(bytes: &[u8]) -> Option<IndexSpan> {
+    let mut position = 0;
+    while position < bytes.len() {
+        let (codepoint, unit_length) = decode_wtf8_unit(&bytes[position..]);
+        if is_wtf8_newline_codepoint(codepoint) {
+            let mut length = unit_length;
+            if codepoint == 0x0D && bytes[position + unit_length..].starts_with(b"\n") {
+                length += 1;
+            }
+            return Some(IndexSpan::new(position, length));
+        }
+        position += unit_length;
+    }
+    None
+}
+
+/// Finds the first whitespace codepoint in a WTF-8 encoded byte buffer, tolerating unpaired
+/// surrogate encodings as opaque units. Recognizes the same codepoints as
+/// [`find_whitespace_utf8`], but works directly on potentially-ill-formed-UTF-8 bytes.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla::find_whitespace_wtf8;
+///
+/// let text = b"Hello World";
+/// let span = find_whitespace_wtf8(text).unwrap();
+/// assert_eq!(span.offset, 5);
+/// assert_eq!(span.length, 1);
+/// ```
+pub fn find_whitespace_wtf8(bytes: &[u8]) -> Option<IndexSpan> {
+    let mut position = 0;
+    while position < bytes.len() {
+        let (codepoint, unit_length) = decode_wtf8_unit(&bytes[position..]);
+        if is_wtf8_whitespace_codepoint(codepoint) {
+            return Some(IndexSpan::new(position, unit_length));
+        }
+        position += unit_length;
+    }
+    None
+}
+
 /// Counts the number of UTF-8 characters in the text.
 ///
 /// This function efficiently counts UTF-8 characters by identifying character start bytes
@@ -1593,6 +2654,116 @@ impl<'a> Utf8View<'a> {
     pub fn iter(&self) -> Utf8Chars<'a> {
         Utf8Chars::new(self.octets)
     }
+
+    /// Returns a lossy iterator over the view's bytes, reporting runs of well-formed UTF-8
+    /// interspersed with the invalid byte sequences that interrupt them (each one as delimited by
+    /// `core::str::Utf8Error::error_len`, so consecutive bad bytes may surface as several pairs). Unlike
+    /// [`Utf8View::iter()`] (built on [`Utf8Chars`], which stops the moment it hits a codepoint it
+    /// can't decode), this never drops data: concatenating every `valid` and replacing every
+    /// non-empty `invalid` with U+FFFD reconstructs the standard lossy rendering of the input. See
+    /// [`Utf8Chunks`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stringzilla::stringzilla as sz;
+    ///
+    /// let octets = b"Hello\xFFWorld";
+    /// let view = sz::Utf8View::new(octets);
+    /// let chunks: Vec<_> = view.chunks().collect();
+    /// assert_eq!(chunks, vec![("Hello", &b"\xFF"[..]), ("World", &b""[..])]);
+    /// ```
+    pub fn chunks(&self) -> Utf8Chunks<'a> {
+        Utf8Chunks::new(self.octets)
+    }
+
+    /// Converts the view's bytes to a `str`, substituting U+FFFD (the replacement character) for
+    /// each invalid byte sequence. Borrows the original bytes when they're already valid UTF-8 in
+    /// their entirety, and only allocates when a substitution is actually needed. Built on
+    /// [`Utf8View::chunks()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stringzilla::stringzilla as sz;
+    /// use std::borrow::Cow;
+    ///
+    /// let valid = sz::Utf8View::new("Hello".as_bytes());
+    /// assert_eq!(valid.to_str_lossy(), Cow::Borrowed("Hello"));
+    ///
+    /// let invalid = sz::Utf8View::new(b"Hello\xFFWorld");
+    /// assert_eq!(invalid.to_str_lossy(), Cow::<str>::Owned("Hello\u{FFFD}World".to_string()));
+    /// ```
+    pub fn to_str_lossy(&self) -> std::borrow::Cow<'a, str> {
+        if let Ok(valid) = core::str::from_utf8(self.octets) {
+            return std::borrow::Cow::Borrowed(valid);
+        }
+
+        let mut owned = String::with_capacity(self.octets.len());
+        for (valid, invalid) in self.chunks() {
+            owned.push_str(valid);
+            if !invalid.is_empty() {
+                owned.push('\u{FFFD}');
+            }
+        }
+        std::borrow::Cow::Owned(owned)
+    }
+}
+
+/// Lossy decoding iterator over the bytes of a [`Utf8View`], yielding `(valid, invalid)` pairs:
+/// the longest prefix of well-formed UTF-8 found, followed by the single invalid byte sequence
+/// that interrupted it, as delimited by `core::str::Utf8Error::error_len` (empty on the last pair
+/// if the input ended on a valid boundary). Mirrors
+/// the chunking `std`/`bstr` use to implement lossy UTF-8 conversion, without ever truncating
+/// iteration the way [`Utf8Chars`] does on malformed input.
+///
+/// Typically created through [`Utf8View::chunks()`].
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla as sz;
+///
+/// let view = sz::Utf8View::new(b"Hello\xFFWorld\xC0");
+/// let chunks: Vec<_> = view.chunks().collect();
+/// assert_eq!(chunks, vec![("Hello", &b"\xFF"[..]), ("World", &b"\xC0"[..])]);
+/// ```
+pub struct Utf8Chunks<'a> {
+    rest: &'a [u8],
+}
+
+impl<'a> Utf8Chunks<'a> {
+    fn new(octets: &'a [u8]) -> Self {
+        Self { rest: octets }
+    }
+}
+
+impl<'a> Iterator for Utf8Chunks<'a> {
+    type Item = (&'a str, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        match core::str::from_utf8(self.rest) {
+            Ok(valid) => {
+                self.rest = &[];
+                Some((valid, &[]))
+            }
+            Err(error) => {
+                let valid_up_to = error.valid_up_to();
+                // Safety: `from_utf8` guarantees everything before `valid_up_to` is well-formed.
+                let valid = unsafe { core::str::from_utf8_unchecked(&self.rest[..valid_up_to]) };
+                // A `None` error length means the tail is an incomplete (but not invalid) sequence
+                // cut short by the end of the slice; treat the remaining bytes as the invalid run.
+                let invalid_length = error.error_len().unwrap_or(self.rest.len() - valid_up_to);
+                let invalid = &self.rest[valid_up_to..valid_up_to + invalid_length];
+                self.rest = &self.rest[valid_up_to + invalid_length..];
+                Some((valid, invalid))
+            }
+        }
+    }
 }
 
 /// Iterator over UTF-8 characters using batched decoding.
@@ -1694,27 +2865,628 @@ impl<'a> Iterator for Utf8Chars<'a> {
     }
 }
 
-/// Randomizes the contents of a given byte slice `text` using characters from
-/// a specified `alphabet`. This function mutates `text` in place, replacing each
-/// byte with a random one from `alphabet`. It is designed for situations where
-/// you need to generate random strings or data sequences based on a specific set
-/// of characters, such as generating random DNA sequences or testing inputs.
-///
-/// # Arguments
+// ----------------------------------------------------------------------
+// Unicode text segmentation (graphemes / words / sentences)
+// ----------------------------------------------------------------------
+
+/// Returns `true` for codepoints that UAX #29 classifies as "Grapheme_Extend" or "SpacingMark":
+/// combining marks that attach to the preceding base character rather than starting a new
+/// grapheme cluster. This covers the combining-mark blocks that show up in real-world text
+/// (accents, Indic vowel signs, emoji variation selectors) without pulling in the full Unicode
+/// character database.
+#[inline]
+fn is_grapheme_extend(c: char) -> bool {
+    matches!(c as u32,
+        0x0300..=0x036F   // Combining Diacritical Marks
+        | 0x0483..=0x0489 // Combining Cyrillic
+        | 0x0591..=0x05BD | 0x05BF | 0x05C1..=0x05C2 | 0x05C4..=0x05C5 | 0x05C7 // Hebrew points
+        | 0x0610..=0x061A | 0x064B..=0x065F | 0x0670 // Arabic marks
+        | 0x06D6..=0x06DC | 0x06DF..=0x06E4 | 0x06E7..=0x06E8 | 0x06EA..=0x06ED
+        | 0x0900..=0x0903 | 0x093A..=0x094F | 0x0951..=0x0957 // Devanagari
+        | 0x20D0..=0x20FF // Combining Diacritical Marks for Symbols
+        | 0x1AB0..=0x1AFF // Combining Diacritical Marks Extended
+        | 0x1DC0..=0x1DFF // Combining Diacritical Marks Supplement
+        | 0xFE00..=0xFE0F // Variation Selectors
+        | 0xFE20..=0xFE2F // Combining Half Marks
+        | 0x200D          // Zero Width Joiner
+    )
+}
+
+/// Returns `true` for Unicode regional indicator symbols (`U+1F1E6..=U+1F1FF`), which UAX #29
+/// requires pairing up into a single grapheme cluster (e.g. the two-codepoint flag emoji).
+#[inline]
+fn is_regional_indicator(c: char) -> bool {
+    matches!(c as u32, 0x1F1E6..=0x1F1FF)
+}
+
+/// Returns `true` for codepoints UAX #29 treats as part of a "word" (`Alphabetic`, `Numeric`, or
+/// the joining `_`/`Extend`/`Format` classes), as opposed to whitespace or punctuation that
+/// separates words.
+#[inline]
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || is_grapheme_extend(c)
+}
+
+/// Byte-indexed codepoint iterator used internally by the segmentation iterators below. Sources
+/// its codepoints from [`Utf8Chars`] (the batched `sz_utf8_unpack_chunk` decoder) rather than
+/// `str::char_indices`, so grapheme/word segmentation decodes each byte only once; byte offsets
+/// are then reconstructed by accumulating `char::len_utf8()` as codepoints are yielded.
+struct Utf8CharCursor<'a> {
+    chars: Utf8Chars<'a>,
+    position: usize,
+}
+
+impl<'a> Utf8CharCursor<'a> {
+    fn new(octets: &'a [u8]) -> Self {
+        Self { chars: Utf8Chars::new(octets), position: 0 }
+    }
+}
+
+impl<'a> Iterator for Utf8CharCursor<'a> {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.chars.next()?;
+        let start = self.position;
+        self.position += c.len_utf8();
+        Some((start, c))
+    }
+}
+
+/// Byte-indexed codepoint iterator over a [`Utf8View`], yielding `(byte_offset, char, len)`
+/// triples: a fast, SIMD-batched replacement for `str::char_indices` that also reports each
+/// codepoint's encoded length instead of making callers recompute it via `char::len_utf8()`.
+/// Built on the same [`Utf8Chars`] (`sz_utf8_unpack_chunk`) decoder that backs
+/// [`Utf8View::iter()`] and the grapheme/word/sentence segmentation iterators, so it shares their
+/// behavior on malformed input: decoding stops at the first byte sequence it can't decode (see
+/// [`Utf8Chunks`] for a lossy alternative that never drops data).
 ///
-/// * `buffer`: A mutable reference to the data to randomize. This data will be mutated in place.
-/// * `nonce`: A 64-bit "number used once" (nonce) value to seed the random number generator.
+/// Typically created through [`StringZillableUnary::sz_char_indices`].
 ///
 /// # Examples
 ///
 /// ```
-/// use stringzilla::stringzilla as sz;
-/// let mut buffer = vec![0; 10];
-/// sz::fill_random(&mut buffer, 42);
-/// ```
+/// use stringzilla::sz::StringZillableUnary;
 ///
-/// After than,  `buffer` is filled with random byte values from 0 to 255.
-pub fn fill_random<T>(buffer: &mut T, nonce: u64)
+/// let text = "a🌍b";
+/// let indices: Vec<(usize, char, usize)> = text.sz_char_indices().collect();
+/// assert_eq!(indices, vec![(0, 'a', 1), (1, '🌍', 4), (5, 'b', 1)]);
+/// ```
+pub struct Utf8CharIndices<'a> {
+    chars: Utf8Chars<'a>,
+    position: usize,
+}
+
+impl<'a> Utf8CharIndices<'a> {
+    fn new(octets: &'a [u8]) -> Self {
+        Self { chars: Utf8Chars::new(octets), position: 0 }
+    }
+}
+
+impl<'a> Iterator for Utf8CharIndices<'a> {
+    type Item = (usize, char, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.chars.next()?;
+        let start = self.position;
+        let len = c.len_utf8();
+        self.position += len;
+        Some((start, c, len))
+    }
+}
+
+/// Byte-indexed grapheme-cluster iterator over a [`Utf8View`], implementing a pragmatic subset
+/// of the UAX #29 grapheme-cluster boundary rules: a cluster is a base codepoint followed by any
+/// run of combining marks ([`is_grapheme_extend`]), with a special case pairing up regional
+/// indicators two at a time (flag emoji).
+pub struct Utf8GraphemeIndices<'a> {
+    text: &'a str,
+    chars: Utf8CharCursor<'a>,
+    pending: Option<(usize, char)>,
+}
+
+impl<'a> Utf8GraphemeIndices<'a> {
+    fn new(text: &'a str) -> Self {
+        let mut chars = Utf8CharCursor::new(text.as_bytes());
+        let pending = chars.next();
+        Self { text, chars, pending }
+    }
+}
+
+impl<'a> Iterator for Utf8GraphemeIndices<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, first_char) = self.pending?;
+        let mut end = start + first_char.len_utf8();
+        let mut regional_indicators_seen = if is_regional_indicator(first_char) { 1 } else { 0 };
+
+        loop {
+            match self.chars.next() {
+                Some((offset, c)) if is_grapheme_extend(c) => {
+                    end = offset + c.len_utf8();
+                }
+                Some((offset, c)) if regional_indicators_seen == 1 && is_regional_indicator(c) => {
+                    end = offset + c.len_utf8();
+                    regional_indicators_seen += 1;
+                }
+                next => {
+                    self.pending = next;
+                    break;
+                }
+            }
+        }
+
+        Some((start, &self.text[start..end]))
+    }
+}
+
+/// Grapheme-cluster iterator over a [`Utf8View`], yielding each cluster as a `&str`. See
+/// [`Utf8GraphemeIndices`] for the boundary rules applied.
+pub struct Utf8Graphemes<'a>(Utf8GraphemeIndices<'a>);
+
+impl<'a> Iterator for Utf8Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, s)| s)
+    }
+}
+
+/// Byte-indexed word iterator over a [`Utf8View`], yielding maximal runs of [`is_word_char`]
+/// codepoints and skipping over whitespace/punctuation between them, following the same
+/// word-break property classes UAX #29 defines (alphabetic, numeric, underscore, and the
+/// extending marks that keep a word together, e.g. `don't` staying one word-like run per side
+/// of the apostrophe).
+pub struct Utf8WordIndices<'a> {
+    text: &'a str,
+    chars: Utf8CharCursor<'a>,
+    pending: Option<(usize, char)>,
+}
+
+impl<'a> Utf8WordIndices<'a> {
+    fn new(text: &'a str) -> Self {
+        let mut chars = Utf8CharCursor::new(text.as_bytes());
+        let pending = chars.next();
+        Self { text, chars, pending }
+    }
+}
+
+impl<'a> Iterator for Utf8WordIndices<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Skip leading non-word codepoints.
+        let (start, first_char) = loop {
+            match self.pending {
+                Some((offset, c)) if is_word_char(c) => break (offset, c),
+                Some(_) => self.pending = self.chars.next(),
+                None => return None,
+            }
+        };
+
+        let mut end = start + first_char.len_utf8();
+        self.pending = self.chars.next();
+        while let Some((offset, c)) = self.pending {
+            if !is_word_char(c) {
+                break;
+            }
+            end = offset + c.len_utf8();
+            self.pending = self.chars.next();
+        }
+
+        Some((start, &self.text[start..end]))
+    }
+}
+
+/// Word iterator over a [`Utf8View`], yielding each word as a `&str`. See [`Utf8WordIndices`]
+/// for the boundary rules applied.
+pub struct Utf8Words<'a>(Utf8WordIndices<'a>);
+
+impl<'a> Iterator for Utf8Words<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, s)| s)
+    }
+}
+
+/// Byte-indexed sentence iterator over a [`Utf8View`], splitting after a sentence terminator
+/// (`.`, `!`, `?`) together with any immediately following closing punctuation and whitespace,
+/// matching the UAX #29 convention that trailing whitespace belongs to the sentence it ends
+/// rather than the one that follows. A final sentence lacking a terminator is still yielded.
+pub struct Utf8SentenceIndices<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Utf8SentenceIndices<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { text, position: 0 }
+    }
+}
+
+impl<'a> Iterator for Utf8SentenceIndices<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position >= self.text.len() {
+            return None;
+        }
+
+        let start = self.position;
+        let rest = &self.text[start..];
+        let mut chars = rest.char_indices();
+        let mut terminator_end: Option<usize> = None;
+
+        for (offset, c) in &mut chars {
+            if matches!(c, '.' | '!' | '?') {
+                terminator_end = Some(start + offset + c.len_utf8());
+                break;
+            }
+        }
+
+        let mut end = match terminator_end {
+            Some(end) => end,
+            None => self.text.len(),
+        };
+
+        // Absorb closing quotes/brackets and trailing whitespace into this sentence.
+        if terminator_end.is_some() {
+            let trailer_start = end;
+            let mut trailer = self.text[end..].char_indices();
+            loop {
+                match trailer.next() {
+                    Some((offset, c)) if matches!(c, '"' | '\'' | ')' | ']' | '”' | '’') => {
+                        end = trailer_start + offset + c.len_utf8();
+                    }
+                    _ => break,
+                }
+            }
+            let whitespace_start = end;
+            let mut whitespace = self.text[end..].char_indices();
+            loop {
+                match whitespace.next() {
+                    Some((offset, c)) if c.is_whitespace() => {
+                        end = whitespace_start + offset + c.len_utf8();
+                    }
+                    _ => break,
+                }
+            }
+        }
+
+        self.position = end;
+        Some((start, &self.text[start..end]))
+    }
+}
+
+/// Sentence iterator over a [`Utf8View`], yielding each sentence as a `&str`. See
+/// [`Utf8SentenceIndices`] for the boundary rules applied.
+pub struct Utf8Sentences<'a>(Utf8SentenceIndices<'a>);
+
+impl<'a> Iterator for Utf8Sentences<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, s)| s)
+    }
+}
+
+impl<'a> Utf8View<'a> {
+    /// Interprets the view's bytes as UTF-8 text for segmentation purposes. Invalid UTF-8 is
+    /// treated as having no further boundaries, since the grapheme/word/sentence iterators need
+    /// decoded codepoints; well-formed input (the common case, since this view is normally built
+    /// from a `&str`) is unaffected.
+    fn as_text(&self) -> &'a str {
+        core::str::from_utf8(self.octets).unwrap_or("")
+    }
+
+    /// Returns an iterator over the grapheme clusters of this view. See [`Utf8GraphemeIndices`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stringzilla::stringzilla as sz;
+    ///
+    /// let view = sz::Utf8View::new("e\u{0301}clair".as_bytes()); // "é" as e + combining acute
+    /// let clusters: Vec<&str> = view.graphemes().collect();
+    /// assert_eq!(clusters[0], "e\u{0301}");
+    /// ```
+    pub fn graphemes(&self) -> Utf8Graphemes<'a> {
+        Utf8Graphemes(Utf8GraphemeIndices::new(self.as_text()))
+    }
+
+    /// Returns an iterator over `(byte_offset, grapheme)` pairs. See [`Utf8GraphemeIndices`].
+    pub fn grapheme_indices(&self) -> Utf8GraphemeIndices<'a> {
+        Utf8GraphemeIndices::new(self.as_text())
+    }
+
+    /// Returns an iterator over the words of this view, skipping whitespace and punctuation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stringzilla::stringzilla as sz;
+    ///
+    /// let view = sz::Utf8View::new("Hello, world!".as_bytes());
+    /// let words: Vec<&str> = view.words().collect();
+    /// assert_eq!(words, vec!["Hello", "world"]);
+    /// ```
+    pub fn words(&self) -> Utf8Words<'a> {
+        Utf8Words(Utf8WordIndices::new(self.as_text()))
+    }
+
+    /// Returns an iterator over `(byte_offset, word)` pairs. See [`Utf8WordIndices`].
+    pub fn word_indices(&self) -> Utf8WordIndices<'a> {
+        Utf8WordIndices::new(self.as_text())
+    }
+
+    /// Returns an iterator over the sentences of this view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stringzilla::stringzilla as sz;
+    ///
+    /// let view = sz::Utf8View::new("Hi there! How are you?".as_bytes());
+    /// let sentences: Vec<&str> = view.sentences().collect();
+    /// assert_eq!(sentences, vec!["Hi there! ", "How are you?"]);
+    /// ```
+    pub fn sentences(&self) -> Utf8Sentences<'a> {
+        Utf8Sentences(Utf8SentenceIndices::new(self.as_text()))
+    }
+
+    /// Returns an iterator over `(byte_offset, sentence)` pairs. See [`Utf8SentenceIndices`].
+    pub fn sentence_indices(&self) -> Utf8SentenceIndices<'a> {
+        Utf8SentenceIndices::new(self.as_text())
+    }
+
+    /// Returns an iterator over `(byte_offset, char, len)` triples, decoding this view's bytes
+    /// batch by batch the same way [`Utf8View::iter()`] does. See [`Utf8CharIndices`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stringzilla::stringzilla as sz;
+    ///
+    /// let view = sz::Utf8View::new("a🌍b".as_bytes());
+    /// let indices: Vec<(usize, char, usize)> = view.char_indices().collect();
+    /// assert_eq!(indices, vec![(0, 'a', 1), (1, '🌍', 4), (5, 'b', 1)]);
+    /// ```
+    pub fn char_indices(&self) -> Utf8CharIndices<'a> {
+        Utf8CharIndices::new(self.octets)
+    }
+}
+
+// ----------------------------------------------------------------------
+// Unicode normalization (NFD / NFKD / NFC / NFKC)
+// ----------------------------------------------------------------------
+
+/// Canonical decomposition table: precomposed Latin-1 Supplement / Latin Extended-A letters to
+/// their base letter plus combining mark. Like [`is_grapheme_extend`], this is a pragmatic subset
+/// covering the Latin letters with diacritics that make up the overwhelming majority of
+/// real-world NFD input (French, Spanish, German, Portuguese, etc.), not the full Unicode
+/// Character Database's `UnicodeData.txt` decomposition column.
+const NFD_DECOMPOSITIONS: &[(char, char, char)] = &[
+    ('À', 'A', '\u{0300}'), ('Á', 'A', '\u{0301}'), ('Â', 'A', '\u{0302}'), ('Ã', 'A', '\u{0303}'),
+    ('Ä', 'A', '\u{0308}'), ('Å', 'A', '\u{030A}'), ('Ç', 'C', '\u{0327}'), ('È', 'E', '\u{0300}'),
+    ('É', 'E', '\u{0301}'), ('Ê', 'E', '\u{0302}'), ('Ë', 'E', '\u{0308}'), ('Ì', 'I', '\u{0300}'),
+    ('Í', 'I', '\u{0301}'), ('Î', 'I', '\u{0302}'), ('Ï', 'I', '\u{0308}'), ('Ñ', 'N', '\u{0303}'),
+    ('Ò', 'O', '\u{0300}'), ('Ó', 'O', '\u{0301}'), ('Ô', 'O', '\u{0302}'), ('Õ', 'O', '\u{0303}'),
+    ('Ö', 'O', '\u{0308}'), ('Ù', 'U', '\u{0300}'), ('Ú', 'U', '\u{0301}'), ('Û', 'U', '\u{0302}'),
+    ('Ü', 'U', '\u{0308}'), ('Ý', 'Y', '\u{0301}'), ('à', 'a', '\u{0300}'), ('á', 'a', '\u{0301}'),
+    ('â', 'a', '\u{0302}'), ('ã', 'a', '\u{0303}'), ('ä', 'a', '\u{0308}'), ('å', 'a', '\u{030A}'),
+    ('ç', 'c', '\u{0327}'), ('è', 'e', '\u{0300}'), ('é', 'e', '\u{0301}'), ('ê', 'e', '\u{0302}'),
+    ('ë', 'e', '\u{0308}'), ('ì', 'i', '\u{0300}'), ('í', 'i', '\u{0301}'), ('î', 'i', '\u{0302}'),
+    ('ï', 'i', '\u{0308}'), ('ñ', 'n', '\u{0303}'), ('ò', 'o', '\u{0300}'), ('ó', 'o', '\u{0301}'),
+    ('ô', 'o', '\u{0302}'), ('õ', 'o', '\u{0303}'), ('ö', 'o', '\u{0308}'), ('ù', 'u', '\u{0300}'),
+    ('ú', 'u', '\u{0301}'), ('û', 'u', '\u{0302}'), ('ü', 'u', '\u{0308}'), ('ý', 'y', '\u{0301}'),
+    ('ÿ', 'y', '\u{0308}'),
+];
+
+/// Compatibility-only decompositions layered on top of [`NFD_DECOMPOSITIONS`] for NFKD/NFKC:
+/// typographic ligatures and other compatibility variants that decompose to plain base letters
+/// rather than a base-plus-mark pair. Also a pragmatic subset, not the full UCD.
+const NFKD_COMPATIBILITY_DECOMPOSITIONS: &[(char, &str)] = &[
+    ('ﬁ', "fi"),
+    ('ﬂ', "fl"),
+    ('ﬀ', "ff"),
+    ('ﬃ', "ffi"),
+    ('ﬄ', "ffl"),
+];
+
+/// Returns the Canonical Combining Class (UAX #15) for the combining marks this module
+/// recognizes, used to stably reorder runs of non-starters during decomposition. Marks not
+/// listed here (including any outside [`is_grapheme_extend`]'s pragmatic combining-mark ranges)
+/// are treated as CCC 0, i.e. as starters that never get reordered.
+#[inline]
+fn canonical_combining_class(c: char) -> u8 {
+    match c {
+        '\u{0327}' | '\u{0328}' => 202, // cedilla, ogonek: "below" attachment
+        '\u{0323}' | '\u{0324}' | '\u{0325}' | '\u{0326}' | '\u{0329}' | '\u{032A}' | '\u{032B}'
+        | '\u{032C}' | '\u{032D}' | '\u{032E}' | '\u{032F}' | '\u{0330}' | '\u{0331}' => 220, // below
+        '\u{0300}'..='\u{0314}' | '\u{0341}' | '\u{0342}' => 230, // above
+        _ if is_grapheme_extend(c) => 230,
+        _ => 0,
+    }
+}
+
+/// Recursively decomposes `c` into its canonical (`compatibility: false`) or compatibility
+/// (`compatibility: true`) decomposition, appending scalars to `out`. Characters with no mapping
+/// decompose to themselves.
+fn decompose_char(c: char, compatibility: bool, out: &mut Vec<char>) {
+    if compatibility {
+        if let Some((_, expansion)) = NFKD_COMPATIBILITY_DECOMPOSITIONS.iter().find(|(from, _)| *from == c) {
+            for expanded in expansion.chars() {
+                decompose_char(expanded, compatibility, out);
+            }
+            return;
+        }
+    }
+    if let Some((_, base, mark)) = NFD_DECOMPOSITIONS.iter().find(|(from, _, _)| *from == c) {
+        decompose_char(*base, compatibility, out);
+        decompose_char(*mark, compatibility, out);
+        return;
+    }
+    out.push(c);
+}
+
+/// Applies the Canonical Ordering Algorithm (UAX #15) in place: stably sorts each maximal run of
+/// non-starter (CCC != 0) codepoints by [`canonical_combining_class`], leaving CCC-0 starters as
+/// run boundaries untouched.
+fn canonical_reorder(codepoints: &mut Vec<char>) {
+    let mut start = 0;
+    while start < codepoints.len() {
+        if canonical_combining_class(codepoints[start]) == 0 {
+            start += 1;
+            continue;
+        }
+        let mut end = start;
+        while end < codepoints.len() && canonical_combining_class(codepoints[end]) != 0 {
+            end += 1;
+        }
+        codepoints[start..end].sort_by_key(|&c| canonical_combining_class(c));
+        start = end;
+    }
+}
+
+/// Applies the Canonical Composition Algorithm (UAX #15) to an already-decomposed, canonically
+/// ordered sequence: scans left to right and, for each starter, tries to compose it with a
+/// following non-blocked character via [`NFD_DECOMPOSITIONS`] (treated as the composition table
+/// in reverse), skipping any mark whose CCC is blocked by an intervening mark of equal or higher
+/// class.
+fn canonical_compose(codepoints: &[char]) -> Vec<char> {
+    let mut output: Vec<char> = Vec::with_capacity(codepoints.len());
+    let mut last_starter: Option<usize> = None;
+    for &c in codepoints {
+        let mark_class = canonical_combining_class(c);
+        if mark_class == 0 {
+            output.push(c);
+            last_starter = Some(output.len() - 1);
+            continue;
+        }
+        if let Some(starter_index) = last_starter {
+            // A mark is blocked from composing with `starter_index` if some closer mark already
+            // in `output` has an equal-or-higher combining class.
+            let blocked = output[starter_index + 1..].iter().any(|&o| canonical_combining_class(o) >= mark_class);
+            if !blocked {
+                let starter = output[starter_index];
+                if let Some((composed, _, _)) =
+                    NFD_DECOMPOSITIONS.iter().find(|(_, base, mark)| *base == starter && *mark == c)
+                {
+                    output[starter_index] = *composed;
+                    continue;
+                }
+            }
+        }
+        output.push(c);
+    }
+    output
+}
+
+/// Decomposes `text` under canonical (NFD) decomposition: recursive decomposition of each
+/// codepoint followed by the Canonical Ordering Algorithm. See [`NFD_DECOMPOSITIONS`] for the
+/// scope of recognized mappings.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla::sz_nfd;
+///
+/// let normalized: String = sz_nfd("café").collect();
+/// assert_eq!(normalized, "cafe\u{0301}");
+/// ```
+pub fn sz_nfd(text: &str) -> impl Iterator<Item = char> {
+    let mut codepoints = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        decompose_char(c, false, &mut codepoints);
+    }
+    canonical_reorder(&mut codepoints);
+    codepoints.into_iter()
+}
+
+/// Decomposes `text` under compatibility (NFKD) decomposition: like [`sz_nfd`], but also expands
+/// compatibility mappings such as typographic ligatures (see
+/// [`NFKD_COMPATIBILITY_DECOMPOSITIONS`]).
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla::sz_nfkd;
+///
+/// let normalized: String = sz_nfkd("ﬁle").collect();
+/// assert_eq!(normalized, "file");
+/// ```
+pub fn sz_nfkd(text: &str) -> impl Iterator<Item = char> {
+    let mut codepoints = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        decompose_char(c, true, &mut codepoints);
+    }
+    canonical_reorder(&mut codepoints);
+    codepoints.into_iter()
+}
+
+/// Composes `text` under canonical (NFC) normalization: canonical decomposition followed by the
+/// Canonical Composition Algorithm.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla::sz_nfc;
+///
+/// let normalized: String = sz_nfc("cafe\u{0301}").collect();
+/// assert_eq!(normalized, "café");
+/// ```
+pub fn sz_nfc(text: &str) -> impl Iterator<Item = char> {
+    let mut codepoints = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        decompose_char(c, false, &mut codepoints);
+    }
+    canonical_reorder(&mut codepoints);
+    canonical_compose(&codepoints).into_iter()
+}
+
+/// Composes `text` under compatibility (NFKC) normalization: compatibility decomposition followed
+/// by the Canonical Composition Algorithm.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla::sz_nfkc;
+///
+/// let normalized: String = sz_nfkc("ﬁancé").collect();
+/// assert_eq!(normalized, "fiancé");
+/// ```
+pub fn sz_nfkc(text: &str) -> impl Iterator<Item = char> {
+    let mut codepoints = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        decompose_char(c, true, &mut codepoints);
+    }
+    canonical_reorder(&mut codepoints);
+    canonical_compose(&codepoints).into_iter()
+}
+
+/// Randomizes the contents of a given byte slice `text` using characters from
+/// a specified `alphabet`. This function mutates `text` in place, replacing each
+/// byte with a random one from `alphabet`. It is designed for situations where
+/// you need to generate random strings or data sequences based on a specific set
+/// of characters, such as generating random DNA sequences or testing inputs.
+///
+/// # Arguments
+///
+/// * `buffer`: A mutable reference to the data to randomize. This data will be mutated in place.
+/// * `nonce`: A 64-bit "number used once" (nonce) value to seed the random number generator.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla as sz;
+/// let mut buffer = vec![0; 10];
+/// sz::fill_random(&mut buffer, 42);
+/// ```
+///
+/// After than,  `buffer` is filled with random byte values from 0 to 255.
+pub fn fill_random<T>(buffer: &mut T, nonce: u64)
 where
     T: AsMut<[u8]> + ?Sized, // Allows for mutable references to dynamically sized types.
 {
@@ -2029,266 +3801,1252 @@ where
     }
 }
 
-pub trait Matcher<'a> {
-    fn find(&self, haystack: &'a [u8]) -> Option<usize>;
-    fn needle_length(&self) -> usize;
-    fn skip_length(&self, include_overlaps: bool, is_reverse: bool) -> usize;
+// ----------------------------------------------------------------------
+// Multi-pattern search
+// ----------------------------------------------------------------------
+
+/// A match produced by [`find_many`]: the index of the matched needle within the patterns slice
+/// passed in, and the byte offset in the haystack where the match starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ManyMatch {
+    /// Index into the `needles` slice originally passed to [`find_many`].
+    pub pattern_index: usize,
+    /// Byte offset of the match within the haystack.
+    pub offset: usize,
 }
 
-pub enum MatcherType<'a> {
-    Find(&'a [u8]),
-    RFind(&'a [u8]),
-    FindFirstOf(&'a [u8]),
-    FindLastOf(&'a [u8]),
-    FindFirstNotOf(&'a [u8]),
-    FindLastNotOf(&'a [u8]),
+/// A compiled Aho-Corasick automaton over a fixed set of needles, enabling a single
+/// left-to-right scan of the haystack to report every occurrence of every needle.
+///
+/// Construction builds the goto/fail/output links classically: a trie over all needles, a
+/// breadth-first pass wiring each node's failure link to the longest proper suffix that is also
+/// a trie prefix, and output links collapsed into a per-node list of pattern indices completing
+/// at that node (following fail links transitively). [`find_any`] runs this same automaton and
+/// stops at the first match; there is no separate SIMD prefilter path for small needle sets.
+///
+/// Matches are reported leftmost-longest, non-overlapping: once a needle completes, the scan holds
+/// it as a pending candidate rather than reporting it right away, since a direct trie edge out of
+/// the current state may still extend it into a longer match sharing the same start (e.g. `"\r"`
+/// vs `"\r\n"`). The candidate is only flushed - and the scan resumes right after it - once the
+/// automaton falls back to the root state with nothing left to extend it. When several needles
+/// complete at exactly the same position (one being a suffix of another), the longest of those
+/// is preferred, since that comparison is free given the automaton's output links.
+pub struct AhoCorasick {
+    /// `goto[state][byte]` is the next state, or `0` (the root) if there is no explicit edge and
+    /// no better option was found while building the automaton.
+    goto_links: Vec<[u32; 256]>,
+    /// `fail[state]` is the state to fall back to on a mismatch.
+    fail_links: Vec<u32>,
+    /// Needle indices that complete at each state, including those reached via fail links.
+    outputs: Vec<Vec<usize>>,
+    /// Original needle lengths, indexed by pattern index.
+    needle_lengths: Vec<usize>,
 }
 
-impl<'a> Matcher<'a> for MatcherType<'a> {
-    fn find(&self, haystack: &'a [u8]) -> Option<usize> {
-        match self {
-            MatcherType::Find(needle) => find(haystack, needle),
-            MatcherType::RFind(needle) => rfind(haystack, needle),
-            MatcherType::FindFirstOf(needles) => find_byte_from(haystack, needles),
-            MatcherType::FindLastOf(needles) => rfind_byte_from(haystack, needles),
-            MatcherType::FindFirstNotOf(needles) => find_byte_not_from(haystack, needles),
-            MatcherType::FindLastNotOf(needles) => rfind_byte_not_from(haystack, needles),
+impl AhoCorasick {
+    /// Builds an automaton over `needles`. Empty needles are rejected with [`Status::UnexpectedDimensions`]
+    /// since a zero-length match can't be reported at a single unambiguous offset.
+    pub fn new<N: AsRef<[u8]>>(needles: &[N]) -> Result<Self, Status> {
+        if needles.iter().any(|n| n.as_ref().is_empty()) {
+            return Err(Status::UnexpectedDimensions);
         }
-    }
 
-    fn needle_length(&self) -> usize {
-        match self {
-            MatcherType::Find(needle) | MatcherType::RFind(needle) => needle.len(),
-            _ => 1,
+        // Build the trie.
+        let mut goto_links: Vec<[u32; 256]> = vec![[0u32; 256]];
+        let mut outputs: Vec<Vec<usize>> = vec![Vec::new()];
+        let mut fail_links: Vec<u32> = vec![0];
+        let mut has_edge: Vec<[bool; 256]> = vec![[false; 256]];
+
+        for (pattern_index, needle) in needles.iter().enumerate() {
+            let mut state = 0u32;
+            for &byte in needle.as_ref() {
+                if has_edge[state as usize][byte as usize] {
+                    state = goto_links[state as usize][byte as usize];
+                } else {
+                    goto_links.push([0u32; 256]);
+                    outputs.push(Vec::new());
+                    fail_links.push(0);
+                    has_edge.push([false; 256]);
+                    let next_state = (goto_links.len() - 1) as u32;
+                    goto_links[state as usize][byte as usize] = next_state;
+                    has_edge[state as usize][byte as usize] = true;
+                    state = next_state;
+                }
+            }
+            outputs[state as usize].push(pattern_index);
         }
-    }
 
-    fn skip_length(&self, include_overlaps: bool, is_reverse: bool) -> usize {
-        match (include_overlaps, is_reverse) {
-            (true, true) => self.needle_length().saturating_sub(1),
-            (true, false) => 1,
-            (false, true) => 0,
-            (false, false) => self.needle_length(),
+        // Breadth-first pass computing fail links, and completing `goto_links` into a full
+        // transition function (so the scan never needs to walk fail links itself).
+        let mut queue: std::collections::VecDeque<u32> = std::collections::VecDeque::new();
+        for byte in 0..256usize {
+            if has_edge[0][byte] {
+                let child = goto_links[0][byte];
+                fail_links[child as usize] = 0;
+                queue.push_back(child);
+            }
         }
-    }
-}
 
-/// An iterator over non-overlapping matches of a pattern in a string slice.
-/// This iterator yields the matched substrings in the order they are found.
-///
-/// # Examples
-///
-/// ```
-/// use stringzilla::{stringzilla as sz, stringzilla::{MatcherType, RangeMatches}};
-///
-/// let haystack = b"abababa";
-/// let matcher = MatcherType::Find(b"aba");
-/// let matches: Vec<&[u8]> = RangeMatches::new(haystack, matcher, false).collect();
-/// assert_eq!(matches, vec![b"aba", b"aba"]);
-/// ```
-pub struct RangeMatches<'a> {
+        while let Some(state) = queue.pop_front() {
+            for byte in 0..256usize {
+                if has_edge[state as usize][byte] {
+                    let child = goto_links[state as usize][byte];
+                    let fallback = fail_links[state as usize];
+                    fail_links[child as usize] = goto_links[fallback as usize][byte];
+                    let fallback_outputs = outputs[fail_links[child as usize] as usize].clone();
+                    outputs[child as usize].extend(fallback_outputs);
+                    queue.push_back(child);
+                } else {
+                    goto_links[state as usize][byte] = goto_links[fail_links[state as usize] as usize][byte];
+                }
+            }
+        }
+
+        Ok(Self {
+            goto_links,
+            fail_links,
+            outputs,
+            needle_lengths: needles.iter().map(|n| n.as_ref().len()).collect(),
+        })
+    }
+
+    /// Scans `haystack` for every non-overlapping, leftmost-longest match of any compiled needle.
+    pub fn find_many(&self, haystack: &[u8]) -> Vec<ManyMatch> {
+        let mut matches = Vec::new();
+        let mut state = 0u32;
+        let mut position = 0usize;
+        let mut pending: Option<(usize, usize)> = None; // (end, pattern_index)
+        while position < haystack.len() {
+            state = self.goto_links[state as usize][haystack[position] as usize];
+            position += 1;
+            if let Some(&pattern_index) = self.outputs[state as usize].iter().max_by_key(|&&p| self.needle_lengths[p])
+            {
+                pending = Some((position, pattern_index));
+            }
+            // Only flush once the automaton falls back to the root: a state's output can still be
+            // extended into a longer match reachable by a direct trie edge (e.g. "\r" vs "\r\n"),
+            // so the candidate is held until no such extension remains.
+            if state == 0 {
+                if let Some((end, pattern_index)) = pending.take() {
+                    matches.push(ManyMatch { pattern_index, offset: end - self.needle_lengths[pattern_index] });
+                    position = end;
+                }
+            }
+        }
+        if let Some((end, pattern_index)) = pending {
+            matches.push(ManyMatch { pattern_index, offset: end - self.needle_lengths[pattern_index] });
+        }
+        matches
+    }
+
+    /// Returns the first match of any compiled needle, or `None` if none occur in `haystack`.
+    pub fn find_any(&self, haystack: &[u8]) -> Option<ManyMatch> {
+        let mut state = 0u32;
+        let mut pending: Option<(usize, usize)> = None; // (end, pattern_index)
+        for (position, &byte) in haystack.iter().enumerate() {
+            state = self.goto_links[state as usize][byte as usize];
+            if let Some(&pattern_index) = self.outputs[state as usize].iter().max_by_key(|&&p| self.needle_lengths[p])
+            {
+                pending = Some((position + 1, pattern_index));
+            }
+            if state == 0 {
+                if let Some((end, pattern_index)) = pending {
+                    return Some(ManyMatch { pattern_index, offset: end - self.needle_lengths[pattern_index] });
+                }
+            }
+        }
+        pending.map(|(end, pattern_index)| ManyMatch { pattern_index, offset: end - self.needle_lengths[pattern_index] })
+    }
+
+    /// Like [`AhoCorasick::find_any`], but returns the match as a plain `(offset, length)` pair
+    /// rather than a [`ManyMatch`]. Used to back [`MatcherType::FindAnyOf`].
+    fn find_first(&self, haystack: &[u8]) -> Option<(usize, usize)> {
+        self.find_any(haystack)
+            .map(|found| (found.offset, self.needle_lengths[found.pattern_index]))
+    }
+
+    /// Scans `haystack` for every non-overlapping match, like [`AhoCorasick::find_many`], but
+    /// selecting among needles completing at the same position according to `kind` instead of
+    /// always preferring the longest. Backs [`SzMultiSearcher`]. Matches are returned as
+    /// `(pattern_index, start, end)` triples.
+    fn scan(&self, haystack: &[u8], kind: MultiMatchKind) -> Vec<(usize, usize, usize)> {
+        let mut matches = Vec::new();
+        let mut state = 0u32;
+        let mut position = 0usize;
+        let mut pending: Option<(usize, usize)> = None; // (end, pattern_index)
+        while position < haystack.len() {
+            state = self.goto_links[state as usize][haystack[position] as usize];
+            position += 1;
+            if let Some(pattern_index) = Self::select_output(&self.outputs[state as usize], &self.needle_lengths, kind) {
+                pending = Some((position, pattern_index));
+            }
+            if state == 0 {
+                if let Some((end, pattern_index)) = pending.take() {
+                    matches.push((pattern_index, end - self.needle_lengths[pattern_index], end));
+                    position = end;
+                }
+            }
+        }
+        if let Some((end, pattern_index)) = pending {
+            matches.push((pattern_index, end - self.needle_lengths[pattern_index], end));
+        }
+        matches
+    }
+
+    /// Chooses which of several needles completing at the same automaton state should be
+    /// reported, per `kind`. See [`MultiMatchKind`].
+    fn select_output(outputs: &[usize], needle_lengths: &[usize], kind: MultiMatchKind) -> Option<usize> {
+        match kind {
+            MultiMatchKind::LeftmostLongest => outputs.iter().max_by_key(|&&p| needle_lengths[p]).copied(),
+            MultiMatchKind::Standard => outputs.iter().min_by_key(|&&p| p).copied(),
+        }
+    }
+}
+
+/// Match-selection semantics for [`SzMultiSearcher`], applied when several needles complete at
+/// the same position in the scan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultiMatchKind {
+    /// Prefer whichever needle was registered first, regardless of length - the usual behavior of
+    /// a single forward scan through a dictionary of literal alternatives.
+    Standard,
+    /// Prefer the longest needle completing at that position, like [`AhoCorasick::find_many`].
+    LeftmostLongest,
+}
+
+enum SzMultiSearcherEngine {
+    /// A lone needle is cheaper to scan with the plain SIMD substring search than to compile into
+    /// a one-state-per-byte trie.
+    Single(Vec<u8>),
+    Trie(AhoCorasick),
+}
+
+/// A multi-pattern searcher compiled once from a fixed set of needles, so a haystack can be
+/// scanned for all of them in a single left-to-right pass instead of one pass per needle.
+///
+/// Built on [`AhoCorasick`] for two or more needles; a single needle instead falls back to the
+/// plain SIMD substring search, since compiling a trie for one literal buys nothing. See
+/// [`MultiMatchKind`] for how overlapping completions at the same position are resolved.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla::SzMultiSearcher;
+///
+/// let haystack = b"the cat sat on the mat";
+/// let searcher = SzMultiSearcher::new(&[&b"cat"[..], &b"mat"[..], &b"sat"[..]]).unwrap();
+/// let matches: Vec<_> = searcher.matches_any(haystack);
+/// assert_eq!(matches, vec![(0, 4, 7), (2, 8, 11), (1, 19, 22)]);
+/// ```
+pub struct SzMultiSearcher {
+    engine: SzMultiSearcherEngine,
+    kind: MultiMatchKind,
+}
+
+impl SzMultiSearcher {
+    /// Compiles `needles` into a searcher using [`MultiMatchKind::LeftmostLongest`] semantics.
+    /// Every needle must be non-empty, otherwise [`Status::UnexpectedDimensions`] is returned.
+    pub fn new<N: AsRef<[u8]>>(needles: &[N]) -> Result<Self, Status> {
+        Self::with_kind(needles, MultiMatchKind::LeftmostLongest)
+    }
+
+    /// Like [`SzMultiSearcher::new`], but with an explicit [`MultiMatchKind`].
+    pub fn with_kind<N: AsRef<[u8]>>(needles: &[N], kind: MultiMatchKind) -> Result<Self, Status> {
+        if needles.iter().any(|n| n.as_ref().is_empty()) {
+            return Err(Status::UnexpectedDimensions);
+        }
+        let engine = match needles {
+            [single] => SzMultiSearcherEngine::Single(single.as_ref().to_vec()),
+            _ => SzMultiSearcherEngine::Trie(AhoCorasick::new(needles)?),
+        };
+        Ok(Self { engine, kind })
+    }
+
+    /// Returns the first match of any compiled needle as `(pattern_index, start, end)`, or `None`
+    /// if none occur in `haystack`.
+    pub fn find_any(&self, haystack: &[u8]) -> Option<(usize, usize, usize)> {
+        match &self.engine {
+            SzMultiSearcherEngine::Single(needle) => {
+                find(haystack, needle.as_slice()).map(|start| (0, start, start + needle.len()))
+            }
+            SzMultiSearcherEngine::Trie(automaton) => automaton.scan(haystack, self.kind).into_iter().next(),
+        }
+    }
+
+    /// Returns every non-overlapping match of any compiled needle as `(pattern_index, start, end)`
+    /// triples, in left-to-right order.
+    pub fn matches_any(&self, haystack: &[u8]) -> Vec<(usize, usize, usize)> {
+        match &self.engine {
+            SzMultiSearcherEngine::Single(needle) => {
+                let mut matches = Vec::new();
+                let mut offset = 0usize;
+                while let Some(position) = find(&haystack[offset..], needle.as_slice()) {
+                    let start = offset + position;
+                    let end = start + needle.len();
+                    matches.push((0, start, end));
+                    offset = end.max(start + 1);
+                }
+                matches
+            }
+            SzMultiSearcherEngine::Trie(automaton) => automaton.scan(haystack, self.kind),
+        }
+    }
+}
+
+/// Lazily yields the `(pattern_index, start, end)` triples of [`SzMultiSearcher::matches_any`].
+///
+/// Backs [`StringZillableMulti::sz_matches_any`]; construct via that method rather than directly.
+pub struct SzMatchesAny {
+    matches: std::vec::IntoIter<(usize, usize, usize)>,
+}
+
+impl SzMatchesAny {
+    fn new(matches: Vec<(usize, usize, usize)>) -> Self {
+        Self { matches: matches.into_iter() }
+    }
+}
+
+impl Iterator for SzMatchesAny {
+    type Item = (usize, usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.matches.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.matches.size_hint()
+    }
+}
+
+/// Finds every non-overlapping, leftmost-longest match of any needle in `needles` within
+/// `haystack`, in a single left-to-right pass using a freshly built [`AhoCorasick`] automaton.
+///
+/// For repeated searches against the same needle set, build an [`AhoCorasick`] once with
+/// [`AhoCorasick::new`] and reuse it instead. An empty `needles` slice yields no matches. Every
+/// needle must be non-empty, otherwise [`Status::UnexpectedDimensions`] is returned.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla::find_many;
+///
+/// let haystack = b"the cat sat on the mat";
+/// let matches = find_many(haystack, &[&b"cat"[..], &b"mat"[..], &b"sat"[..]]).unwrap();
+/// let offsets: Vec<_> = matches.iter().map(|m| (m.pattern_index, m.offset)).collect();
+/// assert_eq!(offsets, vec![(0, 4), (2, 8), (1, 19)]);
+/// ```
+pub fn find_many<N: AsRef<[u8]>>(haystack: &[u8], needles: &[N]) -> Result<Vec<ManyMatch>, Status> {
+    if needles.is_empty() {
+        return Ok(Vec::new());
+    }
+    Ok(AhoCorasick::new(needles)?.find_many(haystack))
+}
+
+/// Returns the first match of any needle in `needles` within `haystack`, stopping the scan as
+/// soon as a match is found. See [`find_many`] for the full-scan variant.
+pub fn find_any<N: AsRef<[u8]>>(haystack: &[u8], needles: &[N]) -> Result<Option<ManyMatch>, Status> {
+    if needles.is_empty() {
+        return Ok(None);
+    }
+    Ok(AhoCorasick::new(needles)?.find_any(haystack))
+}
+
+pub trait Matcher<'a> {
+    /// Returns the `(offset, matched_length)` of the leftmost match within `haystack`, if any. The
+    /// length is match-dependent rather than a fixed needle length so that variable-length
+    /// matchers (e.g. [`MatcherType::FindAnyOf`]) can report exactly what matched.
+    fn find(&self, haystack: &'a [u8]) -> Option<(usize, usize)>;
+    /// Returns the `(offset, matched_length)` of the rightmost match within `haystack`, if any.
+    /// Backs [`DoubleEndedIterator::next_back`] for [`RangeMatches`] and [`RangeSplits`], so a
+    /// single matcher drives a search in either direction without a dedicated reverse variant.
+    fn rfind(&self, haystack: &'a [u8]) -> Option<(usize, usize)>;
+    /// Returns how far a cursor should advance past a match of `matched_length` bytes.
+    fn skip_length(&self, matched_length: usize, include_overlaps: bool, is_reverse: bool) -> usize;
+}
+
+/// Folds `c` to a single canonical codepoint for case-insensitive comparison. The ASCII fast path
+/// lower-cases `'A'..='Z'` directly; anything else falls back to `char::to_lowercase`, the
+/// Unicode simple-folding table built into core, keeping only its first produced codepoint
+/// (multi-codepoint special foldings, like German `ß` → `"ss"`, are not followed).
+#[inline]
+fn fold_case(c: char) -> char {
+    if c.is_ascii_uppercase() {
+        return c.to_ascii_lowercase();
+    }
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+/// Strips a common Latin combining diacritic from `c` by mapping pre-composed accented letters
+/// (Latin-1 Supplement and the frequent Latin Extended-A vowels/consonants) to their unaccented
+/// base letter, preserving case, e.g. `'é'` → `'e'`, `'Ü'` → `'U'`. Codepoints outside this
+/// curated set (the common European Latin alphabets) pass through unchanged.
+#[inline]
+fn strip_diacritic(c: char) -> char {
+    match c {
+        'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' | 'Ā' | 'Ă' | 'Ą' => 'A',
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => 'a',
+        'È' | 'É' | 'Ê' | 'Ë' | 'Ē' | 'Ĕ' | 'Ė' | 'Ę' | 'Ě' => 'E',
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => 'e',
+        'Ì' | 'Í' | 'Î' | 'Ï' | 'Ĩ' | 'Ī' | 'Ĭ' | 'Į' | 'İ' => 'I',
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => 'i',
+        'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' | 'Ō' | 'Ŏ' | 'Ő' => 'O',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => 'o',
+        'Ù' | 'Ú' | 'Û' | 'Ü' | 'Ũ' | 'Ū' | 'Ŭ' | 'Ů' | 'Ű' | 'Ų' => 'U',
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => 'u',
+        'Ç' | 'Ć' | 'Ĉ' | 'Ċ' | 'Č' => 'C',
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => 'c',
+        'Ñ' | 'Ń' | 'Ņ' | 'Ň' => 'N',
+        'ñ' | 'ń' | 'ņ' | 'ň' => 'n',
+        'Ý' | 'Ÿ' => 'Y',
+        'ý' | 'ÿ' => 'y',
+        _ => c,
+    }
+}
+
+/// Normalization applied to each decoded codepoint before comparing needle and haystack in a
+/// [`MatcherType::FindFolded`] matcher, built via [`MatcherType::find_folded`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MatcherConfig {
+    /// Fold ASCII and simple Unicode case differences, so `'A'` matches `'a'`.
+    pub ignore_case: bool,
+    /// Additionally strip common Latin combining diacritics, so `'é'` matches `'e'`. Independent
+    /// of `ignore_case`: enabling only `normalize` keeps case-sensitivity while still matching
+    /// accented and unaccented spellings of the same letter.
+    pub normalize: bool,
+}
+
+impl MatcherConfig {
+    #[inline]
+    fn fold(&self, c: char) -> char {
+        let c = if self.ignore_case { fold_case(c) } else { c };
+        if self.normalize {
+            strip_diacritic(c)
+        } else {
+            c
+        }
+    }
+}
+
+/// A needle pre-folded under a [`MatcherConfig`], so repeated scans across a
+/// [`RangeMatches`]/[`RangeSplits`] iteration don't re-fold it on every call. Built through
+/// [`MatcherType::find_folded`].
+pub struct FoldedNeedle {
+    config: MatcherConfig,
+    folded: Vec<char>,
+}
+
+/// Finds the leftmost match of `needle` within `haystack` under `needle.config`'s normalization,
+/// decoding both sides as UTF-8 codepoints (via [`Utf8CharCursor`], the batched
+/// `sz_utf8_unpack_chunk` decoder) but returning the byte offset/length of the match in the
+/// *original*, unfolded `haystack`. Invalid UTF-8 in `haystack` yields no match, same as the
+/// segmentation iterators.
+fn find_folded(haystack: &[u8], needle: &FoldedNeedle) -> Option<(usize, usize)> {
+    if needle.folded.is_empty() {
+        return Some((0, 0));
+    }
+
+    let positions: Vec<(usize, char)> = Utf8CharCursor::new(haystack).collect();
+    let needle_len = needle.folded.len();
+    if positions.len() < needle_len {
+        return None;
+    }
+
+    'windows: for start in 0..=(positions.len() - needle_len) {
+        for (offset, &expected) in needle.folded.iter().enumerate() {
+            let (_, c) = positions[start + offset];
+            if needle.config.fold(c) != expected {
+                continue 'windows;
+            }
+        }
+        let start_byte = positions[start].0;
+        let end_byte = positions
+            .get(start + needle_len)
+            .map(|&(byte, _)| byte)
+            .unwrap_or(haystack.len());
+        return Some((start_byte, end_byte - start_byte));
+    }
+    None
+}
+
+pub enum MatcherType<'a> {
+    Find(&'a [u8]),
+    /// Finds the leftmost match of `needle`, prefiltering candidates by its statistically rarest
+    /// byte instead of probing its first byte. Construct with
+    /// [`MatcherType::find_with_prefilter`].
+    FindWithPrefilter {
+        needle: &'a [u8],
+        rare_position: usize,
+        rare_byte: u8,
+    },
+    FindFirstOf(&'a [u8]),
+    FindFirstNotOf(&'a [u8]),
+    /// Finds the leftmost single byte belonging to `Byteset`, the same SIMD-backed set lookup
+    /// that powers [`find_byteset`]/[`rfind_byteset`]. Construct directly, or via a
+    /// [`SzPattern`] impl (for `u8` or [`Byteset`] themselves) to drive
+    /// [`StringZillablePattern::sz_match_indices`]/`sz_split_pattern`/`sz_split_indices`.
+    FindByteset(Byteset),
+    /// Finds the leftmost match of any needle in a set, compiled once into an Aho-Corasick
+    /// automaton. Construct with [`MatcherType::find_any_of`].
+    FindAnyOf(AhoCorasick),
+    /// Finds the leftmost match of a needle under case and/or diacritic folding. Construct with
+    /// [`MatcherType::find_folded`].
+    FindFolded(FoldedNeedle),
+}
+
+impl<'a> MatcherType<'a> {
+    /// Builds a [`MatcherType::FindAnyOf`] matcher, compiling `needles` into an Aho-Corasick
+    /// automaton once up front so repeated scans (e.g. across a [`RangeMatches`]/[`RangeSplits`]
+    /// iteration) don't rebuild it. Every needle must be non-empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stringzilla::{stringzilla as sz, stringzilla::{MatcherType, RangeSplits}};
+    ///
+    /// let text = b"line one\r\nline two\nline three\rdone";
+    /// let matcher = MatcherType::find_any_of(&[&b"\r\n"[..], &b"\n"[..], &b"\r"[..]]).unwrap();
+    /// let lines: Vec<&[u8]> = RangeSplits::new(text, matcher).collect();
+    /// assert_eq!(lines, vec![&b"line one"[..], b"line two", b"line three", b"done"]);
+    /// ```
+    pub fn find_any_of<N: AsRef<[u8]>>(needles: &[N]) -> Result<Self, Status> {
+        Ok(MatcherType::FindAnyOf(AhoCorasick::new(needles)?))
+    }
+
+    /// Builds a [`MatcherType::FindFolded`] matcher, folding `needle` once up front under
+    /// `config` so repeated scans don't re-fold it on every call. `needle` must be valid UTF-8,
+    /// otherwise [`Status::InvalidUtf8`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stringzilla::{stringzilla as sz, stringzilla::{MatcherConfig, MatcherType, RangeMatches}};
+    ///
+    /// let haystack = "Café CAFÉ cafe".as_bytes();
+    /// let config = MatcherConfig { ignore_case: true, normalize: true };
+    /// let matcher = MatcherType::find_folded("cafe", config).unwrap();
+    /// let matches: Vec<&[u8]> = RangeMatches::new(haystack, matcher, false).collect();
+    /// assert_eq!(matches, vec!["Café".as_bytes(), "CAFÉ".as_bytes(), b"cafe"]);
+    /// ```
+    pub fn find_folded<N: AsRef<[u8]>>(needle: N, config: MatcherConfig) -> Result<Self, Status> {
+        let needle_text = core::str::from_utf8(needle.as_ref()).map_err(|_| Status::InvalidUtf8)?;
+        let folded = needle_text.chars().map(|c| config.fold(c)).collect();
+        Ok(MatcherType::FindFolded(FoldedNeedle { config, folded }))
+    }
+
+    /// Builds a matcher that prefilters candidate positions by `needle`'s statistically rarest
+    /// byte (see [`rarest_byte`]) rather than probing its first byte, computing the rare byte
+    /// once up front so repeated scans across a [`RangeMatches`]/[`RangeSplits`] iteration don't
+    /// recompute it on every call. Falls back to a plain [`MatcherType::Find`] when `needle` is
+    /// empty or its rarest byte is still too common to filter well (rarity rank above
+    /// [`PREFILTER_RARITY_THRESHOLD`]), where the prefilter wouldn't pay for itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stringzilla::{stringzilla as sz, stringzilla::{MatcherType, RangeMatches}};
+    ///
+    /// let haystack = b"the quick brown fox jumps over the lazy dog";
+    /// let matcher = MatcherType::find_with_prefilter(b"lazy");
+    /// let matches: Vec<&[u8]> = RangeMatches::new(haystack, matcher, false).collect();
+    /// assert_eq!(matches, vec![b"lazy"]);
+    /// ```
+    pub fn find_with_prefilter(needle: &'a [u8]) -> Self {
+        match rarest_byte(needle) {
+            Some((rare_position, rare_byte)) if BYTE_RARITY_RANK[rare_byte as usize] <= PREFILTER_RARITY_THRESHOLD => {
+                MatcherType::FindWithPrefilter { needle, rare_position, rare_byte }
+            }
+            _ => MatcherType::Find(needle),
+        }
+    }
+}
+
+impl<'a> Matcher<'a> for MatcherType<'a> {
+    fn find(&self, haystack: &'a [u8]) -> Option<(usize, usize)> {
+        match self {
+            MatcherType::Find(needle) => find(haystack, needle).map(|offset| (offset, needle.len())),
+            MatcherType::FindWithPrefilter { needle, rare_position, rare_byte } => {
+                find_prefiltered(haystack, needle, *rare_position, *rare_byte).map(|offset| (offset, needle.len()))
+            }
+            MatcherType::FindFirstOf(needles) => find_byte_from(haystack, needles).map(|offset| (offset, 1)),
+            MatcherType::FindFirstNotOf(needles) => find_byte_not_from(haystack, needles).map(|offset| (offset, 1)),
+            MatcherType::FindByteset(needles) => find_byteset(haystack, *needles).map(|offset| (offset, 1)),
+            MatcherType::FindAnyOf(automaton) => automaton.find_first(haystack),
+            MatcherType::FindFolded(needle) => find_folded(haystack, needle),
+        }
+    }
+
+    fn rfind(&self, haystack: &'a [u8]) -> Option<(usize, usize)> {
+        match self {
+            MatcherType::Find(needle) => rfind(haystack, needle).map(|offset| (offset, needle.len())),
+            MatcherType::FindWithPrefilter { needle, rare_position, rare_byte } => {
+                rfind_prefiltered(haystack, needle, *rare_position, *rare_byte).map(|offset| (offset, needle.len()))
+            }
+            MatcherType::FindFirstOf(needles) => rfind_byte_from(haystack, needles).map(|offset| (offset, 1)),
+            MatcherType::FindFirstNotOf(needles) => rfind_byte_not_from(haystack, needles).map(|offset| (offset, 1)),
+            MatcherType::FindByteset(needles) => rfind_byteset(haystack, *needles).map(|offset| (offset, 1)),
+            // Neither the Aho-Corasick automaton nor the folding matcher has a dedicated backward
+            // scan, so fall back to a full left-to-right sweep, keeping the rightmost match found.
+            MatcherType::FindAnyOf(_) | MatcherType::FindFolded(_) => {
+                let mut rightmost = None;
+                let mut position = 0;
+                while position < haystack.len() {
+                    match self.find(&haystack[position..]) {
+                        Some((index, matched_length)) => {
+                            let start = position + index;
+                            rightmost = Some((start, matched_length));
+                            position = start + self.skip_length(matched_length, true, false).max(1);
+                        }
+                        None => break,
+                    }
+                }
+                rightmost
+            }
+        }
+    }
+
+    fn skip_length(&self, matched_length: usize, include_overlaps: bool, is_reverse: bool) -> usize {
+        match (include_overlaps, is_reverse) {
+            (true, true) => matched_length.saturating_sub(1),
+            (true, false) => 1,
+            (false, true) => 0,
+            (false, false) => matched_length,
+        }
+    }
+}
+
+/// An iterator over non-overlapping matches of a pattern in a string slice.
+/// This iterator yields the matched substrings in the order they are found, and supports
+/// searching from either end: `.rev()` walks the same matches back to front, via the matcher's
+/// [`Matcher::rfind`].
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::{stringzilla as sz, stringzilla::{MatcherType, RangeMatches}};
+///
+/// let haystack = b"abababa";
+/// let matcher = MatcherType::Find(b"aba");
+/// let matches: Vec<&[u8]> = RangeMatches::new(haystack, matcher, false).collect();
+/// assert_eq!(matches, vec![b"aba", b"aba"]);
+///
+/// let matcher = MatcherType::Find(b"aba");
+/// let matches: Vec<&[u8]> = RangeMatches::new(haystack, matcher, false).rev().collect();
+/// assert_eq!(matches, vec![b"aba", b"aba"]);
+/// ```
+pub struct RangeMatches<'a> {
     haystack: &'a [u8],
     matcher: MatcherType<'a>,
     position: usize,
+    end: usize,
     include_overlaps: bool,
 }
 
-impl<'a> RangeMatches<'a> {
-    pub fn new(haystack: &'a [u8], matcher: MatcherType<'a>, include_overlaps: bool) -> Self {
-        Self {
-            haystack,
-            matcher,
-            position: 0,
-            include_overlaps,
-        }
+impl<'a> RangeMatches<'a> {
+    pub fn new(haystack: &'a [u8], matcher: MatcherType<'a>, include_overlaps: bool) -> Self {
+        Self {
+            end: haystack.len(),
+            haystack,
+            matcher,
+            position: 0,
+            include_overlaps,
+        }
+    }
+}
+
+impl<'a> Iterator for RangeMatches<'a> {
+    type Item = &'a [u8];
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position >= self.end {
+            return None;
+        }
+
+        if let Some((index, matched_length)) = self.matcher.find(&self.haystack[self.position..self.end]) {
+            let start = self.position + index;
+            let end = start + matched_length;
+            self.position = start + self.matcher.skip_length(matched_length, self.include_overlaps, false);
+            Some(&self.haystack[start..end])
+        } else {
+            self.position = self.end;
+            None
+        }
+    }
+}
+
+impl<'a> DoubleEndedIterator for RangeMatches<'a> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.position >= self.end {
+            return None;
+        }
+
+        if let Some((index, matched_length)) = self.matcher.rfind(&self.haystack[self.position..self.end]) {
+            let start = self.position + index;
+            let end = start + matched_length;
+            self.end = start + self.matcher.skip_length(matched_length, self.include_overlaps, true);
+            Some(&self.haystack[start..end])
+        } else {
+            self.end = self.position;
+            None
+        }
+    }
+}
+
+/// An iterator over non-overlapping splits of a string slice by a pattern.
+/// This iterator yields the substrings between the matches of the pattern, and supports
+/// searching from either end: `.rev()` walks the same splits back to front, via the matcher's
+/// [`Matcher::rfind`].
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::{stringzilla as sz, stringzilla::{MatcherType, RangeSplits}};
+///
+/// let haystack = b"a,b,c,d";
+/// let matcher = MatcherType::Find(b",");
+/// let splits: Vec<&[u8]> = RangeSplits::new(haystack, matcher).collect();
+/// assert_eq!(splits, vec![b"a", b"b", b"c", b"d"]);
+///
+/// let matcher = MatcherType::Find(b",");
+/// let splits: Vec<&[u8]> = RangeSplits::new(haystack, matcher).rev().collect();
+/// assert_eq!(splits, vec![b"d", b"c", b"b", b"a"]);
+/// ```
+pub struct RangeSplits<'a> {
+    haystack: &'a [u8],
+    matcher: MatcherType<'a>,
+    start: usize,
+    end: usize,
+    finished: bool,
+}
+
+impl<'a> RangeSplits<'a> {
+    pub fn new(haystack: &'a [u8], matcher: MatcherType<'a>) -> Self {
+        Self {
+            start: 0,
+            end: haystack.len(),
+            haystack,
+            matcher,
+            finished: false,
+        }
+    }
+}
+
+impl<'a> Iterator for RangeSplits<'a> {
+    type Item = &'a [u8];
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        if let Some((index, matched_length)) = self.matcher.find(&self.haystack[self.start..self.end]) {
+            let segment_start = self.start;
+            let match_start = self.start + index;
+            self.start = match_start + matched_length;
+            Some(&self.haystack[segment_start..match_start])
+        } else {
+            self.finished = true;
+            Some(&self.haystack[self.start..self.end])
+        }
+    }
+}
+
+impl<'a> DoubleEndedIterator for RangeSplits<'a> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        if let Some((index, matched_length)) = self.matcher.rfind(&self.haystack[self.start..self.end]) {
+            let match_start = self.start + index;
+            let segment_end = self.end;
+            self.end = match_start;
+            Some(&self.haystack[match_start + matched_length..segment_end])
+        } else {
+            self.finished = true;
+            Some(&self.haystack[self.start..self.end])
+        }
+    }
+}
+
+impl<'a> RangeSplits<'a> {
+    /// Stops the scan and returns everything not yet yielded as a single span, without searching
+    /// for further matches. Returns `None` if the iterator is already exhausted. Backs
+    /// [`RangeSplitsN`]'s bounded "last item is the unsplit remainder" semantics.
+    fn take_rest(&mut self) -> Option<&'a [u8]> {
+        if self.finished {
+            None
+        } else {
+            self.finished = true;
+            Some(&self.haystack[self.start..self.end])
+        }
+    }
+}
+
+/// Bounded variant of [`RangeSplits`] that performs at most `n - 1` splits before yielding
+/// everything left over as a single final item, mirroring std's `splitn`/`rsplitn`. Constructing
+/// with `reverse: false` advances from the front via [`Matcher::find`]; `reverse: true` advances
+/// from the back via [`Matcher::rfind`], so the final item is the unsplit *prefix* instead.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::{stringzilla as sz, stringzilla::{MatcherType, RangeSplitsN}};
+///
+/// let haystack = b"a,b,c,d";
+/// let matcher = MatcherType::Find(b",");
+/// let splits: Vec<&[u8]> = RangeSplitsN::new(haystack, matcher, 2, false).collect();
+/// assert_eq!(splits, vec![b"a".as_slice(), b"b,c,d".as_slice()]);
+///
+/// let matcher = MatcherType::Find(b",");
+/// let splits: Vec<&[u8]> = RangeSplitsN::new(haystack, matcher, 2, true).collect();
+/// assert_eq!(splits, vec![b"d".as_slice(), b"a,b,c".as_slice()]);
+/// ```
+pub struct RangeSplitsN<'a> {
+    inner: RangeSplits<'a>,
+    remaining: usize,
+    reverse: bool,
+}
+
+impl<'a> RangeSplitsN<'a> {
+    pub fn new(haystack: &'a [u8], matcher: MatcherType<'a>, n: usize, reverse: bool) -> Self {
+        Self {
+            inner: RangeSplits::new(haystack, matcher),
+            remaining: n,
+            reverse,
+        }
+    }
+}
+
+impl<'a> Iterator for RangeSplitsN<'a> {
+    type Item = &'a [u8];
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        if self.remaining == 1 {
+            self.remaining = 0;
+            return self.inner.take_rest();
+        }
+        self.remaining -= 1;
+        if self.reverse {
+            self.inner.next_back()
+        } else {
+            self.inner.next()
+        }
+    }
+}
+
+/// A step of a [`Searcher`] scan: mirrors the unstable `std::str::pattern::SearchStep`, which
+/// `str::find`/`str::split`/`str::match_indices` are built on. A full scan partitions the haystack
+/// into a contiguous, non-overlapping sequence of `Match` and `Reject` byte-offset spans, ending
+/// in `Done`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchStep {
+    /// A match spanning `haystack[start..end]`.
+    Match(usize, usize),
+    /// A non-matching span `haystack[start..end]`.
+    Reject(usize, usize),
+    /// The scan is exhausted; no further steps follow.
+    Done,
+}
+
+/// Mirrors the unstable `std::str::pattern::Searcher` trait: a stateful forward cursor over a
+/// haystack that reports one contiguous [`SearchStep`] at a time. [`SzSearcher`] is the
+/// SIMD-backed implementation built into this crate.
+pub trait Searcher<'a> {
+    /// Returns the haystack this searcher scans over.
+    fn haystack(&self) -> &'a [u8];
+    /// Advances the scan by one step.
+    fn next(&mut self) -> SearchStep;
+
+    /// Returns the `(start, end)` of the next match, skipping over any `Reject` steps. This is
+    /// what `str::find` and `str::match_indices` actually iterate on; `Reject` spans only matter
+    /// to consumers that care about the gaps themselves.
+    fn next_match(&mut self) -> Option<(usize, usize)> {
+        loop {
+            match self.next() {
+                SearchStep::Match(start, end) => return Some((start, end)),
+                SearchStep::Reject(..) => continue,
+                SearchStep::Done => return None,
+            }
+        }
+    }
+}
+
+/// Mirrors the unstable `std::str::pattern::ReverseSearcher` trait: the backward counterpart of
+/// [`Searcher`]. A correct implementation yields exactly the same matches as the forward
+/// [`Searcher`], just in the opposite order.
+pub trait ReverseSearcher<'a>: Searcher<'a> {
+    /// Advances the scan by one step, from the end of the haystack backward.
+    fn next_back(&mut self) -> SearchStep;
+
+    /// Reverse counterpart of [`Searcher::next_match`].
+    fn next_match_back(&mut self) -> Option<(usize, usize)> {
+        loop {
+            match self.next_back() {
+                SearchStep::Match(start, end) => return Some((start, end)),
+                SearchStep::Reject(..) => continue,
+                SearchStep::Done => return None,
+            }
+        }
+    }
+}
+
+/// The SIMD-backed [`Searcher`]/[`ReverseSearcher`] for any [`MatcherType`], built on the same
+/// [`Matcher::find`]/[`Matcher::rfind`] engine that drives [`RangeMatches`] and [`RangeSplits`].
+/// Because both directions dispatch through that one matcher, a reverse scan is guaranteed to
+/// surface the same matches as a forward scan, just in the opposite order. Construct directly, or
+/// via a [`SzPattern`] and [`StringZillablePattern::sz_match_indices`]/
+/// [`StringZillablePattern::sz_split_pattern`].
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla::{MatcherType, Searcher, SearchStep, SzSearcher};
+///
+/// let mut searcher = SzSearcher::new(b"a,b,c", MatcherType::Find(b","));
+/// assert_eq!(searcher.next(), SearchStep::Reject(0, 1));
+/// assert_eq!(searcher.next(), SearchStep::Match(1, 2));
+/// assert_eq!(searcher.next(), SearchStep::Reject(2, 3));
+/// assert_eq!(searcher.next(), SearchStep::Match(3, 4));
+/// assert_eq!(searcher.next(), SearchStep::Reject(4, 5));
+/// assert_eq!(searcher.next(), SearchStep::Done);
+/// ```
+pub struct SzSearcher<'a> {
+    haystack: &'a [u8],
+    matcher: MatcherType<'a>,
+    position: usize,
+    end: usize,
+}
+
+impl<'a> SzSearcher<'a> {
+    pub fn new(haystack: &'a [u8], matcher: MatcherType<'a>) -> Self {
+        Self {
+            haystack,
+            matcher,
+            position: 0,
+            end: haystack.len(),
+        }
+    }
+}
+
+impl<'a> Searcher<'a> for SzSearcher<'a> {
+    fn haystack(&self) -> &'a [u8] {
+        self.haystack
+    }
+
+    fn next(&mut self) -> SearchStep {
+        if self.position >= self.end {
+            return SearchStep::Done;
+        }
+
+        match self.matcher.find(&self.haystack[self.position..self.end]) {
+            Some((index, matched_length)) => {
+                let match_start = self.position + index;
+                let match_end = match_start + matched_length;
+                if match_start > self.position {
+                    let reject_start = self.position;
+                    self.position = match_start;
+                    SearchStep::Reject(reject_start, match_start)
+                } else {
+                    // `.max(self.position + 1)` guarantees forward progress for a zero-length
+                    // match (only possible via `MatcherType::FindFolded` with an empty needle).
+                    self.position = match_end.max(self.position + 1);
+                    SearchStep::Match(match_start, match_end)
+                }
+            }
+            None => {
+                let reject_start = self.position;
+                self.position = self.end;
+                SearchStep::Reject(reject_start, self.end)
+            }
+        }
+    }
+}
+
+impl<'a> ReverseSearcher<'a> for SzSearcher<'a> {
+    fn next_back(&mut self) -> SearchStep {
+        if self.position >= self.end {
+            return SearchStep::Done;
+        }
+
+        match self.matcher.rfind(&self.haystack[self.position..self.end]) {
+            Some((index, matched_length)) => {
+                let match_start = self.position + index;
+                let match_end = match_start + matched_length;
+                if match_end < self.end {
+                    let reject_end = self.end;
+                    self.end = match_end;
+                    SearchStep::Reject(match_end, reject_end)
+                } else {
+                    // Mirrors the forward guard above, for a zero-length match touching `self.end`.
+                    self.end = match_start.min(self.end.saturating_sub(1));
+                    SearchStep::Match(match_start, match_end)
+                }
+            }
+            None => {
+                let reject_end = self.end;
+                self.end = self.position;
+                SearchStep::Reject(self.position, reject_end)
+            }
+        }
+    }
+}
+
+/// Mirrors the unstable `std::str::pattern::Pattern` trait: a needle type that knows how to
+/// search for itself. Implemented for `&'a N` where `N: AsRef<[u8]>` (so plain byte slices and
+/// strings work directly) and for [`MatcherType<'a>`] itself (so callers can opt into
+/// [`MatcherType::find_any_of`], [`MatcherType::find_folded`], or
+/// [`MatcherType::find_with_prefilter`] through the same `sz_match_indices`/`sz_split_pattern`
+/// entry points).
+pub trait SzPattern<'a> {
+    fn into_searcher(self, haystack: &'a [u8]) -> SzSearcher<'a>;
+}
+
+impl<'a, N> SzPattern<'a> for &'a N
+where
+    N: AsRef<[u8]> + ?Sized,
+{
+    fn into_searcher(self, haystack: &'a [u8]) -> SzSearcher<'a> {
+        SzSearcher::new(haystack, MatcherType::Find(self.as_ref()))
+    }
+}
+
+impl<'a> SzPattern<'a> for MatcherType<'a> {
+    fn into_searcher(self, haystack: &'a [u8]) -> SzSearcher<'a> {
+        SzSearcher::new(haystack, self)
+    }
+}
+
+impl<'a> SzPattern<'a> for u8 {
+    fn into_searcher(self, haystack: &'a [u8]) -> SzSearcher<'a> {
+        SzSearcher::new(haystack, MatcherType::FindByteset(Byteset::from_bytes(&[self])))
+    }
+}
+
+impl<'a> SzPattern<'a> for Byteset {
+    fn into_searcher(self, haystack: &'a [u8]) -> SzSearcher<'a> {
+        SzSearcher::new(haystack, MatcherType::FindByteset(self))
+    }
+}
+
+/// Wraps an ASCII byte predicate so it can be used as a [`SzPattern`], in the style of std's
+/// unstable `Pattern` impl for `FnMut(char) -> bool`. The predicate is evaluated once per byte
+/// value up front (not once per haystack byte) and folded into a [`Byteset`], which is then
+/// driven by the same SIMD-backed [`MatcherType::FindByteset`] lookup as a literal [`Byteset`]
+/// or `u8` pattern. A bare closure can't implement [`SzPattern`] directly without conflicting
+/// with the blanket impl over `&'a N where N: AsRef<[u8]>`, so it needs this newtype wrapper.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla::{AsciiPredicate, StringZillablePattern};
+///
+/// let haystack = b"room101";
+/// let digits: Vec<&[u8]> = haystack.sz_split_pattern(AsciiPredicate(|byte: u8| byte.is_ascii_digit())).collect();
+/// assert_eq!(digits, vec![&b"room"[..], b"", b"", b""]);
+/// ```
+pub struct AsciiPredicate<F>(pub F);
+
+impl<'a, F> SzPattern<'a> for AsciiPredicate<F>
+where
+    F: Fn(u8) -> bool,
+{
+    fn into_searcher(self, haystack: &'a [u8]) -> SzSearcher<'a> {
+        let mut needles = Byteset::new();
+        for byte in 0u8..=255u8 {
+            if (self.0)(byte) {
+                needles.add_u8(byte);
+            }
+        }
+        SzSearcher::new(haystack, MatcherType::FindByteset(needles))
+    }
+}
+
+/// An iterator over `(start, matched)` pairs yielded by a [`SzPattern`], in the style of
+/// `str::match_indices`. Built via [`StringZillablePattern::sz_match_indices`].
+pub struct SzMatchIndices<'a> {
+    searcher: SzSearcher<'a>,
+}
+
+impl<'a> SzMatchIndices<'a> {
+    fn new(searcher: SzSearcher<'a>) -> Self {
+        Self { searcher }
+    }
+}
+
+impl<'a> Iterator for SzMatchIndices<'a> {
+    type Item = (usize, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, end) = self.searcher.next_match()?;
+        Some((start, &self.searcher.haystack()[start..end]))
+    }
+}
+
+impl<'a> DoubleEndedIterator for SzMatchIndices<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (start, end) = self.searcher.next_match_back()?;
+        Some((start, &self.searcher.haystack()[start..end]))
     }
 }
 
-impl<'a> Iterator for RangeMatches<'a> {
+/// An iterator over the substrings between matches of a [`SzPattern`], in the style of
+/// `str::split`. Built via [`StringZillablePattern::sz_split_pattern`].
+pub struct SzSplitPattern<'a> {
+    searcher: SzSearcher<'a>,
+    segment_start: usize,
+    finished: bool,
+}
+
+impl<'a> SzSplitPattern<'a> {
+    fn new(searcher: SzSearcher<'a>) -> Self {
+        Self { searcher, segment_start: 0, finished: false }
+    }
+}
+
+impl<'a> Iterator for SzSplitPattern<'a> {
     type Item = &'a [u8];
 
-    #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.position >= self.haystack.len() {
+        if self.finished {
             return None;
         }
 
-        if let Some(index) = self.matcher.find(&self.haystack[self.position..]) {
-            let start = self.position + index;
-            let end = start + self.matcher.needle_length();
-            self.position = start + self.matcher.skip_length(self.include_overlaps, false);
-            Some(&self.haystack[start..end])
-        } else {
-            self.position = self.haystack.len();
-            None
+        match self.searcher.next_match() {
+            Some((match_start, match_end)) => {
+                let segment_start = self.segment_start;
+                self.segment_start = match_end;
+                Some(&self.searcher.haystack()[segment_start..match_start])
+            }
+            None => {
+                self.finished = true;
+                Some(&self.searcher.haystack()[self.segment_start..])
+            }
         }
     }
 }
 
-/// An iterator over non-overlapping splits of a string slice by a pattern.
-/// This iterator yields the substrings between the matches of the pattern.
-///
-/// # Examples
-///
-/// ```
-/// use stringzilla::{stringzilla as sz, stringzilla::{MatcherType, RangeSplits}};
-///
-/// let haystack = b"a,b,c,d";
-/// let matcher = MatcherType::Find(b",");
-/// let splits: Vec<&[u8]> = RangeSplits::new(haystack, matcher).collect();
-/// assert_eq!(splits, vec![b"a", b"b", b"c", b"d"]);
-/// ```
-pub struct RangeSplits<'a> {
-    haystack: &'a [u8],
-    matcher: MatcherType<'a>,
-    position: usize,
-    last_match: Option<usize>,
+/// An iterator over `(offset, segment)` pairs between matches of a [`SzPattern`], like
+/// [`SzSplitPattern`] but additionally reporting each segment's byte offset within the original
+/// haystack. Built via [`StringZillablePattern::sz_split_indices`].
+pub struct SzSplitIndices<'a> {
+    searcher: SzSearcher<'a>,
+    segment_start: usize,
+    finished: bool,
 }
 
-impl<'a> RangeSplits<'a> {
-    pub fn new(haystack: &'a [u8], matcher: MatcherType<'a>) -> Self {
-        Self {
-            haystack,
-            matcher,
-            position: 0,
-            last_match: None,
-        }
+impl<'a> SzSplitIndices<'a> {
+    fn new(searcher: SzSearcher<'a>) -> Self {
+        Self { searcher, segment_start: 0, finished: false }
     }
 }
 
-impl<'a> Iterator for RangeSplits<'a> {
-    type Item = &'a [u8];
+impl<'a> Iterator for SzSplitIndices<'a> {
+    type Item = (usize, &'a [u8]);
 
-    #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.position > self.haystack.len() {
+        if self.finished {
             return None;
         }
 
-        if let Some(index) = self.matcher.find(&self.haystack[self.position..]) {
-            let start = self.position;
-            let end = self.position + index;
-            self.position = end + self.matcher.needle_length();
-            self.last_match = Some(end);
-            Some(&self.haystack[start..end])
-        } else if self.position < self.haystack.len() || self.last_match.is_some() {
-            let start = self.position;
-            self.position = self.haystack.len() + 1;
-            Some(&self.haystack[start..])
-        } else {
-            None
+        match self.searcher.next_match() {
+            Some((match_start, match_end)) => {
+                let segment_start = self.segment_start;
+                self.segment_start = match_end;
+                Some((segment_start, &self.searcher.haystack()[segment_start..match_start]))
+            }
+            None => {
+                self.finished = true;
+                Some((self.segment_start, &self.searcher.haystack()[self.segment_start..]))
+            }
         }
     }
 }
 
-/// An iterator over non-overlapping matches of a pattern in a string slice, searching from the end.
-/// This iterator yields the matched substrings in reverse order.
+/// Entry points that let any [`SzPattern`] (a plain needle, or a prebuilt [`MatcherType`] such as
+/// [`MatcherType::find_any_of`] or [`MatcherType::find_folded`]) drive a search the same way
+/// `std`'s unstable `Pattern` API does, via [`SzSearcher`].
 ///
 /// # Examples
 ///
 /// ```
-/// use stringzilla::{stringzilla as sz, stringzilla::{MatcherType, RangeRMatches}};
+/// use stringzilla::sz::StringZillablePattern;
 ///
-/// let haystack = b"abababa";
-/// let matcher = MatcherType::RFind(b"aba");
-/// let matches: Vec<&[u8]> = RangeRMatches::new(haystack, matcher, false).collect();
-/// assert_eq!(matches, vec![b"aba", b"aba"]);
+/// let haystack = "a,b,c";
+/// let matches: Vec<_> = haystack.sz_match_indices(",").collect();
+/// assert_eq!(matches, vec![(1, ",".as_bytes()), (3, ",".as_bytes())]);
+///
+/// let parts: Vec<&[u8]> = haystack.sz_split_pattern(",").collect();
+/// assert_eq!(parts, vec![b"a", b"b", b"c"]);
+///
+/// let parts: Vec<_> = haystack.sz_split_indices(",").collect();
+/// assert_eq!(parts, vec![(0, "a".as_bytes()), (2, "b".as_bytes()), (4, "c".as_bytes())]);
 /// ```
-pub struct RangeRMatches<'a> {
-    haystack: &'a [u8],
-    matcher: MatcherType<'a>,
-    position: usize,
-    include_overlaps: bool,
+pub trait StringZillablePattern<'a> {
+    fn sz_match_indices<P: SzPattern<'a>>(&'a self, pattern: P) -> SzMatchIndices<'a>;
+    fn sz_split_pattern<P: SzPattern<'a>>(&'a self, pattern: P) -> SzSplitPattern<'a>;
+    fn sz_split_indices<P: SzPattern<'a>>(&'a self, pattern: P) -> SzSplitIndices<'a>;
 }
 
-impl<'a> RangeRMatches<'a> {
-    pub fn new(haystack: &'a [u8], matcher: MatcherType<'a>, include_overlaps: bool) -> Self {
-        Self {
-            haystack,
-            matcher,
-            position: haystack.len(),
-            include_overlaps,
-        }
+impl<'a, T> StringZillablePattern<'a> for T
+where
+    T: AsRef<[u8]> + ?Sized,
+{
+    fn sz_match_indices<P: SzPattern<'a>>(&'a self, pattern: P) -> SzMatchIndices<'a> {
+        SzMatchIndices::new(pattern.into_searcher(self.as_ref()))
     }
-}
-
-impl<'a> Iterator for RangeRMatches<'a> {
-    type Item = &'a [u8];
-
-    #[inline(always)]
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.position == 0 {
-            return None;
-        }
-
-        let search_area = &self.haystack[..self.position];
-        if let Some(index) = self.matcher.find(search_area) {
-            let start = index;
-            let end = start + self.matcher.needle_length();
-            let result = Some(&self.haystack[start..end]);
 
-            let skip = self.matcher.skip_length(self.include_overlaps, true);
-            self.position = start + skip;
+    fn sz_split_pattern<P: SzPattern<'a>>(&'a self, pattern: P) -> SzSplitPattern<'a> {
+        SzSplitPattern::new(pattern.into_searcher(self.as_ref()))
+    }
 
-            result
-        } else {
-            None
-        }
+    fn sz_split_indices<P: SzPattern<'a>>(&'a self, pattern: P) -> SzSplitIndices<'a> {
+        SzSplitIndices::new(pattern.into_searcher(self.as_ref()))
     }
 }
 
-/// An iterator over non-overlapping splits of a string slice by a pattern, searching from the end.
-/// This iterator yields the substrings between the matches of the pattern in reverse order.
+/// Scans `self` against a precompiled [`SzMultiSearcher`], finding any of its needles in a
+/// single left-to-right pass rather than one pass per needle.
 ///
 /// # Examples
 ///
 /// ```
-/// use stringzilla::{stringzilla as sz, stringzilla::{MatcherType, RangeRSplits}};
+/// use stringzilla::sz::StringZillableMulti;
+/// use stringzilla::stringzilla::SzMultiSearcher;
 ///
-/// let haystack = b"a,b,c,d";
-/// let matcher = MatcherType::RFind(b",");
-/// let splits: Vec<&[u8]> = RangeRSplits::new(haystack, matcher).collect();
-/// assert_eq!(splits, vec![b"d", b"c", b"b", b"a"]);
+/// let haystack = "the cat sat on the mat";
+/// let searcher = SzMultiSearcher::new(&["cat", "mat", "sat"]).unwrap();
+/// assert_eq!(haystack.sz_find_any(&searcher), Some((0, 4, 7)));
+///
+/// let matches: Vec<_> = haystack.sz_matches_any(&searcher).collect();
+/// assert_eq!(matches, vec![(0, 4, 7), (2, 8, 11), (1, 19, 22)]);
 /// ```
-pub struct RangeRSplits<'a> {
-    haystack: &'a [u8],
-    matcher: MatcherType<'a>,
-    position: usize,
-}
+pub trait StringZillableMulti {
+    /// Returns the first match of any needle in `searcher` as `(pattern_index, start, end)`.
+    fn sz_find_any(&self, searcher: &SzMultiSearcher) -> Option<(usize, usize, usize)>;
 
-impl<'a> RangeRSplits<'a> {
-    pub fn new(haystack: &'a [u8], matcher: MatcherType<'a>) -> Self {
-        Self {
-            haystack,
-            matcher,
-            position: haystack.len(),
-        }
-    }
+    /// Returns an iterator over every non-overlapping match of any needle in `searcher`, as
+    /// `(pattern_index, start, end)` triples in left-to-right order.
+    fn sz_matches_any(&self, searcher: &SzMultiSearcher) -> SzMatchesAny;
 }
 
-impl<'a> Iterator for RangeRSplits<'a> {
-    type Item = &'a [u8];
-
-    #[inline(always)]
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.position == 0 {
-            return None;
-        }
-
-        let search_area = &self.haystack[..self.position];
-        if let Some(index) = self.matcher.find(search_area) {
-            let end = self.position;
-            let start = index + self.matcher.needle_length();
-            let result = Some(&self.haystack[start..end]);
-
-            self.position = index;
+impl<T> StringZillableMulti for T
+where
+    T: AsRef<[u8]> + ?Sized,
+{
+    fn sz_find_any(&self, searcher: &SzMultiSearcher) -> Option<(usize, usize, usize)> {
+        searcher.find_any(self.as_ref())
+    }
 
-            result
-        } else {
-            let result = Some(&self.haystack[..self.position]);
-            self.position = 0;
-            result
-        }
+    fn sz_matches_any(&self, searcher: &SzMultiSearcher) -> SzMatchesAny {
+        SzMatchesAny::new(searcher.matches_any(self.as_ref()))
     }
 }
 
@@ -2356,6 +5114,118 @@ impl<'a> Iterator for RangeNewlineUtf8Splits<'a> {
     }
 }
 
+/// An iterator over the lines of UTF-8 text, built on [`find_newline_utf8`], with the
+/// terminator stripped from each yielded line.
+///
+/// Unlike [`RangeNewlineUtf8Splits`] (which mirrors `split`'s behavior of yielding a trailing
+/// empty slice after a final terminator), this follows bstr's `lines` convention: a trailing
+/// terminator does not produce an extra empty line, but a final unterminated line is still
+/// yielded. Returned by [`lines_utf8`].
+pub struct Utf8Lines<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Utf8Lines<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self { text, position: 0 }
+    }
+}
+
+impl<'a> Iterator for Utf8Lines<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position >= self.text.len() {
+            return None;
+        }
+
+        let rest = &self.text[self.position..];
+        if let Some(span) = find_newline_utf8(rest) {
+            let line_end = self.position + span.offset;
+            let line = &self.text[self.position..line_end];
+            self.position = line_end + span.length;
+            Some(line)
+        } else {
+            let line = rest;
+            self.position = self.text.len();
+            Some(line)
+        }
+    }
+}
+
+/// An iterator over the lines of UTF-8 text, built on [`find_newline_utf8`], with the
+/// terminator retained at the end of each yielded line. See [`Utf8Lines`] for the trailing-line
+/// semantics. Returned by [`lines_with_terminator_utf8`].
+pub struct Utf8LinesWithTerminator<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Utf8LinesWithTerminator<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self { text, position: 0 }
+    }
+}
+
+impl<'a> Iterator for Utf8LinesWithTerminator<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position >= self.text.len() {
+            return None;
+        }
+
+        let rest = &self.text[self.position..];
+        if let Some(span) = find_newline_utf8(rest) {
+            let line_end = self.position + span.offset + span.length;
+            let line = &self.text[self.position..line_end];
+            self.position = line_end;
+            Some(line)
+        } else {
+            let line = rest;
+            self.position = self.text.len();
+            Some(line)
+        }
+    }
+}
+
+/// Returns an iterator over the lines of `text`, stripping the terminator from each line.
+/// Recognizes the full Unicode newline set handled by [`find_newline_utf8`] (LF, VT, FF, CR,
+/// CRLF as one, NEL, LS, PS), unlike `str::lines` which only splits on
+/// `\n`/`\r\n`. A final line without a trailing terminator is still yielded.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla as sz;
+///
+/// let lines: Vec<&str> = sz::lines_utf8("Hello\nWorld\r\nRust").collect();
+/// assert_eq!(lines, vec!["Hello", "World", "Rust"]);
+///
+/// // A trailing terminator does not produce an extra empty line.
+/// let lines: Vec<&str> = sz::lines_utf8("a\nb\n").collect();
+/// assert_eq!(lines, vec!["a", "b"]);
+/// ```
+pub fn lines_utf8(text: &str) -> Utf8Lines<'_> {
+    Utf8Lines::new(text)
+}
+
+/// Returns an iterator over the lines of `text`, retaining the terminator at the end of each
+/// line. See [`lines_utf8`] for the newline set recognized and the trailing-line behavior.
+///
+/// # Examples
+///
+/// ```
+/// use stringzilla::stringzilla as sz;
+///
+/// let lines: Vec<&str> = sz::lines_with_terminator_utf8("Hello\nWorld\r\n").collect();
+/// assert_eq!(lines, vec!["Hello\n", "World\r\n"]);
+/// ```
+pub fn lines_with_terminator_utf8(text: &str) -> Utf8LinesWithTerminator<'_> {
+    Utf8LinesWithTerminator::new(text)
+}
+
 /// An iterator over words in UTF-8 text split by whitespace characters.
 ///
 /// This iterator yields non-empty slices between whitespace characters. The whitespace
@@ -2565,6 +5435,104 @@ pub trait StringZillableUnary {
     /// assert_eq!(words, vec!["Hello", "World", "Rust"]);
     /// ```
     fn sz_utf8_whitespace_splits(&self) -> RangeWhitespaceUtf8Splits<'_>;
+
+    /// Returns an iterator over the extended grapheme clusters of `self`. See [`Utf8GraphemeIndices`]
+    /// for the boundary rules applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stringzilla::sz::StringZillableUnary;
+    ///
+    /// let text = "e\u{0301}clair"; // "é" as e + combining acute
+    /// let clusters: Vec<&str> = text.sz_graphemes().collect();
+    /// assert_eq!(clusters[0], "e\u{0301}");
+    /// ```
+    fn sz_graphemes(&self) -> Utf8Graphemes<'_>;
+
+    /// Returns an iterator over `(byte_offset, grapheme)` pairs. See [`sz_graphemes`](Self::sz_graphemes).
+    fn sz_grapheme_indices(&self) -> Utf8GraphemeIndices<'_>;
+
+    /// Returns an iterator over the Unicode words of `self`, skipping whitespace and punctuation
+    /// while keeping contractions intact (e.g. `don't` stays one word).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stringzilla::sz::StringZillableUnary;
+    ///
+    /// let text = "Hello, world!";
+    /// let words: Vec<&str> = text.sz_words().collect();
+    /// assert_eq!(words, vec!["Hello", "world"]);
+    /// ```
+    fn sz_words(&self) -> Utf8Words<'_>;
+
+    /// Returns an iterator over `(byte_offset, word)` pairs. See [`sz_words`](Self::sz_words).
+    fn sz_word_indices(&self) -> Utf8WordIndices<'_>;
+
+    /// Returns an iterator over the sentences of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stringzilla::sz::StringZillableUnary;
+    ///
+    /// let text = "Hi there! How are you?";
+    /// let sentences: Vec<&str> = text.sz_sentences().collect();
+    /// assert_eq!(sentences, vec!["Hi there! ", "How are you?"]);
+    /// ```
+    fn sz_sentences(&self) -> Utf8Sentences<'_>;
+
+    /// Returns an iterator over `(byte_offset, sentence)` pairs. See [`sz_sentences`](Self::sz_sentences).
+    fn sz_sentence_indices(&self) -> Utf8SentenceIndices<'_>;
+
+    /// Returns an iterator over `(byte_offset, char, len)` triples, decoding `self`'s bytes with
+    /// the same SIMD-batched decoder as [`sz_utf8_chars`](Self::sz_utf8_chars) but without
+    /// discarding each codepoint's encoded length. A fast replacement for `str::char_indices`
+    /// that stops at the first byte sequence it can't decode; see [`Utf8CharIndices`] and
+    /// [`sz_utf8_chunks`](Self::sz_utf8_chunks) for a lossy alternative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stringzilla::sz::StringZillableUnary;
+    ///
+    /// let text = "a🌍b";
+    /// let indices: Vec<(usize, char, usize)> = text.sz_char_indices().collect();
+    /// assert_eq!(indices, vec![(0, 'a', 1), (1, '🌍', 4), (5, 'b', 1)]);
+    /// ```
+    fn sz_char_indices(&self) -> Utf8CharIndices<'_>;
+
+    /// Returns a lossy decoding iterator over `self`'s bytes, yielding `(valid, invalid)` pairs:
+    /// the longest prefix of well-formed UTF-8 found, followed by the single invalid byte
+    /// sequence that interrupted it (as delimited by `core::str::Utf8Error::error_len`). See
+    /// [`Utf8Chunks`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stringzilla::sz::StringZillableUnary;
+    ///
+    /// let text = b"Hello\xFFWorld";
+    /// let chunks: Vec<_> = text.sz_utf8_chunks().collect();
+    /// assert_eq!(chunks, vec![("Hello", &b"\xFF"[..]), ("World", &b""[..])]);
+    /// ```
+    fn sz_utf8_chunks(&self) -> Utf8Chunks<'_>;
+
+    /// Converts `self`'s bytes to a `str`, substituting U+FFFD (the replacement character) for
+    /// each invalid byte sequence. Borrows `self` when it's already valid UTF-8 in its entirety,
+    /// and only allocates when a substitution is actually needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stringzilla::sz::StringZillableUnary;
+    /// use std::borrow::Cow;
+    ///
+    /// assert_eq!(b"Hello".sz_to_str_lossy(), Cow::Borrowed("Hello"));
+    /// assert_eq!(b"Hello\xFFWorld".sz_to_str_lossy(), Cow::<str>::Owned("Hello\u{FFFD}World".to_string()));
+    /// ```
+    fn sz_to_str_lossy(&self) -> std::borrow::Cow<'_, str>;
 }
 
 /// Trait for binary string operations that take a needle parameter.
@@ -2690,7 +5658,7 @@ where
     /// let matches: Vec<&[u8]> = haystack.sz_rmatches(needle).collect();
     /// assert_eq!(matches, vec![b"aba", b"aba", b"aba"]);
     /// ```
-    fn sz_rmatches(&'a self, needle: &'a N) -> RangeRMatches<'a>;
+    fn sz_rmatches(&'a self, needle: &'a N) -> core::iter::Rev<RangeMatches<'a>>;
 
     /// Returns an iterator over the substrings of `self` that are separated by the given `needle`.
     ///
@@ -2708,12 +5676,54 @@ where
     /// let splits: Vec<&[u8]> = haystack.sz_splits(needle).collect();
     /// assert_eq!(splits, vec![b"a", b"b", b"c", b"d"]);
     /// ```
-    fn sz_splits(&'a self, needle: &'a N) -> RangeSplits<'a>;
+    fn sz_splits(&'a self, needle: &'a N) -> RangeSplits<'a>;
+
+    /// Returns an iterator over the substrings of `self` that are separated by the given `needle`, searching from the end.
+    ///
+    /// # Arguments
+    ///
+    /// * `needle`: The byte slice to split `self` by.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stringzilla::sz::StringZillableBinary;
+    ///
+    /// let haystack = b"a,b,c,d";
+    /// let needle = b",";
+    /// let splits: Vec<&[u8]> = haystack.sz_rsplits(needle).collect();
+    /// assert_eq!(splits, vec![b"d", b"c", b"b", b"a"]);
+    /// ```
+    fn sz_rsplits(&'a self, needle: &'a N) -> core::iter::Rev<RangeSplits<'a>>;
+
+    /// Returns an iterator over at most `n` substrings of `self` separated by `needle`: the first
+    /// `n - 1` are the usual splits, and the final one is everything left over, unsplit.
+    /// Mirrors `str::splitn`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n`: The maximum number of substrings to produce.
+    /// * `needle`: The byte slice to split `self` by.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stringzilla::sz::StringZillableBinary;
+    ///
+    /// let haystack = b"a,b,c,d";
+    /// let needle = b",";
+    /// let splits: Vec<&[u8]> = haystack.sz_splitn(2, needle).collect();
+    /// assert_eq!(splits, vec![b"a".as_slice(), b"b,c,d".as_slice()]);
+    /// ```
+    fn sz_splitn(&'a self, n: usize, needle: &'a N) -> RangeSplitsN<'a>;
 
-    /// Returns an iterator over the substrings of `self` that are separated by the given `needle`, searching from the end.
+    /// Returns an iterator over at most `n` substrings of `self` separated by `needle`, searching
+    /// from the end: the first `n - 1` are the usual splits, and the final one is everything left
+    /// over at the front, unsplit. Mirrors `str::rsplitn`.
     ///
     /// # Arguments
     ///
+    /// * `n`: The maximum number of substrings to produce.
     /// * `needle`: The byte slice to split `self` by.
     ///
     /// # Examples
@@ -2723,10 +5733,10 @@ where
     ///
     /// let haystack = b"a,b,c,d";
     /// let needle = b",";
-    /// let splits: Vec<&[u8]> = haystack.sz_rsplits(needle).collect();
-    /// assert_eq!(splits, vec![b"d", b"c", b"b", b"a"]);
+    /// let splits: Vec<&[u8]> = haystack.sz_rsplitn(2, needle).collect();
+    /// assert_eq!(splits, vec![b"d".as_slice(), b"a,b,c".as_slice()]);
     /// ```
-    fn sz_rsplits(&'a self, needle: &'a N) -> RangeRSplits<'a>;
+    fn sz_rsplitn(&'a self, n: usize, needle: &'a N) -> RangeSplitsN<'a>;
 
     /// Returns an iterator over all non-overlapping matches of any of the bytes in `needles` within `self`.
     ///
@@ -2762,7 +5772,7 @@ where
     /// let matches: Vec<&[u8]> = haystack.sz_find_last_of(needles).collect();
     /// assert_eq!(matches, vec![b"o", b"o", b"e"]);
     /// ```
-    fn sz_find_last_of(&'a self, needles: &'a N) -> RangeRMatches<'a>;
+    fn sz_find_last_of(&'a self, needles: &'a N) -> core::iter::Rev<RangeMatches<'a>>;
 
     /// Returns an iterator over all non-overlapping matches of any byte not in `needles` within `self`.
     ///
@@ -2798,7 +5808,33 @@ where
     /// let matches: Vec<&[u8]> = haystack.sz_find_last_not_of(needles).collect();
     /// assert_eq!(matches, vec![b"!", b"d", b"l", b"r", b"w", b" ", b",", b"l", b"l", b"H"]);
     /// ```
-    fn sz_find_last_not_of(&'a self, needles: &'a N) -> RangeRMatches<'a>;
+    fn sz_find_last_not_of(&'a self, needles: &'a N) -> core::iter::Rev<RangeMatches<'a>>;
+
+    /// Replaces at most `count` non-overlapping occurrences of `needle` in `self` with
+    /// `replacement`, reading left to right; `count = usize::MAX` replaces every occurrence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stringzilla::sz::StringZillableBinary;
+    ///
+    /// let haystack = "abcabcabc";
+    /// assert_eq!(haystack.sz_replacen("abc".as_bytes(), "x".as_bytes(), 2), b"xxabc");
+    /// ```
+    fn sz_replacen(&self, needle: N, replacement: N, count: usize) -> Vec<u8>;
+
+    /// Replaces every non-overlapping occurrence of `needle` in `self` with `replacement`,
+    /// reading left to right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stringzilla::sz::StringZillableBinary;
+    ///
+    /// let haystack = "abcabcabc";
+    /// assert_eq!(haystack.sz_replace("abc".as_bytes(), "x".as_bytes()), b"xxx");
+    /// ```
+    fn sz_replace(&self, needle: N, replacement: N) -> Vec<u8>;
 }
 
 impl<T> StringZillableUnary for T
@@ -2832,6 +5868,42 @@ where
     fn sz_utf8_whitespace_splits(&self) -> RangeWhitespaceUtf8Splits<'_> {
         RangeWhitespaceUtf8Splits::new(self.as_ref())
     }
+
+    fn sz_graphemes(&self) -> Utf8Graphemes<'_> {
+        Utf8View::new(self.as_ref()).graphemes()
+    }
+
+    fn sz_grapheme_indices(&self) -> Utf8GraphemeIndices<'_> {
+        Utf8View::new(self.as_ref()).grapheme_indices()
+    }
+
+    fn sz_words(&self) -> Utf8Words<'_> {
+        Utf8View::new(self.as_ref()).words()
+    }
+
+    fn sz_word_indices(&self) -> Utf8WordIndices<'_> {
+        Utf8View::new(self.as_ref()).word_indices()
+    }
+
+    fn sz_sentences(&self) -> Utf8Sentences<'_> {
+        Utf8View::new(self.as_ref()).sentences()
+    }
+
+    fn sz_sentence_indices(&self) -> Utf8SentenceIndices<'_> {
+        Utf8View::new(self.as_ref()).sentence_indices()
+    }
+
+    fn sz_char_indices(&self) -> Utf8CharIndices<'_> {
+        Utf8View::new(self.as_ref()).char_indices()
+    }
+
+    fn sz_utf8_chunks(&self) -> Utf8Chunks<'_> {
+        Utf8Chunks::new(self.as_ref())
+    }
+
+    fn sz_to_str_lossy(&self) -> std::borrow::Cow<'_, str> {
+        Utf8View::new(self.as_ref()).to_str_lossy()
+    }
 }
 
 impl<'a, T, N> StringZillableBinary<'a, N> for T
@@ -2867,32 +5939,48 @@ where
         RangeMatches::new(self.as_ref(), MatcherType::Find(needle.as_ref()), true)
     }
 
-    fn sz_rmatches(&'a self, needle: &'a N) -> RangeRMatches<'a> {
-        RangeRMatches::new(self.as_ref(), MatcherType::RFind(needle.as_ref()), true)
+    fn sz_rmatches(&'a self, needle: &'a N) -> core::iter::Rev<RangeMatches<'a>> {
+        RangeMatches::new(self.as_ref(), MatcherType::Find(needle.as_ref()), true).rev()
     }
 
     fn sz_splits(&'a self, needle: &'a N) -> RangeSplits<'a> {
         RangeSplits::new(self.as_ref(), MatcherType::Find(needle.as_ref()))
     }
 
-    fn sz_rsplits(&'a self, needle: &'a N) -> RangeRSplits<'a> {
-        RangeRSplits::new(self.as_ref(), MatcherType::RFind(needle.as_ref()))
+    fn sz_rsplits(&'a self, needle: &'a N) -> core::iter::Rev<RangeSplits<'a>> {
+        RangeSplits::new(self.as_ref(), MatcherType::Find(needle.as_ref())).rev()
+    }
+
+    fn sz_splitn(&'a self, n: usize, needle: &'a N) -> RangeSplitsN<'a> {
+        RangeSplitsN::new(self.as_ref(), MatcherType::Find(needle.as_ref()), n, false)
+    }
+
+    fn sz_rsplitn(&'a self, n: usize, needle: &'a N) -> RangeSplitsN<'a> {
+        RangeSplitsN::new(self.as_ref(), MatcherType::Find(needle.as_ref()), n, true)
     }
 
     fn sz_find_first_of(&'a self, needles: &'a N) -> RangeMatches<'a> {
         RangeMatches::new(self.as_ref(), MatcherType::FindFirstOf(needles.as_ref()), true)
     }
 
-    fn sz_find_last_of(&'a self, needles: &'a N) -> RangeRMatches<'a> {
-        RangeRMatches::new(self.as_ref(), MatcherType::FindLastOf(needles.as_ref()), true)
+    fn sz_find_last_of(&'a self, needles: &'a N) -> core::iter::Rev<RangeMatches<'a>> {
+        RangeMatches::new(self.as_ref(), MatcherType::FindFirstOf(needles.as_ref()), true).rev()
     }
 
     fn sz_find_first_not_of(&'a self, needles: &'a N) -> RangeMatches<'a> {
         RangeMatches::new(self.as_ref(), MatcherType::FindFirstNotOf(needles.as_ref()), true)
     }
 
-    fn sz_find_last_not_of(&'a self, needles: &'a N) -> RangeRMatches<'a> {
-        RangeRMatches::new(self.as_ref(), MatcherType::FindLastNotOf(needles.as_ref()), true)
+    fn sz_find_last_not_of(&'a self, needles: &'a N) -> core::iter::Rev<RangeMatches<'a>> {
+        RangeMatches::new(self.as_ref(), MatcherType::FindFirstNotOf(needles.as_ref()), true).rev()
+    }
+
+    fn sz_replacen(&self, needle: N, replacement: N, count: usize) -> Vec<u8> {
+        replacen(self.as_ref(), needle.as_ref(), replacement.as_ref(), count)
+    }
+
+    fn sz_replace(&self, needle: N, replacement: N) -> Vec<u8> {
+        replace(self.as_ref(), needle.as_ref(), replacement.as_ref())
     }
 }
 
@@ -2953,6 +6041,27 @@ mod tests {
         assert_eq!(streamed, expected);
     }
 
+    #[test]
+    fn stable_hasher_is_deterministic() {
+        let mut a = sz::StableSzHasher::new(7);
+        a.write(b"Hello").write(b"World");
+        let mut b = sz::StableSzHasher::new(7);
+        b.write(b"Hello").write(b"World");
+        assert_eq!(a.finish_stable(), b.finish_stable());
+    }
+
+    #[test]
+    fn stable_hasher_distinguishes_chunk_boundaries() {
+        // Without length-prefixing, ("a", "b") and ("ab", "") could collide.
+        let mut a = sz::StableSzHasher::new(0);
+        a.write(b"a").write(b"b");
+
+        let mut b = sz::StableSzHasher::new(0);
+        b.write(b"ab").write(b"");
+
+        assert_ne!(a.finish_stable(), b.finish_stable());
+    }
+
     #[test]
     fn hashmap_with_sz() {
         let mut map: HashMap<&str, i32, sz::BuildSzHasher> = HashMap::with_hasher(sz::BuildSzHasher::with_seed(0));
@@ -2978,6 +6087,65 @@ mod tests {
         assert_eq!(set.len(), len_before);
     }
 
+    #[test]
+    fn random_state_varies_across_instances() {
+        let a = sz::RandomState::new();
+        let b = sz::RandomState::new();
+        assert_ne!(a.seed, b.seed, "successive RandomState instances should not repeat a seed");
+    }
+
+    #[test]
+    fn hashmap_with_random_state() {
+        let mut map: HashMap<&str, i32, sz::RandomState> = HashMap::with_hasher(sz::RandomState::new());
+        map.insert("a", 1);
+        map.insert("b", 2);
+        assert_eq!(map.get("a"), Some(&1));
+        assert_eq!(map.get("b"), Some(&2));
+    }
+
+    #[test]
+    fn quality_avalanche_is_well_mixed() {
+        let report = sz::quality::avalanche(42, 16, 64);
+        assert!(
+            report.worst_case_bias < 0.5,
+            "worst-case avalanche bias too high: {:?}",
+            report
+        );
+    }
+
+    #[test]
+    fn quality_distribution_is_roughly_uniform() {
+        let report = sz::quality::distribution_uniformity(42, 16, 4096, 16);
+        // With 16 buckets the chi-squared statistic should stay in a sane range for a
+        // well-mixed hash; a badly biased hash would blow this far past the bucket count.
+        assert!(
+            report.chi_squared < (report.bucket_count as f64) * 10.0,
+            "chi-squared too high: {:?}",
+            report
+        );
+    }
+
+    #[test]
+    fn quality_sequential_integers_rarely_collide() {
+        let report = sz::quality::collisions_of_sequential_integers(42, 10_000);
+        assert_eq!(report.keys_tested, 10_000);
+        assert!(report.collisions < 10, "too many collisions: {:?}", report);
+    }
+
+    #[test]
+    fn quality_short_ascii_keys_rarely_collide() {
+        let report = sz::quality::collisions_of_short_ascii_keys(42, 5_000, 8);
+        assert_eq!(report.keys_tested, 5_000);
+        assert!(report.collisions < 10, "too many collisions: {:?}", report);
+    }
+
+    #[test]
+    fn quality_single_byte_variants_rarely_collide() {
+        let report = sz::quality::collisions_of_single_byte_variants(42, b"base-key-0000", 4_000);
+        assert_eq!(report.keys_tested, 4_000);
+        assert!(report.collisions < 10, "too many collisions: {:?}", report);
+    }
+
     #[test]
     fn search() {
         let my_string: String = String::from("Hello, world!");
@@ -3081,6 +6249,46 @@ mod tests {
         assert_eq!(splits, vec!["こんにちは".as_bytes(), "世界".as_bytes()]);
     }
 
+    #[test]
+    fn iter_splitn_caps_splits() {
+        let haystack = b"a,b,c,d";
+        let needle = b",";
+        let splits: Vec<_> = haystack.sz_splitn(2, needle).collect();
+        assert_eq!(splits, vec![&b"a"[..], &b"b,c,d"[..]]);
+    }
+
+    #[test]
+    fn iter_splitn_n_exceeds_matches() {
+        let haystack = b"a,b";
+        let needle = b",";
+        let splits: Vec<_> = haystack.sz_splitn(100, needle).collect();
+        assert_eq!(splits, vec![&b"a"[..], &b"b"[..]]);
+    }
+
+    #[test]
+    fn iter_splitn_zero_is_empty() {
+        let haystack = b"a,b,c";
+        let needle = b",";
+        let splits: Vec<_> = haystack.sz_splitn(0, needle).collect();
+        assert!(splits.is_empty());
+    }
+
+    #[test]
+    fn iter_splitn_one_yields_whole_haystack() {
+        let haystack = b"a,b,c";
+        let needle = b",";
+        let splits: Vec<_> = haystack.sz_splitn(1, needle).collect();
+        assert_eq!(splits, vec![&b"a,b,c"[..]]);
+    }
+
+    #[test]
+    fn iter_rsplitn_caps_splits() {
+        let haystack = b"a,b,c,d";
+        let needle = b",";
+        let splits: Vec<_> = haystack.sz_rsplitn(2, needle).collect();
+        assert_eq!(splits, vec![&b"d"[..], &b"a,b,c"[..]]);
+    }
+
     #[test]
     fn iter_find_first_of() {
         let haystack = b"hello world";
@@ -3167,19 +6375,371 @@ mod tests {
     #[test]
     fn iter_range_rmatches_overlapping() {
         let haystack = b"aaaa";
-        let matcher = MatcherType::RFind(b"aa");
-        let matches: Vec<_> = RangeRMatches::new(haystack, matcher, true).collect();
+        let matcher = MatcherType::Find(b"aa");
+        let matches: Vec<_> = RangeMatches::new(haystack, matcher, true).rev().collect();
         assert_eq!(matches, vec![&b"aa"[..], &b"aa"[..], &b"aa"[..]]);
     }
 
     #[test]
     fn iter_range_rmatches_non_overlapping() {
         let haystack = b"aaaa";
-        let matcher = MatcherType::RFind(b"aa");
-        let matches: Vec<_> = RangeRMatches::new(haystack, matcher, false).collect();
+        let matcher = MatcherType::Find(b"aa");
+        let matches: Vec<_> = RangeMatches::new(haystack, matcher, false).rev().collect();
         assert_eq!(matches, vec![&b"aa"[..], &b"aa"[..]]);
     }
 
+    #[test]
+    fn iter_range_matches_double_ended_non_overlapping_does_not_overlap() {
+        // Alternating next()/next_back() on one iterator must claim disjoint matches: the front
+        // cursor takes the left edge, the back cursor the right edge, meeting in the middle.
+        let haystack = b"aaaa";
+        let matcher = MatcherType::Find(b"aa");
+        let mut matches = RangeMatches::new(haystack, matcher, false);
+        assert_eq!(matches.next(), Some(&b"aa"[..]));
+        assert_eq!(matches.next_back(), Some(&b"aa"[..]));
+        assert_eq!(matches.next(), None);
+        assert_eq!(matches.next_back(), None);
+    }
+
+    #[test]
+    fn iter_range_matches_double_ended_overlapping_agrees_with_one_sided_scans() {
+        let haystack = b"aaaaa";
+        let matcher = MatcherType::Find(b"aa");
+        let forward_only: Vec<_> = RangeMatches::new(haystack, matcher, true).collect();
+        let matcher = MatcherType::Find(b"aa");
+        let reverse_only: Vec<_> = RangeMatches::new(haystack, matcher, true).rev().collect();
+
+        let matcher = MatcherType::Find(b"aa");
+        let mut mixed = RangeMatches::new(haystack, matcher, true);
+        let mut collected = vec![mixed.next().unwrap()];
+        collected.push(mixed.next_back().unwrap());
+        collected.extend(mixed.by_ref());
+        assert_eq!(collected.len(), forward_only.len());
+        assert_eq!(collected.len(), reverse_only.len());
+    }
+
+    #[test]
+    fn iter_range_splits_double_ended_meets_in_the_middle() {
+        let haystack = b"a,b,c,d,e";
+        let matcher = MatcherType::Find(b",");
+        let mut splits = RangeSplits::new(haystack, matcher);
+        assert_eq!(splits.next(), Some(&b"a"[..]));
+        assert_eq!(splits.next_back(), Some(&b"e"[..]));
+        assert_eq!(splits.next(), Some(&b"b"[..]));
+        assert_eq!(splits.next_back(), Some(&b"d"[..]));
+        assert_eq!(splits.next(), Some(&b"c"[..]));
+        assert_eq!(splits.next(), None);
+        assert_eq!(splits.next_back(), None);
+    }
+
+    #[test]
+    fn find_with_prefilter_matcher_basic() {
+        let haystack = b"the quick brown fox jumps over the lazy dog";
+        let matcher = MatcherType::find_with_prefilter(b"lazy");
+        assert!(matches!(matcher, MatcherType::FindWithPrefilter { .. }));
+        assert_eq!(matcher.find(haystack), Some((35, 4)));
+    }
+
+    #[test]
+    fn find_with_prefilter_matcher_falls_back_for_common_rarest_byte() {
+        // Every byte of "east" is common (no control codes, no rare letters), so the rarest among
+        // them still sits above the threshold and the matcher should fall back to plain `Find`.
+        let matcher = MatcherType::find_with_prefilter(b"east");
+        assert!(matches!(matcher, MatcherType::Find(_)));
+    }
+
+    #[test]
+    fn find_with_prefilter_matcher_no_match() {
+        let matcher = MatcherType::find_with_prefilter(b"lazy");
+        assert!(matcher.find(b"the quick brown fox").is_none());
+    }
+
+    #[test]
+    fn find_with_prefilter_matcher_drives_range_matches_and_reverse() {
+        let haystack = b"lazy fox, lazy dog, lazy cat";
+        let matcher = MatcherType::find_with_prefilter(b"lazy");
+        let forward: Vec<_> = RangeMatches::new(haystack, matcher, false).collect();
+        assert_eq!(forward, vec![&b"lazy"[..], &b"lazy"[..], &b"lazy"[..]]);
+
+        let matcher = MatcherType::find_with_prefilter(b"lazy");
+        let backward: Vec<_> = RangeMatches::new(haystack, matcher, false).rev().collect();
+        assert_eq!(backward, vec![&b"lazy"[..], &b"lazy"[..], &b"lazy"[..]]);
+    }
+
+    #[test]
+    fn sz_searcher_steps_through_matches_and_rejects() {
+        let mut searcher = SzSearcher::new(b"a,b,c", MatcherType::Find(b","));
+        assert_eq!(searcher.next(), SearchStep::Reject(0, 1));
+        assert_eq!(searcher.next(), SearchStep::Match(1, 2));
+        assert_eq!(searcher.next(), SearchStep::Reject(2, 3));
+        assert_eq!(searcher.next(), SearchStep::Match(3, 4));
+        assert_eq!(searcher.next(), SearchStep::Reject(4, 5));
+        assert_eq!(searcher.next(), SearchStep::Done);
+    }
+
+    #[test]
+    fn sz_searcher_next_back_yields_same_matches_in_reverse() {
+        let haystack = b"a,,b,";
+        let mut forward = SzSearcher::new(haystack, MatcherType::Find(b","));
+        let mut forward_matches = Vec::new();
+        while let Some(found) = forward.next_match() {
+            forward_matches.push(found);
+        }
+
+        let mut backward = SzSearcher::new(haystack, MatcherType::Find(b","));
+        let mut backward_matches = Vec::new();
+        while let Some(found) = backward.next_match_back() {
+            backward_matches.push(found);
+        }
+        backward_matches.reverse();
+
+        assert_eq!(forward_matches, backward_matches);
+        assert_eq!(forward_matches, vec![(1, 2), (2, 3), (4, 5)]);
+    }
+
+    #[test]
+    fn sz_match_indices_basic() {
+        let haystack = "a,b,c";
+        let matches: Vec<_> = haystack.sz_match_indices(",").collect();
+        assert_eq!(matches, vec![(1, ",".as_bytes()), (3, ",".as_bytes())]);
+    }
+
+    #[test]
+    fn sz_match_indices_reverse_matches_forward_order_reversed() {
+        let haystack = "a,,b,";
+        let forward: Vec<_> = haystack.sz_match_indices(",").collect();
+        let mut backward: Vec<_> = haystack.sz_match_indices(",").rev().collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+    }
+
+    #[test]
+    fn sz_split_pattern_basic() {
+        let haystack = "a,,b,";
+        let parts: Vec<&[u8]> = haystack.sz_split_pattern(",").collect();
+        assert_eq!(parts, vec![b"a", &b""[..], b"b", &b""[..]]);
+    }
+
+    #[test]
+    fn sz_split_pattern_accepts_a_prebuilt_matcher_type() {
+        let haystack = b"Hello, WORLD!";
+        let config = MatcherConfig { ignore_case: true, normalize: false };
+        let matcher = MatcherType::find_folded("world", config).unwrap();
+        let parts: Vec<&[u8]> = haystack.sz_split_pattern(matcher).collect();
+        assert_eq!(parts, vec![&b"Hello, "[..], &b"!"[..]]);
+    }
+
+    #[test]
+    fn sz_split_indices_basic() {
+        let haystack = "a,,b,";
+        let parts: Vec<_> = haystack.sz_split_indices(",").collect();
+        assert_eq!(parts, vec![(0, "a".as_bytes()), (2, "".as_bytes()), (3, "b".as_bytes()), (5, "".as_bytes())]);
+    }
+
+    #[test]
+    fn sz_split_indices_agrees_with_sz_split_pattern() {
+        let haystack = "alpha,beta,,gamma";
+        let segments: Vec<&[u8]> = haystack.sz_split_pattern(",").collect();
+        let indexed: Vec<_> = haystack.sz_split_indices(",").collect();
+        assert_eq!(indexed.iter().map(|&(_, segment)| segment).collect::<Vec<_>>(), segments);
+        for (offset, segment) in indexed {
+            assert_eq!(&haystack.as_bytes()[offset..offset + segment.len()], segment);
+        }
+    }
+
+    #[test]
+    fn sz_match_indices_accepts_a_byte_pattern() {
+        let haystack = "a,,b,";
+        let matches: Vec<_> = haystack.sz_match_indices(b',').collect();
+        assert_eq!(matches, vec![(1, ",".as_bytes()), (2, ",".as_bytes()), (4, ",".as_bytes())]);
+    }
+
+    #[test]
+    fn sz_split_pattern_accepts_a_byteset() {
+        let haystack = "one,two;three";
+        let parts: Vec<&[u8]> = haystack.sz_split_pattern(Byteset::from(",;")).collect();
+        assert_eq!(parts, vec![&b"one"[..], b"two", b"three"]);
+    }
+
+    #[test]
+    fn sz_split_pattern_accepts_an_ascii_predicate() {
+        let haystack = b"room101";
+        let parts: Vec<&[u8]> =
+            haystack.sz_split_pattern(AsciiPredicate(|byte: u8| byte.is_ascii_digit())).collect();
+        assert_eq!(parts, vec![&b"room"[..], b"", b"", b""]);
+    }
+
+    #[test]
+    fn find_folded_ignore_case() {
+        let config = MatcherConfig { ignore_case: true, normalize: false };
+        let matcher = MatcherType::find_folded("world", config).unwrap();
+        assert_eq!(matcher.find(b"Hello, WORLD!"), Some((7, 5)));
+    }
+
+    #[test]
+    fn find_folded_ignore_case_preserves_byte_offsets_across_matches() {
+        let haystack = "Café CAFÉ cafe".as_bytes();
+        let config = MatcherConfig { ignore_case: true, normalize: true };
+        let matcher = MatcherType::find_folded("cafe", config).unwrap();
+        let matches: Vec<_> = RangeMatches::new(haystack, matcher, false).collect();
+        assert_eq!(matches, vec!["Café".as_bytes(), "CAFÉ".as_bytes(), b"cafe"]);
+    }
+
+    #[test]
+    fn find_folded_normalize_without_ignore_case_keeps_case_sensitivity() {
+        let config = MatcherConfig { ignore_case: false, normalize: true };
+        let matcher = MatcherType::find_folded("cafe", config).unwrap();
+        assert_eq!(matcher.find("café".as_bytes()), Some((0, 5)));
+        assert!(matcher.find("CAFÉ".as_bytes()).is_none());
+    }
+
+    #[test]
+    fn find_folded_no_match() {
+        let config = MatcherConfig { ignore_case: true, normalize: true };
+        let matcher = MatcherType::find_folded("xyz", config).unwrap();
+        assert!(matcher.find(b"Hello, world!").is_none());
+    }
+
+    #[test]
+    fn find_folded_rejects_invalid_utf8_needle() {
+        let config = MatcherConfig::default();
+        let result = MatcherType::find_folded(&b"\xFF"[..], config);
+        assert_eq!(result.err(), Some(Status::InvalidUtf8));
+    }
+
+    #[test]
+    fn find_many_basic() {
+        let haystack = b"the cat sat on the mat";
+        let matches = sz::find_many(haystack, &[&b"cat"[..], &b"mat"[..], &b"sat"[..]]).unwrap();
+        let offsets: Vec<_> = matches.iter().map(|m| (m.pattern_index, m.offset)).collect();
+        assert_eq!(offsets, vec![(0, 4), (2, 8), (1, 19)]);
+    }
+
+    #[test]
+    fn find_many_empty_needle_set() {
+        let matches = sz::find_many(b"hello", &[] as &[&[u8]]).unwrap();
+        assert!(matches.is_empty());
+    }
+
+    #[test]
+    fn find_many_rejects_empty_needle() {
+        let result = sz::find_many(b"hello", &[&b""[..]]);
+        assert_eq!(result, Err(Status::UnexpectedDimensions));
+    }
+
+    #[test]
+    fn find_many_leftmost_longest() {
+        // "hers" extends "he" through direct trie edges from the same start, so the longer
+        // match replaces the pending "he" and is the one reported.
+        let haystack = b"ushers";
+        let matches = sz::find_many(haystack, &[&b"he"[..], &b"hers"[..]]).unwrap();
+        assert_eq!(matches, vec![ManyMatch { pattern_index: 1, offset: 2 }]);
+    }
+
+    #[test]
+    fn find_many_prefers_longest_at_same_endpoint() {
+        // "ab" and "b" both complete exactly when the final "b" is read; the longer of the
+        // two sharing that endpoint is reported.
+        let haystack = b"xab";
+        let matches = sz::find_many(haystack, &[&b"b"[..], &b"ab"[..]]).unwrap();
+        assert_eq!(matches, vec![ManyMatch { pattern_index: 1, offset: 1 }]);
+    }
+
+    #[test]
+    fn find_any_stops_at_first_match() {
+        let haystack = b"abcdefg";
+        let found = sz::find_any(haystack, &[&b"def"[..], &b"cd"[..]]).unwrap();
+        assert_eq!(found, Some(ManyMatch { pattern_index: 1, offset: 2 }));
+    }
+
+    #[test]
+    fn find_any_none() {
+        let haystack = b"abcdefg";
+        let found = sz::find_any(haystack, &[&b"xyz"[..]]).unwrap();
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn find_many_extends_across_direct_trie_edge() {
+        // "\r" completes one byte in, but a direct trie edge out of that state can still extend
+        // it into "\r\n"; the match must be held pending until that extension is ruled out.
+        let haystack = b"a\r\nb\rc";
+        let matches = sz::find_many(haystack, &[&b"\r\n"[..], &b"\r"[..]]).unwrap();
+        let offsets: Vec<_> = matches.iter().map(|m| (m.pattern_index, m.offset)).collect();
+        assert_eq!(offsets, vec![(0, 1), (1, 4)]);
+    }
+
+    #[test]
+    fn find_any_of_matches_any_pattern() {
+        let haystack = b"line one\r\nline two\nline three\rdone";
+        let matcher = MatcherType::find_any_of(&[&b"\r\n"[..], &b"\n"[..], &b"\r"[..]]).unwrap();
+        let matches: Vec<&[u8]> = RangeMatches::new(haystack, matcher, false).collect();
+        assert_eq!(matches, vec![&b"\r\n"[..], b"\n", b"\r"]);
+    }
+
+    #[test]
+    fn find_any_of_splits_mixed_line_endings() {
+        let haystack = b"line one\r\nline two\nline three\rdone";
+        let matcher = MatcherType::find_any_of(&[&b"\r\n"[..], &b"\n"[..], &b"\r"[..]]).unwrap();
+        let lines: Vec<&[u8]> = RangeSplits::new(haystack, matcher).collect();
+        assert_eq!(lines, vec![&b"line one"[..], b"line two", b"line three", b"done"]);
+    }
+
+    #[test]
+    fn find_any_of_rejects_empty_needle() {
+        let result = MatcherType::find_any_of(&[&b""[..]]);
+        assert!(matches!(result, Err(Status::UnexpectedDimensions)));
+    }
+
+    #[test]
+    fn sz_multi_searcher_matches_any_basic() {
+        let haystack = b"the cat sat on the mat";
+        let searcher = SzMultiSearcher::new(&[&b"cat"[..], &b"mat"[..], &b"sat"[..]]).unwrap();
+        assert_eq!(searcher.matches_any(haystack), vec![(0, 4, 7), (2, 8, 11), (1, 19, 22)]);
+    }
+
+    #[test]
+    fn sz_multi_searcher_find_any_stops_at_first_match() {
+        let haystack = b"abcdefg";
+        let searcher = SzMultiSearcher::new(&[&b"def"[..], &b"cd"[..]]).unwrap();
+        assert_eq!(searcher.find_any(haystack), Some((1, 2, 4)));
+    }
+
+    #[test]
+    fn sz_multi_searcher_falls_back_to_simd_for_one_needle() {
+        let haystack = b"ababab";
+        let searcher = SzMultiSearcher::new(&[&b"ab"[..]]).unwrap();
+        assert_eq!(searcher.matches_any(haystack), vec![(0, 0, 2), (0, 2, 4), (0, 4, 6)]);
+    }
+
+    #[test]
+    fn sz_multi_searcher_standard_kind_prefers_first_registered_needle() {
+        // "b" and "ab" both complete when the final "b" is read; LeftmostLongest prefers the
+        // longer "ab", while Standard prefers whichever needle was registered first.
+        let haystack = b"xab";
+        let longest = SzMultiSearcher::with_kind(&[&b"b"[..], &b"ab"[..]], MultiMatchKind::LeftmostLongest).unwrap();
+        assert_eq!(longest.matches_any(haystack), vec![(1, 1, 3)]);
+
+        let standard = SzMultiSearcher::with_kind(&[&b"b"[..], &b"ab"[..]], MultiMatchKind::Standard).unwrap();
+        assert_eq!(standard.matches_any(haystack), vec![(0, 2, 3)]);
+    }
+
+    #[test]
+    fn sz_multi_searcher_rejects_empty_needle() {
+        let result = SzMultiSearcher::new(&[&b""[..]]);
+        assert!(matches!(result, Err(Status::UnexpectedDimensions)));
+    }
+
+    #[test]
+    fn sz_find_any_and_sz_matches_any_trait_methods() {
+        let haystack = "the cat sat on the mat";
+        let searcher = SzMultiSearcher::new(&["cat", "mat", "sat"]).unwrap();
+        assert_eq!(haystack.sz_find_any(&searcher), Some((0, 4, 7)));
+
+        let matches: Vec<_> = haystack.sz_matches_any(&searcher).collect();
+        assert_eq!(matches, vec![(0, 4, 7), (2, 8, 11), (1, 19, 22)]);
+    }
+
     #[test]
     fn argsort_permutation_default() {
         // Test with a slice of string literals.
@@ -3423,6 +6983,68 @@ mod tests {
         assert_eq!(mac, expected);
     }
 
+    #[test]
+    fn fixed_time_eq_basic() {
+        assert!(sz::fixed_time_eq(b"same", b"same"));
+        assert!(!sz::fixed_time_eq(b"same", b"diff"));
+        assert!(!sz::fixed_time_eq(b"short", b"longer"));
+        assert!(sz::fixed_time_eq(b"", b""));
+    }
+
+    #[test]
+    fn hmac_sha256_verify_roundtrip() {
+        let key = b"secret_key";
+        let message = b"important message";
+        let mac = sz::hmac_sha256(key, message);
+        assert!(sz::hmac_sha256_verify(key, message, &mac));
+
+        let mut tampered = mac;
+        tampered[0] ^= 1;
+        assert!(!sz::hmac_sha256_verify(key, message, &tampered));
+        assert!(!sz::hmac_sha256_verify(key, b"different message", &mac));
+    }
+
+    #[test]
+    fn hkdf_rfc5869_test_case_1() {
+        // RFC 5869 Appendix A.1 (adapted for SHA256, which is the test vector it defines).
+        let ikm = [0x0b; 22];
+        let salt = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c,
+        ];
+        let info = [
+            0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9,
+        ];
+        let prk = sz::hkdf_extract(&salt, &ikm);
+        let expected_prk = [
+            0x07, 0x77, 0x09, 0x36, 0x2c, 0x2e, 0x32, 0xdf, 0x0d, 0xdc, 0x3f, 0x0d, 0xc4, 0x7b, 0xba, 0x63, 0x90, 0xb6,
+            0xc7, 0x3b, 0xb5, 0x0f, 0x9c, 0x31, 0x22, 0xec, 0x84, 0x4a, 0xd7, 0xc2, 0xb3, 0xe5,
+        ];
+        assert_eq!(prk, expected_prk);
+
+        let mut okm = [0u8; 42];
+        sz::hkdf_expand(&prk, &info, &mut okm).unwrap();
+        let expected_okm = [
+            0x3c, 0xb2, 0x5f, 0x25, 0xfa, 0xac, 0xd5, 0x7a, 0x90, 0x43, 0x4f, 0x64, 0xd0, 0x36, 0x2f, 0x2a, 0x2d, 0x2d,
+            0x0a, 0x90, 0xcf, 0x1a, 0x5a, 0x4c, 0x5d, 0xb0, 0x2d, 0x56, 0xec, 0xc4, 0xc5, 0xbf, 0x34, 0x00, 0x72, 0x08,
+            0xd5, 0xb8, 0x87, 0x18, 0x58, 0x65,
+        ];
+        assert_eq!(okm, expected_okm);
+    }
+
+    #[test]
+    fn hkdf_empty_salt_uses_zero_padding() {
+        let prk_empty_salt = sz::hkdf_extract(b"", b"input key material");
+        let prk_zero_salt = sz::hkdf_extract(&[0u8; 32], b"input key material");
+        assert_eq!(prk_empty_salt, prk_zero_salt);
+    }
+
+    #[test]
+    fn hkdf_expand_rejects_oversized_output() {
+        let prk = sz::hkdf_extract(b"salt", b"ikm");
+        let mut out = vec![0u8; 255 * 32 + 1];
+        assert_eq!(sz::hkdf_expand(&prk, b"info", &mut out), Err(sz::Status::OverflowRisk));
+    }
+
     #[test]
     #[should_panic]
     fn copy_size_checks() {
@@ -3501,6 +7123,159 @@ mod tests {
         assert_eq!(buffer, b"unchanged");
     }
 
+    #[test]
+    fn replace_first_n_same_length() {
+        let mut buffer = b"abcabcabc".to_vec();
+        let replaced = sz::try_replace_first_n(&mut buffer, b"ab", b"XY", 2).unwrap();
+        assert_eq!(replaced, 2);
+        assert_eq!(buffer, b"XYcXYcabc");
+    }
+
+    #[test]
+    fn replace_first_n_shrinks() {
+        let mut buffer = b"aaaaaa".to_vec();
+        let replaced = sz::try_replace_first_n(&mut buffer, b"aa", b"b", 2).unwrap();
+        assert_eq!(replaced, 2);
+        assert_eq!(buffer, b"bbaa");
+    }
+
+    #[test]
+    fn replace_first_n_grows() {
+        let mut buffer = b"aaaa".to_vec();
+        let replaced = sz::try_replace_first_n(&mut buffer, b"a", b"XY", 2).unwrap();
+        assert_eq!(replaced, 2);
+        assert_eq!(buffer, b"XYXYaa");
+    }
+
+    #[test]
+    fn replace_first_n_count_exceeds_matches() {
+        let mut buffer = b"aba".to_vec();
+        let replaced = sz::try_replace_first_n(&mut buffer, b"a", b"XYZ", 100).unwrap();
+        assert_eq!(replaced, 2);
+        assert_eq!(buffer, b"XYZbXYZ");
+    }
+
+    #[test]
+    fn replace_first_n_zero_count_is_noop() {
+        let mut buffer = b"aaaa".to_vec();
+        let replaced = sz::try_replace_first_n(&mut buffer, b"a", b"XYZ", 0).unwrap();
+        assert_eq!(replaced, 0);
+        assert_eq!(buffer, b"aaaa");
+    }
+
+    #[test]
+    fn replace_basic() {
+        assert_eq!(sz::replace(b"abcabcabc", b"abc", b"x"), b"xxx");
+    }
+
+    #[test]
+    fn replace_grows() {
+        assert_eq!(sz::replace(b"aba", b"a", b"XYZ"), b"XYZbXYZ");
+    }
+
+    #[test]
+    fn replace_no_match_returns_copy() {
+        assert_eq!(sz::replace(b"unchanged", b"xyz", b"anything"), b"unchanged");
+    }
+
+    #[test]
+    fn replace_noop_on_empty_needle() {
+        assert_eq!(sz::replace(b"unchanged", b"", b"anything"), b"unchanged");
+    }
+
+    #[test]
+    fn replacen_stops_after_count() {
+        assert_eq!(sz::replacen(b"abcabcabc", b"abc", b"x", 2), b"xxabc");
+    }
+
+    #[test]
+    fn replacen_zero_count_is_noop() {
+        assert_eq!(sz::replacen(b"aaaa", b"a", b"XYZ", 0), b"aaaa");
+    }
+
+    #[test]
+    fn replacen_count_exceeds_matches() {
+        assert_eq!(sz::replacen(b"aba", b"a", b"XYZ", 100), b"XYZbXYZ");
+    }
+
+    #[test]
+    fn sz_replace_trait_methods() {
+        let haystack = "abcabcabc";
+        assert_eq!(haystack.sz_replace("abc".as_bytes(), "x".as_bytes()), b"xxx");
+        assert_eq!(haystack.sz_replacen("abc".as_bytes(), "x".as_bytes(), 2), b"xxabc");
+    }
+
+    #[test]
+    fn match_indices_basic() {
+        let spans: Vec<_> = sz::match_indices(b"ababab", b"ab").collect();
+        assert_eq!(spans, vec![(0, 2), (2, 2), (4, 2)]);
+    }
+
+    #[test]
+    fn match_indices_no_matches() {
+        let spans: Vec<_> = sz::match_indices(b"hello", b"xyz").collect();
+        assert_eq!(spans, Vec::<(usize, usize)>::new());
+    }
+
+    #[test]
+    fn rmatch_indices_basic() {
+        let spans: Vec<_> = sz::rmatch_indices(b"ababab", b"ab").collect();
+        assert_eq!(spans, vec![(4, 2), (2, 2), (0, 2)]);
+    }
+
+    #[test]
+    fn rmatch_indices_matches_forward_reversed() {
+        let forward: Vec<_> = sz::match_indices(b"aXaXaXa", b"a").collect();
+        let mut reversed: Vec<_> = sz::rmatch_indices(b"aXaXaXa", b"a").collect();
+        reversed.reverse();
+        assert_eq!(forward, reversed);
+    }
+
+    #[test]
+    fn rarest_byte_prefers_least_common_letter() {
+        let (position, byte) = sz::rarest_byte(b"the size").unwrap();
+        assert_eq!(byte, b'z');
+        assert_eq!(position, 6);
+    }
+
+    #[test]
+    fn rarest_byte_breaks_ties_by_earliest_position() {
+        let (position, byte) = sz::rarest_byte(b"zz").unwrap();
+        assert_eq!(byte, b'z');
+        assert_eq!(position, 0);
+    }
+
+    #[test]
+    fn rarest_byte_empty_needle() {
+        assert_eq!(sz::rarest_byte(b""), None);
+    }
+
+    #[test]
+    fn find_with_prefilter_basic() {
+        let haystack = b"the quick brown fox jumps over the lazy dog";
+        assert_eq!(sz::find_with_prefilter(haystack, b"lazy"), Some(35));
+        assert_eq!(sz::find_with_prefilter(haystack, b"cat"), None);
+    }
+
+    #[test]
+    fn find_with_prefilter_matches_plain_find() {
+        let haystack = b"abracadabra needle in a haystack with needle again";
+        assert_eq!(
+            sz::find_with_prefilter(haystack, b"needle"),
+            sz::find(haystack, b"needle")
+        );
+    }
+
+    #[test]
+    fn find_with_prefilter_needle_longer_than_haystack() {
+        assert_eq!(sz::find_with_prefilter(b"hi", b"hello"), None);
+    }
+
+    #[test]
+    fn find_with_prefilter_empty_needle() {
+        assert_eq!(sz::find_with_prefilter(b"hello", b""), None);
+    }
+
     #[test]
     fn find_newline_utf8_lf() {
         let text = "Hello\nWorld";
@@ -3748,6 +7523,74 @@ mod tests {
         assert_eq!(span.length, 1);
     }
 
+    #[test]
+    fn find_newline_wtf8_basic() {
+        let span = find_newline_wtf8(b"Hello\nWorld").unwrap();
+        assert_eq!(span.offset, 5);
+        assert_eq!(span.length, 1);
+    }
+
+    #[test]
+    fn find_newline_wtf8_crlf() {
+        let span = find_newline_wtf8(b"Hello\r\nWorld").unwrap();
+        assert_eq!(span.offset, 5);
+        assert_eq!(span.length, 2);
+    }
+
+    #[test]
+    fn find_newline_wtf8_tolerates_unpaired_surrogate() {
+        // `\xED\xA0\x80` is the WTF-8 encoding of the unpaired surrogate U+D800: well-formed
+        // UTF-8 decoding would reject it, but the WTF-8 scan must treat it as an opaque unit and
+        // keep scanning forward to the real newline that follows.
+        let mut text = b"Hello".to_vec();
+        text.extend_from_slice(b"\xED\xA0\x80");
+        text.extend_from_slice(b"\nWorld");
+        let span = find_newline_wtf8(&text).unwrap();
+        assert_eq!(span.offset, 8);
+        assert_eq!(span.length, 1);
+    }
+
+    #[test]
+    fn find_newline_wtf8_not_found() {
+        assert!(find_newline_wtf8(b"Hello World").is_none());
+    }
+
+    #[test]
+    fn find_whitespace_wtf8_basic() {
+        let span = find_whitespace_wtf8(b"Hello World").unwrap();
+        assert_eq!(span.offset, 5);
+        assert_eq!(span.length, 1);
+    }
+
+    #[test]
+    fn find_whitespace_wtf8_tolerates_unpaired_surrogate() {
+        let mut text = b"Hello\xED\xA0\x80".to_vec();
+        text.extend_from_slice(" World".as_bytes());
+        let span = find_whitespace_wtf8(&text).unwrap();
+        assert_eq!(span.offset, 8);
+        assert_eq!(span.length, 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn os_str_as_wtf8_round_trips_through_os_string_from_wtf8() {
+        use std::ffi::OsStr;
+        let path = OsStr::new("/tmp/café");
+        let bytes = os_str_as_wtf8(path);
+        assert_eq!(bytes, "/tmp/café".as_bytes());
+        assert_eq!(os_string_from_wtf8(bytes.to_vec()), path);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn os_str_as_wtf8_works_with_existing_byte_level_matchers() {
+        use crate::sz::StringZillableUnary;
+        use std::ffi::OsStr;
+        let path = OsStr::new("/tmp/report.csv");
+        let bytes = os_str_as_wtf8(path);
+        assert_eq!(bytes.sz_find(b".csv"), Some(11));
+    }
+
     #[test]
     fn iter_newline_utf8_splits() {
         let text = b"a\nb\r\nc\n\nd";
@@ -3762,6 +7605,48 @@ mod tests {
         assert_eq!(lines, vec!["Hello".as_bytes(), "World".as_bytes()]);
     }
 
+    #[test]
+    fn lines_utf8_strips_terminators() {
+        let lines: Vec<_> = sz::lines_utf8("Hello\nWorld\r\nRust").collect();
+        assert_eq!(lines, vec!["Hello", "World", "Rust"]);
+    }
+
+    #[test]
+    fn lines_utf8_no_trailing_empty_line() {
+        let lines: Vec<_> = sz::lines_utf8("a\nb\n").collect();
+        assert_eq!(lines, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn lines_utf8_unterminated_final_line() {
+        let lines: Vec<_> = sz::lines_utf8("a\nb").collect();
+        assert_eq!(lines, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn lines_utf8_empty_input() {
+        let lines: Vec<_> = sz::lines_utf8("").collect();
+        assert_eq!(lines, Vec::<&str>::new());
+    }
+
+    #[test]
+    fn lines_utf8_unicode_separators() {
+        let lines: Vec<_> = sz::lines_utf8("Hello\u{2028}World").collect();
+        assert_eq!(lines, vec!["Hello", "World"]);
+    }
+
+    #[test]
+    fn lines_with_terminator_utf8_retains_terminators() {
+        let lines: Vec<_> = sz::lines_with_terminator_utf8("Hello\nWorld\r\n").collect();
+        assert_eq!(lines, vec!["Hello\n", "World\r\n"]);
+    }
+
+    #[test]
+    fn lines_with_terminator_utf8_unterminated_final_line() {
+        let lines: Vec<_> = sz::lines_with_terminator_utf8("a\nb").collect();
+        assert_eq!(lines, vec!["a\n", "b"]);
+    }
+
     #[test]
     fn iter_whitespace_utf8_splits() {
         let text = b"  a \t b\n\nc  ";
@@ -3775,4 +7660,268 @@ mod tests {
         let words: Vec<_> = RangeWhitespaceUtf8Splits::new(text).collect();
         assert_eq!(words, vec![b"a", b"b", b"c"]);
     }
+
+    #[test]
+    fn utf8_view_graphemes_combining_marks() {
+        let text = "e\u{0301}clair"; // "é" as e + combining acute accent
+        let view = Utf8View::new(text.as_bytes());
+        let clusters: Vec<&str> = view.graphemes().collect();
+        assert_eq!(clusters[0], "e\u{0301}");
+        assert_eq!(clusters[1..], ["c", "l", "a", "i", "r"]);
+    }
+
+    #[test]
+    fn utf8_view_graphemes_regional_indicators() {
+        let text = "\u{1F1FA}\u{1F1F8}"; // regional indicators U + S ("flag")
+        let view = Utf8View::new(text.as_bytes());
+        let clusters: Vec<&str> = view.graphemes().collect();
+        assert_eq!(clusters, vec![text]);
+    }
+
+    #[test]
+    fn utf8_view_grapheme_indices_offsets() {
+        let text = "a\u{0301}b";
+        let view = Utf8View::new(text.as_bytes());
+        let indices: Vec<(usize, &str)> = view.grapheme_indices().collect();
+        assert_eq!(indices, vec![(0, "a\u{0301}"), (3, "b")]);
+    }
+
+    #[test]
+    fn utf8_view_words_basic() {
+        let view = Utf8View::new("Hello, world!".as_bytes());
+        let words: Vec<&str> = view.words().collect();
+        assert_eq!(words, vec!["Hello", "world"]);
+    }
+
+    #[test]
+    fn utf8_view_word_indices_offsets() {
+        let view = Utf8View::new("Hello, world!".as_bytes());
+        let indices: Vec<(usize, &str)> = view.word_indices().collect();
+        assert_eq!(indices, vec![(0, "Hello"), (7, "world")]);
+    }
+
+    #[test]
+    fn utf8_view_words_empty_text() {
+        let view = Utf8View::new(b"");
+        assert_eq!(view.words().count(), 0);
+    }
+
+    #[test]
+    fn utf8_view_sentences_basic() {
+        let view = Utf8View::new("Hi there! How are you?".as_bytes());
+        let sentences: Vec<&str> = view.sentences().collect();
+        assert_eq!(sentences, vec!["Hi there! ", "How are you?"]);
+    }
+
+    #[test]
+    fn utf8_view_sentences_trailing_without_terminator() {
+        let view = Utf8View::new("First. Second".as_bytes());
+        let sentences: Vec<&str> = view.sentences().collect();
+        assert_eq!(sentences, vec!["First. ", "Second"]);
+    }
+
+    #[test]
+    fn utf8_view_sentence_indices_offsets() {
+        let view = Utf8View::new("Hi there! How are you?".as_bytes());
+        let indices: Vec<(usize, &str)> = view.sentence_indices().collect();
+        assert_eq!(indices, vec![(0, "Hi there! "), (10, "How are you?")]);
+    }
+
+    #[test]
+    fn sz_graphemes_matches_utf8_view() {
+        let text = "e\u{0301}clair";
+        let clusters: Vec<&str> = text.sz_graphemes().collect();
+        assert_eq!(clusters[0], "e\u{0301}");
+        assert_eq!(clusters[1..], ["c", "l", "a", "i", "r"]);
+    }
+
+    #[test]
+    fn sz_grapheme_indices_offsets() {
+        let text = "a\u{0301}b";
+        let indices: Vec<(usize, &str)> = text.sz_grapheme_indices().collect();
+        assert_eq!(indices, vec![(0, "a\u{0301}"), (3, "b")]);
+    }
+
+    #[test]
+    fn sz_words_basic() {
+        let words: Vec<&str> = "Hello, world!".sz_words().collect();
+        assert_eq!(words, vec!["Hello", "world"]);
+    }
+
+    #[test]
+    fn sz_word_indices_offsets() {
+        let indices: Vec<(usize, &str)> = "Hello, world!".sz_word_indices().collect();
+        assert_eq!(indices, vec![(0, "Hello"), (7, "world")]);
+    }
+
+    #[test]
+    fn sz_sentences_basic() {
+        let sentences: Vec<&str> = "Hi there! How are you?".sz_sentences().collect();
+        assert_eq!(sentences, vec!["Hi there! ", "How are you?"]);
+    }
+
+    #[test]
+    fn sz_sentence_indices_offsets() {
+        let indices: Vec<(usize, &str)> = "Hi there! How are you?".sz_sentence_indices().collect();
+        assert_eq!(indices, vec![(0, "Hi there! "), (10, "How are you?")]);
+    }
+
+    #[test]
+    fn sz_char_indices_basic() {
+        let indices: Vec<(usize, char, usize)> = "a🌍b".sz_char_indices().collect();
+        assert_eq!(indices, vec![(0, 'a', 1), (1, '🌍', 4), (5, 'b', 1)]);
+    }
+
+    #[test]
+    fn sz_char_indices_agrees_with_std_char_indices() {
+        let text = "Héllo, 世界!";
+        let expected: Vec<(usize, char, usize)> =
+            text.char_indices().map(|(offset, c)| (offset, c, c.len_utf8())).collect();
+        let actual: Vec<(usize, char, usize)> = text.sz_char_indices().collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn sz_char_indices_stops_at_invalid_utf8() {
+        let view = Utf8View::new(b"ab\xFFcd");
+        let indices: Vec<(usize, char, usize)> = view.char_indices().collect();
+        assert_eq!(indices, vec![(0, 'a', 1), (1, 'b', 1)]);
+    }
+
+    #[test]
+    fn sz_nfd_decomposes_precomposed_letters() {
+        let normalized: String = sz_nfd("café").collect();
+        assert_eq!(normalized, "cafe\u{0301}");
+    }
+
+    #[test]
+    fn sz_nfd_leaves_plain_ascii_unchanged() {
+        let normalized: String = sz_nfd("hello").collect();
+        assert_eq!(normalized, "hello");
+    }
+
+    #[test]
+    fn sz_nfkd_expands_ligatures_and_decomposes() {
+        let normalized: String = sz_nfkd("ﬁancé").collect();
+        assert_eq!(normalized, "fiance\u{0301}");
+    }
+
+    #[test]
+    fn sz_nfc_recomposes_base_plus_mark() {
+        let normalized: String = sz_nfc("cafe\u{0301}").collect();
+        assert_eq!(normalized, "café");
+    }
+
+    #[test]
+    fn sz_nfc_is_idempotent_on_already_composed_text() {
+        let normalized: String = sz_nfc("café").collect();
+        assert_eq!(normalized, "café");
+    }
+
+    #[test]
+    fn sz_nfkc_expands_ligature_then_recomposes() {
+        let normalized: String = sz_nfkc("ﬁancé").collect();
+        assert_eq!(normalized, "fiancé");
+    }
+
+    #[test]
+    fn sz_nfc_does_not_compose_across_a_blocking_mark() {
+        // 'a' + combining macron (CCC 230, not itself composable with 'a' in this table) +
+        // combining ring above (CCC 230, which *would* compose with 'a' into "å" on its own):
+        // the macron sits between the starter and the ring with an equal-or-higher class, so it
+        // blocks the ring from reaching back to the starter.
+        let input = "a\u{0304}\u{030A}";
+        let normalized: String = sz_nfc(input).collect();
+        assert_eq!(normalized, input);
+    }
+
+    #[test]
+    fn sz_nfd_reorders_combining_marks_by_combining_class() {
+        // Combining acute (CCC 230) typed before combining dot below (CCC 220) must be reordered
+        // so the lower class sorts first, per the Canonical Ordering Algorithm.
+        let input = "e\u{0301}\u{0323}";
+        let normalized: String = sz_nfd(input).collect();
+        assert_eq!(normalized, "e\u{0323}\u{0301}");
+    }
+
+    #[test]
+    fn utf8_chunks_all_valid() {
+        let view = Utf8View::new("Hello, world!".as_bytes());
+        let chunks: Vec<_> = view.chunks().collect();
+        assert_eq!(chunks, vec![("Hello, world!", &b""[..])]);
+    }
+
+    #[test]
+    fn utf8_chunks_empty_input() {
+        let view = Utf8View::new(b"");
+        assert_eq!(view.chunks().count(), 0);
+    }
+
+    #[test]
+    fn utf8_chunks_single_invalid_byte() {
+        let view = Utf8View::new(b"Hello\xFFWorld");
+        let chunks: Vec<_> = view.chunks().collect();
+        assert_eq!(chunks, vec![("Hello", &b"\xFF"[..]), ("World", &b""[..])]);
+    }
+
+    #[test]
+    fn utf8_chunks_multiple_invalid_runs() {
+        // 0xFF and 0xFE are each invalid lead bytes in their own right, so they surface as two
+        // separate single-byte invalid runs (with an empty valid chunk between them) rather than
+        // being merged into one two-byte run.
+        let view = Utf8View::new(b"a\xFF\xFEb\xC0c");
+        let chunks: Vec<_> = view.chunks().collect();
+        assert_eq!(
+            chunks,
+            vec![("a", &b"\xFF"[..]), ("", &b"\xFE"[..]), ("b", &b"\xC0"[..]), ("c", &b""[..])]
+        );
+    }
+
+    #[test]
+    fn utf8_chunks_trailing_invalid_run() {
+        let view = Utf8View::new(b"Hello\xC0");
+        let chunks: Vec<_> = view.chunks().collect();
+        assert_eq!(chunks, vec![("Hello", &b"\xC0"[..])]);
+    }
+
+    #[test]
+    fn utf8_chunks_leading_invalid_run() {
+        let view = Utf8View::new(b"\xFFHello");
+        let chunks: Vec<_> = view.chunks().collect();
+        assert_eq!(chunks, vec![("", &b"\xFF"[..]), ("Hello", &b""[..])]);
+    }
+
+    #[test]
+    fn to_str_lossy_borrows_valid_input() {
+        let view = Utf8View::new("Hello, world!".as_bytes());
+        match view.to_str_lossy() {
+            Cow::Borrowed(text) => assert_eq!(text, "Hello, world!"),
+            Cow::Owned(_) => panic!("expected a borrowed Cow for valid UTF-8"),
+        }
+    }
+
+    #[test]
+    fn to_str_lossy_substitutes_invalid_runs() {
+        let view = Utf8View::new(b"Hello\xFFWorld\xC0");
+        assert_eq!(view.to_str_lossy(), "Hello\u{FFFD}World\u{FFFD}");
+    }
+
+    #[test]
+    fn sz_utf8_chunks_on_byte_slice() {
+        let chunks: Vec<_> = b"Hello\xFFWorld".sz_utf8_chunks().collect();
+        assert_eq!(chunks, vec![("Hello", &b"\xFF"[..]), ("World", &b""[..])]);
+    }
+
+    #[test]
+    fn sz_to_str_lossy_borrows_valid_input() {
+        match b"Hello, world!".sz_to_str_lossy() {
+            Cow::Borrowed(text) => assert_eq!(text, "Hello, world!"),
+            Cow::Owned(_) => panic!("expected a borrowed Cow for valid UTF-8"),
+        }
+    }
+
+    #[test]
+    fn sz_to_str_lossy_substitutes_invalid_runs() {
+        assert_eq!(b"Hello\xFFWorld\xC0".sz_to_str_lossy(), "Hello\u{FFFD}World\u{FFFD}");
+    }
 }
@@ -1,6 +1,7 @@
 extern crate alloc;
 use alloc::vec::Vec;
 use core::ffi::{c_char, c_void, CStr};
+use core::ops::Range;
 use core::ptr;
 
 use allocator_api2::{alloc::AllocError, alloc::Allocator, alloc::Layout};
@@ -12,6 +13,15 @@ pub use crate::stringzilla::{SortedIdx, Status as SzStatus};
 /// Capability flags
 pub type Capability = u32;
 
+/// Capability bit set when the Metal compute backend (`szs_cap_metal_k` in C) is available,
+/// i.e. an Apple AGX GPU (M1/M2-class) can be targeted via [`DeviceScope::metal_device`].
+pub const CAPABILITY_METAL: Capability = 1 << 20;
+
+/// Capability bit set when the CPU exposes hardware AES instructions (`szs_cap_aes_k` in C),
+/// i.e. AES-NI on x86 or the ARMv8 Cryptography Extensions. Lets [`FingerprintsBuilder::hash_family`]
+/// pick [`HashFamily::Aes`] to mix the MinHash lanes.
+pub const CAPABILITY_AES: Capability = 1 << 21;
+
 // Import from stringzilla module
 pub use crate::stringzilla::Status;
 
@@ -67,6 +77,62 @@ pub enum AnyBytesTape<'a> {
     View64(BytesTapeView<'a, u64>),
 }
 
+// SAFETY: every variant is either an owned buffer or a read-only FFI view; both are safe to share
+// a `&AnyBytesTape` across threads for read-only access, which is all `LevenshteinDistances::broadcast_into`
+// does with the shared query tape.
+unsafe impl<'a> Sync for AnyBytesTape<'a> {}
+
+/// Backend a [`DeviceDescriptor`] belongs to.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceKind {
+    /// A pool of CPU cores running the SIMD/SWAR kernels.
+    CpuCores = 0,
+    /// An NVIDIA GPU reachable through the CUDA backend.
+    Cuda = 1,
+    /// An AMD GPU reachable through the ROCm/HIP backend.
+    Rocm = 2,
+    /// An Apple Silicon GPU (M1/M2-class AGX) reachable through the Metal compute backend.
+    Metal = 3,
+}
+
+/// Describes one backend/device that [`DeviceScope::enumerate`] found on the system.
+///
+/// Mirrors how cross-platform GPU HALs expose `enumerate_adapters`: callers can compare memory,
+/// core count, and capability bits across every available device before committing to one, then
+/// build a [`DeviceScope`] from the chosen descriptor with [`DeviceScope::from_descriptor`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeviceDescriptor {
+    /// Which backend this descriptor belongs to.
+    pub kind: DeviceKind,
+    /// Backend-local device index: the CPU core count for [`DeviceKind::CpuCores`], or the
+    /// CUDA/ROCm device ordinal otherwise.
+    pub device_index: usize,
+    /// Hardware capabilities bitmask, as returned by [`DeviceScope::get_capabilities`].
+    pub capabilities: Capability,
+    /// Number of CPU cores for [`DeviceKind::CpuCores`], or GPU streaming multiprocessors for
+    /// [`DeviceKind::Cuda`]/[`DeviceKind::Rocm`].
+    pub core_count: usize,
+    /// Unified memory available to this device, in bytes.
+    pub unified_memory_bytes: u64,
+}
+
+/// Memory space an allocation should live in, chosen to match how a [`DeviceScope`] will
+/// access it. Build a [`PooledAlloc`] for a mode with [`DeviceScope::allocator`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AllocationMode {
+    /// Ordinary pageable host memory (`malloc`/`free`). Always available.
+    HostPageable = 0,
+    /// Page-locked ("pinned") host memory, registered for fast asynchronous DMA to a GPU.
+    /// Requires a GPU-capable device.
+    Pinned = 1,
+    /// CUDA/ROCm managed (unified) memory, migrated automatically between host and device.
+    /// Requires a GPU-capable device.
+    Managed = 2,
+}
+
 /// Manages execution context and hardware resource allocation.
 ///
 /// Auto-detects available hardware (CPU SIMD, GPU) and selects optimal implementations.
@@ -191,6 +257,118 @@ impl DeviceScope {
         }
     }
 
+    /// Create a device scope for a specific Apple Silicon GPU via the Metal compute backend.
+    ///
+    /// Configures execution to use the specified Apple AGX GPU (M1/M2-class). Requires the
+    /// `metal` backend to be compiled in and the device index to be valid.
+    ///
+    /// Because Apple Silicon GPUs share unified memory with the CPU, [`UnifiedAlloc`]-backed
+    /// tapes (`StringTape`/`BytesTape<_, UnifiedAlloc>`) can be handed to a Metal device scope
+    /// with zero copy: the `*_u32tape`/`*_u64tape` entry points (e.g.
+    /// [`LevenshteinDistances::compute_into`]) read the same unified allocation the CPU wrote,
+    /// with no separate host-to-device transfer.
+    ///
+    /// # Parameters
+    ///
+    /// - `metal_device`: Metal GPU device index (0-based)
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(DeviceScope)`: Successfully configured Metal device
+    /// - `Err(Error)`: Metal unavailable, invalid device, or allocation failure
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use stringzilla::szs::DeviceScope;
+    /// match DeviceScope::metal_device(0) {
+    ///     Ok(device) => {
+    ///         println!("Using Metal device: {}", device.get_gpu_device().unwrap());
+    ///         assert!(device.is_gpu());
+    ///     }
+    ///     Err(e) => println!("Metal not available: {:?}", e),
+    /// }
+    /// ```
+    pub fn metal_device(metal_device: usize) -> Result<Self, Error> {
+        let mut handle = ptr::null_mut();
+        let mut error_msg: *const c_char = ptr::null();
+        let status = unsafe { szs_device_scope_init_metal_device(metal_device, &mut handle, &mut error_msg) };
+        match status {
+            Status::Success => Ok(Self { handle }),
+            err => Err(rust_error_from_c_message(err, error_msg)),
+        }
+    }
+
+    /// Enumerates every backend/device available on this system, CPU and GPU alike.
+    ///
+    /// Mirrors how cross-platform GPU HALs expose `enumerate_adapters`: instead of probing GPU
+    /// indices by trial and error (as the [`DeviceScope::gpu_device`] examples above do), inspect
+    /// the returned descriptors' memory, core count, and capability bits, pick the best fit, and
+    /// build a scope from it with [`DeviceScope::from_descriptor`].
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<DeviceDescriptor>)`: One descriptor per available device; at least the CPU
+    ///   backend is always present.
+    /// - `Err(Error)`: Enumeration failed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use stringzilla::szs::DeviceScope;
+    /// let descriptors = DeviceScope::enumerate().unwrap();
+    /// let most_memory = descriptors
+    ///     .iter()
+    ///     .max_by_key(|descriptor| descriptor.unified_memory_bytes)
+    ///     .expect("at least the CPU backend is always available");
+    /// let device = DeviceScope::from_descriptor(most_memory).unwrap();
+    /// ```
+    pub fn enumerate() -> Result<Vec<DeviceDescriptor>, Error> {
+        let mut error_msg: *const c_char = ptr::null();
+        let mut count: usize = 0;
+        // First call with a null buffer and zero capacity just reports how many devices exist,
+        // matching the two-call enumeration pattern used by e.g. Vulkan's adapter listing.
+        let status = unsafe { szs_device_enumerate(ptr::null_mut(), 0, &mut count, &mut error_msg) };
+        if status != Status::Success {
+            return Err(rust_error_from_c_message(status, error_msg));
+        }
+
+        let mut descriptors = Vec::with_capacity(count);
+        let status = unsafe { szs_device_enumerate(descriptors.as_mut_ptr(), count, &mut count, &mut error_msg) };
+        match status {
+            Status::Success => {
+                unsafe { descriptors.set_len(count) };
+                Ok(descriptors)
+            }
+            err => Err(rust_error_from_c_message(err, error_msg)),
+        }
+    }
+
+    /// Creates a device scope from a descriptor returned by [`DeviceScope::enumerate`].
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(DeviceScope)`: Successfully configured the described device.
+    /// - `Err(Error)`: The device is no longer available, e.g. it was hot-unplugged or its
+    ///   backend was disabled since enumeration.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use stringzilla::szs::DeviceScope;
+    /// let descriptors = DeviceScope::enumerate().unwrap();
+    /// let device = DeviceScope::from_descriptor(&descriptors[0]).unwrap();
+    /// ```
+    pub fn from_descriptor(descriptor: &DeviceDescriptor) -> Result<Self, Error> {
+        let mut handle = ptr::null_mut();
+        let mut error_msg: *const c_char = ptr::null();
+        let status = unsafe { szs_device_scope_init_from_descriptor(descriptor, &mut handle, &mut error_msg) };
+        match status {
+            Status::Success => Ok(Self { handle }),
+            err => Err(rust_error_from_c_message(err, error_msg)),
+        }
+    }
+
     /// Get the hardware capabilities mask for this device scope.
     ///
     /// Returns a bitmask indicating available hardware features like SIMD instructions,
@@ -313,6 +491,19 @@ impl DeviceScope {
     pub fn is_gpu(&self) -> bool {
         self.get_gpu_device().is_ok()
     }
+
+    /// Builds a pooled allocator whose memory space matches `mode` and this device.
+    ///
+    /// `AllocationMode::Pinned` and `AllocationMode::Managed` require a GPU-capable scope
+    /// (built via [`Self::gpu_device`], [`Self::metal_device`], or [`Self::from_descriptor`] on
+    /// a GPU descriptor); requesting either on a CPU-only scope fails with
+    /// `SzStatus::MissingGpu`. `AllocationMode::HostPageable` is always available.
+    pub fn allocator(&self, mode: AllocationMode) -> Result<PooledAlloc, Error> {
+        if mode != AllocationMode::HostPageable && !self.is_gpu() {
+            return Err(Error::from(SzStatus::MissingGpu));
+        }
+        Ok(PooledAlloc { mode, free_lists: std::sync::Mutex::new(std::collections::HashMap::new()) })
+    }
 }
 
 impl Drop for DeviceScope {
@@ -326,6 +517,414 @@ impl Drop for DeviceScope {
 unsafe impl Send for DeviceScope {}
 unsafe impl Sync for DeviceScope {}
 
+/// A raw pointer wrapper that asserts it is safe to hand off to another thread.
+///
+/// Used by [`DeviceSet`] to let each spawned worker write into its own disjoint slice of a
+/// shared results buffer: every worker is handed a distinct, non-overlapping `[start, end)` range
+/// computed up front by [`DeviceSet::partition_ranges`], so the writes never alias and no lock is
+/// needed.
+#[derive(Clone, Copy)]
+struct SendPtr<T>(*mut T);
+unsafe impl<T> Send for SendPtr<T> {}
+impl<T> SendPtr<T> {
+    // A method call (rather than a `.0` field projection) captures the whole `SendPtr` by value
+    // under Rust 2021's disjoint closure capture, so the `unsafe impl Send` above actually applies.
+    fn get(self) -> *mut T {
+        self.0
+    }
+}
+
+/// Hybrid scheduler that splits one batch across several [`DeviceScope`]s and runs them concurrently.
+///
+/// Built for machines with both a strong CPU and a GPU: instead of picking one device and letting
+/// the other idle, a `DeviceSet` partitions the `0..num_pairs` range of a batch call proportionally
+/// across every device it owns and runs the partitions in parallel, gathering the per-pair results
+/// back into one contiguous buffer.
+///
+/// # Partitioning
+///
+/// Each device `d` starts with a throughput weight `w_d`: CPU scopes start at their
+/// [`DeviceScope::get_cpu_cores`] count, GPU scopes start at [`Self::INITIAL_GPU_WEIGHT`]. Device
+/// `d` is assigned `round(num_pairs * w_d / Σw)` contiguous indices (the last device absorbs any
+/// rounding remainder so partitions always cover the whole range exactly). After each batch, every
+/// device that produced output updates its weight from the observed pairs-per-second throughput:
+/// `w_d = alpha*w_d + (1-alpha)*(pairs_d / elapsed_d)`, so repeated calls self-tune the split
+/// toward the faster device.
+///
+/// # Errors
+///
+/// If any device's partition fails, the whole batch fails with the first [`Error`] observed;
+/// weights are left unchanged for a batch that failed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use stringzilla::szs::{DeviceScope, DeviceSet, LevenshteinDistances};
+/// let cpu = DeviceScope::cpu_cores(0).unwrap();
+/// let set = DeviceSet::new(vec![cpu]);
+/// let engine = LevenshteinDistances::new(&set.devices()[0], 0, 1, 1, 1).unwrap();
+///
+/// let words_a = vec!["cat", "dog", "bird"];
+/// let words_b = vec!["bat", "fog", "word"];
+/// let distances = set.compute_levenshtein(&engine, &words_a, &words_b).unwrap();
+/// assert_eq!(&distances[..], &[1, 1, 2]);
+/// ```
+pub struct DeviceSet {
+    devices: Vec<DeviceScope>,
+    weights: std::sync::Mutex<Vec<f64>>,
+}
+
+impl DeviceSet {
+    /// Throughput weight assumed for a GPU scope before its first batch has been observed.
+    const INITIAL_GPU_WEIGHT: f64 = 64.0;
+    /// Exponential-smoothing factor `alpha` used when updating weights after each batch.
+    const WEIGHT_SMOOTHING: f64 = 0.5;
+
+    /// Builds a device set from the given scopes, seeding each device's throughput weight.
+    ///
+    /// CPU scopes start at their configured core count; GPU scopes start at a fixed constant
+    /// competitive with a modest CPU core count, since their true throughput is unknown until
+    /// the first batch completes.
+    pub fn new(devices: Vec<DeviceScope>) -> Self {
+        let weights = devices
+            .iter()
+            .map(|device| {
+                if device.is_gpu() {
+                    Self::INITIAL_GPU_WEIGHT
+                } else {
+                    device.get_cpu_cores().unwrap_or(1).max(1) as f64
+                }
+            })
+            .collect();
+        Self {
+            devices,
+            weights: std::sync::Mutex::new(weights),
+        }
+    }
+
+    /// The device scopes owned by this set, in partitioning order.
+    pub fn devices(&self) -> &[DeviceScope] {
+        &self.devices
+    }
+
+    /// Computes Levenshtein distances for a batch, partitioned across every device in this set.
+    ///
+    /// Pairs sequences by index, same as [`LevenshteinDistances::compute`]; `engine` is shared
+    /// read-only across every device's partition.
+    pub fn compute_levenshtein<T, S>(
+        &self,
+        engine: &LevenshteinDistances,
+        sequences_a: T,
+        sequences_b: T,
+    ) -> Result<UnifiedVec<usize>, Error>
+    where
+        T: AsRef<[S]>,
+        S: AsRef<[u8]> + Sync,
+    {
+        self.run_partitioned(sequences_a.as_ref(), sequences_b.as_ref(), |device, a, b| {
+            engine.compute(device, a, b)
+        })
+    }
+
+    /// Computes Needleman-Wunsch global alignment scores for a batch, partitioned across every
+    /// device in this set. Pairs sequences by index, same as [`NeedlemanWunschScores::compute`].
+    pub fn compute_needleman_wunsch<T, S>(
+        &self,
+        engine: &NeedlemanWunschScores,
+        sequences_a: T,
+        sequences_b: T,
+    ) -> Result<UnifiedVec<isize>, Error>
+    where
+        T: AsRef<[S]>,
+        S: AsRef<[u8]> + Sync,
+    {
+        self.run_partitioned(sequences_a.as_ref(), sequences_b.as_ref(), |device, a, b| {
+            engine.compute(device, a, b)
+        })
+    }
+
+    /// Computes Smith-Waterman local alignment scores for a batch, partitioned across every
+    /// device in this set. Pairs sequences by index, same as [`SmithWatermanScores::compute`].
+    pub fn compute_smith_waterman<T, S>(
+        &self,
+        engine: &SmithWatermanScores,
+        sequences_a: T,
+        sequences_b: T,
+    ) -> Result<UnifiedVec<isize>, Error>
+    where
+        T: AsRef<[S]>,
+        S: AsRef<[u8]> + Sync,
+    {
+        self.run_partitioned(sequences_a.as_ref(), sequences_b.as_ref(), |device, a, b| {
+            engine.compute(device, a, b)
+        })
+    }
+
+    /// Shared scheduling core: partitions `0..num_pairs` proportionally across devices by their
+    /// current weight, runs `compute_fn` concurrently on each non-empty partition, and gathers
+    /// the results back into one contiguous buffer. Aborts with the first observed [`Error`] if
+    /// any partition fails, and otherwise folds the observed per-device throughput back into the
+    /// weights used by the next call.
+    fn run_partitioned<T, S, F>(&self, sequences_a: &[S], sequences_b: &[S], compute_fn: F) -> Result<UnifiedVec<T>, Error>
+    where
+        S: AsRef<[u8]> + Sync,
+        T: Copy + Default,
+        F: Fn(&DeviceScope, &[S], &[S]) -> Result<UnifiedVec<T>, Error> + Sync,
+    {
+        let num_pairs = sequences_a.len().min(sequences_b.len());
+        let mut results = UnifiedVec::with_capacity_in(num_pairs, UnifiedAlloc);
+        results.resize(num_pairs, T::default());
+
+        if self.devices.is_empty() || num_pairs == 0 {
+            return Ok(results);
+        }
+
+        let previous_weights = self.weights.lock().unwrap().clone();
+        let ranges = Self::partition_ranges(num_pairs, &previous_weights);
+        let results_ptr = SendPtr(results.as_mut_ptr());
+
+        let first_error: std::sync::Mutex<Option<Error>> = std::sync::Mutex::new(None);
+        let throughput: std::sync::Mutex<Vec<Option<(usize, std::time::Duration)>>> =
+            std::sync::Mutex::new(vec![None; self.devices.len()]);
+
+        std::thread::scope(|scope| {
+            for (device_index, (device, &(start, end))) in self.devices.iter().zip(ranges.iter()).enumerate() {
+                if start == end {
+                    continue;
+                }
+                let compute_fn = &compute_fn;
+                let first_error = &first_error;
+                let throughput = &throughput;
+                let a_partition = &sequences_a[start..end];
+                let b_partition = &sequences_b[start..end];
+                scope.spawn(move || {
+                    let began = std::time::Instant::now();
+                    match compute_fn(device, a_partition, b_partition) {
+                        // SAFETY: [start, end) is this worker's exclusive partition; no other
+                        // worker ever touches these indices.
+                        Ok(partial) => {
+                            unsafe { ptr::copy_nonoverlapping(partial.as_ptr(), results_ptr.get().add(start), end - start) };
+                            throughput.lock().unwrap()[device_index] = Some((end - start, began.elapsed()));
+                        }
+                        Err(error) => {
+                            let mut first_error = first_error.lock().unwrap();
+                            if first_error.is_none() {
+                                *first_error = Some(error);
+                            }
+                        }
+                    }
+                });
+            }
+        });
+
+        if let Some(error) = first_error.into_inner().unwrap() {
+            return Err(error);
+        }
+
+        self.update_weights(&previous_weights, throughput.into_inner().unwrap());
+        Ok(results)
+    }
+
+    /// Splits `num_pairs` into contiguous, disjoint ranges proportional to `weights`, covering
+    /// `0..num_pairs` exactly. The last device absorbs the rounding remainder.
+    fn partition_ranges(num_pairs: usize, weights: &[f64]) -> Vec<(usize, usize)> {
+        let total_weight: f64 = weights.iter().sum();
+        let mut ranges = Vec::with_capacity(weights.len());
+        let mut start = 0usize;
+        for (device_index, &weight) in weights.iter().enumerate() {
+            let remaining = num_pairs - start;
+            let share = if device_index + 1 == weights.len() {
+                remaining
+            } else if total_weight > 0.0 {
+                (((num_pairs as f64) * weight / total_weight).round() as usize).min(remaining)
+            } else {
+                0
+            };
+            ranges.push((start, start + share));
+            start += share;
+        }
+        ranges
+    }
+
+    /// Folds each device's observed pairs-per-second throughput into its weight via exponential
+    /// smoothing; devices that did not run this batch (empty partition, or excluded by a prior
+    /// error) keep their previous weight.
+    fn update_weights(&self, previous_weights: &[f64], throughput: Vec<Option<(usize, std::time::Duration)>>) {
+        let mut weights = self.weights.lock().unwrap();
+        for ((weight, &previous), observed) in weights.iter_mut().zip(previous_weights).zip(throughput) {
+            if let Some((pairs, elapsed)) = observed {
+                let seconds = elapsed.as_secs_f64().max(1e-9);
+                let observed_rate = pairs as f64 / seconds;
+                *weight = Self::WEIGHT_SMOOTHING * previous + (1.0 - Self::WEIGHT_SMOOTHING) * observed_rate;
+            }
+        }
+    }
+}
+
+/// A handle to a batch submitted via one of the `submit_into` methods on [`LevenshteinDistances`],
+/// [`NeedlemanWunschScores`], or [`SmithWatermanScores`].
+///
+/// The batch runs asynchronously on the device; `results` is written only once the batch
+/// completes, so the borrow in `'a` keeps the buffer alive (and exclusively borrowed) until the
+/// `Completion` is dropped, polled to done, or explicitly waited on. This lets the caller overlap
+/// host-side tape construction or post-processing of a *different* buffer with an in-flight batch,
+/// and submit several batches to the same [`DeviceScope`] before waiting on any of them.
+///
+/// Dropping a `Completion` that has not been waited on blocks until the batch finishes, so
+/// `results` is never read or reused while a write to it is still in flight.
+pub struct Completion<'a> {
+    event: *mut c_void,
+    waited: bool,
+    _borrow: core::marker::PhantomData<&'a mut ()>,
+}
+
+impl<'a> Completion<'a> {
+    fn new(event: *mut c_void) -> Self {
+        Completion { event, waited: false, _borrow: core::marker::PhantomData }
+    }
+
+    /// Checks whether the submitted batch has finished without blocking.
+    ///
+    /// Returns `None` while the batch is still running, or `Some(status)` once it has finished
+    /// (`Status::Success` on success, or the error status the device reported).
+    pub fn poll(&self) -> Option<Status> {
+        let mut is_done: u8 = 0;
+        let mut result_status = Status::Success;
+        let status = unsafe { szs_event_poll(self.event, &mut is_done, &mut result_status) };
+        if status != Status::Success || is_done == 0 {
+            return None;
+        }
+        Some(result_status)
+    }
+
+    /// Blocks until the submitted batch finishes, returning its outcome.
+    ///
+    /// Safe to call more than once (including from [`Drop`]): once the batch has finished,
+    /// later calls return immediately with the same outcome.
+    pub fn wait(&mut self) -> Result<(), Error> {
+        self.waited = true;
+        let mut result_status = Status::Success;
+        let mut error_msg: *const c_char = ptr::null();
+        let status = unsafe { szs_event_wait(self.event, &mut result_status, &mut error_msg) };
+        match status {
+            Status::Success => match result_status {
+                Status::Success => Ok(()),
+                err => Err(Error::from(err)),
+            },
+            err => Err(rust_error_from_c_message(err, error_msg)),
+        }
+    }
+}
+
+impl Drop for Completion<'_> {
+    fn drop(&mut self) {
+        if !self.waited {
+            let _ = self.wait();
+        }
+        unsafe { szs_event_free(self.event) };
+    }
+}
+
+unsafe impl Send for Completion<'_> {}
+
+/// Outcome and wake-up state shared between a [`JobHandle`] and the background thread that waits
+/// on its [`Completion`].
+struct JobState {
+    outcome: std::sync::Mutex<Option<Result<(), Error>>>,
+    waker: std::sync::Mutex<Option<core::task::Waker>>,
+}
+
+impl JobState {
+    fn new() -> std::sync::Arc<Self> {
+        std::sync::Arc::new(JobState { outcome: std::sync::Mutex::new(None), waker: std::sync::Mutex::new(None) })
+    }
+
+    /// Records the batch's outcome and wakes whichever [`core::task::Waker`] [`JobHandle::poll`]
+    /// last registered, if any.
+    fn finish(&self, outcome: Result<(), Error>) {
+        *self.outcome.lock().unwrap() = Some(outcome);
+        if let Some(waker) = self.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+}
+
+/// A batch submitted via one of the `submit` methods on [`LevenshteinDistances`],
+/// [`NeedlemanWunschScores`], [`SmithWatermanScores`], or [`Fingerprints`], running asynchronously
+/// on the device.
+///
+/// Unlike [`Completion`], which borrows a caller-owned results buffer, `JobHandle` owns its
+/// results outright (`T` is typically a `Box<UnifiedVec<_>>` or a tuple of them), so it can be
+/// moved into another thread or a `tokio::spawn`ed task instead of being tied to a borrow. A
+/// background worker thread blocks on the underlying [`Completion`] and, once the device
+/// finishes, records the outcome and wakes any [`core::task::Waker`] registered by polling the
+/// `Future` impl -- so `.await`ing a `JobHandle` parks instead of spinning. Resolve it either by
+/// blocking with [`Self::join`] or by `.await`ing it under an async runtime.
+pub struct JobHandle<T> {
+    results: Option<T>,
+    state: std::sync::Arc<JobState>,
+    worker: Option<std::thread::JoinHandle<()>>,
+}
+
+impl<T> JobHandle<T>
+where
+    T: Send + 'static,
+{
+    /// Spawns the background worker that blocks on `completion` and hands `results` back once
+    /// the batch finishes.
+    fn spawn(results: T, mut completion: Completion<'static>) -> Self {
+        let state = JobState::new();
+        let worker = {
+            let state = std::sync::Arc::clone(&state);
+            std::thread::spawn(move || state.finish(completion.wait()))
+        };
+        JobHandle { results: Some(results), state, worker: Some(worker) }
+    }
+
+    /// Takes the recorded outcome, if the background worker has finished, joining the worker
+    /// thread itself if it hasn't been reaped yet. Returns `None` while the batch is still
+    /// running.
+    fn take_ready(&mut self) -> Option<Result<T, Error>> {
+        let outcome = self.state.outcome.lock().unwrap().take()?;
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+        Some(match outcome {
+            Ok(()) => Ok(self.results.take().expect("JobHandle resolves only once")),
+            Err(err) => Err(err),
+        })
+    }
+
+    /// Blocks until the batch finishes, returning the owned results.
+    pub fn join(mut self) -> Result<T, Error> {
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join(); // blocks until `JobState::finish` has run
+        }
+        self.take_ready().expect("background worker always records an outcome before returning")
+    }
+}
+
+impl<T> core::future::Future for JobHandle<T>
+where
+    T: Send + 'static,
+{
+    type Output = Result<T, Error>;
+
+    fn poll(self: core::pin::Pin<&mut Self>, cx: &mut core::task::Context<'_>) -> core::task::Poll<Self::Output> {
+        let this = self.get_mut();
+        if let Some(ready) = this.take_ready() {
+            return core::task::Poll::Ready(ready);
+        }
+        *this.state.waker.lock().unwrap() = Some(cx.waker().clone());
+        // Re-check after registering the waker: the worker may have finished (and found no
+        // waker to wake) in the window between the check above and the line above this one.
+        match this.take_ready() {
+            Some(ready) => core::task::Poll::Ready(ready),
+            None => core::task::Poll::Pending,
+        }
+    }
+}
+
 /// Internal representation of `sz_sequence_t` for passing to C
 #[repr(C)]
 struct SzSequence {
@@ -588,6 +1187,11 @@ extern "C" {
         scope: *mut *mut c_void,
         error_message: *mut *const c_char,
     ) -> Status;
+    fn szs_device_scope_init_metal_device(
+        metal_device: usize,
+        scope: *mut *mut c_void,
+        error_message: *mut *const c_char,
+    ) -> Status;
     fn szs_device_scope_get_capabilities(
         scope: *mut c_void,
         capabilities: *mut Capability,
@@ -603,6 +1207,17 @@ extern "C" {
         gpu_device: *mut usize,
         error_message: *mut *const c_char,
     ) -> Status;
+    fn szs_device_enumerate(
+        descriptors: *mut DeviceDescriptor,
+        capacity: usize,
+        count: *mut usize,
+        error_message: *mut *const c_char,
+    ) -> Status;
+    fn szs_device_scope_init_from_descriptor(
+        descriptor: *const DeviceDescriptor,
+        scope: *mut *mut c_void,
+        error_message: *mut *const c_char,
+    ) -> Status;
     fn szs_device_scope_free(scope: *mut c_void);
 
     // Levenshtein distance functions
@@ -779,33 +1394,107 @@ extern "C" {
 
     fn szs_smith_waterman_scores_free(engine: SmithWatermanScoresHandle);
 
-    // Fingerprinting functions
-    fn szs_fingerprints_init(
-        dimensions: usize,
-        alphabet_size: usize,
-        window_widths: *const usize,
-        window_widths_count: usize,
-        alloc: *const c_void, // MemoryAllocator - using null for default
-        capabilities: Capability,
-        engine: *mut FingerprintsHandle,
+    // Non-blocking submission: each `_submit` call enqueues work on the device and returns
+    // immediately with an opaque event handle; progress is observed via `szs_event_*`.
+    fn szs_levenshtein_distances_submit_u32tape(
+        engine: LevenshteinDistancesHandle,
+        device: *mut c_void,
+        a: *const c_void, // sz_sequence_u32tape_t
+        b: *const c_void, // sz_sequence_u32tape_t
+        results: *mut usize,
+        results_stride: usize,
+        event: *mut *mut c_void,
         error_message: *mut *const c_char,
     ) -> Status;
 
-    fn szs_fingerprints_sequence(
-        engine: FingerprintsHandle,
-        device: *mut c_void,  // DeviceScope
-        texts: *const c_void, // sz_sequence_t
-        min_hashes: *mut u32,
-        min_hashes_stride: usize,
-        min_counts: *mut u32,
-        min_counts_stride: usize,
+    fn szs_levenshtein_distances_submit_u64tape(
+        engine: LevenshteinDistancesHandle,
+        device: *mut c_void,
+        a: *const c_void, // sz_sequence_u64tape_t
+        b: *const c_void, // sz_sequence_u64tape_t
+        results: *mut usize,
+        results_stride: usize,
+        event: *mut *mut c_void,
         error_message: *mut *const c_char,
     ) -> Status;
 
-    fn szs_fingerprints_u32tape(
-        engine: FingerprintsHandle,
-        device: *mut c_void,  // DeviceScope
-        texts: *const c_void, // sz_sequence_u32tape_t
+    fn szs_needleman_wunsch_scores_submit_u32tape(
+        engine: NeedlemanWunschScoresHandle,
+        device: *mut c_void,
+        a: *const c_void, // sz_sequence_u32tape_t
+        b: *const c_void, // sz_sequence_u32tape_t
+        results: *mut isize,
+        results_stride: usize,
+        event: *mut *mut c_void,
+        error_message: *mut *const c_char,
+    ) -> Status;
+
+    fn szs_needleman_wunsch_scores_submit_u64tape(
+        engine: NeedlemanWunschScoresHandle,
+        device: *mut c_void,
+        a: *const c_void, // sz_sequence_u64tape_t
+        b: *const c_void, // sz_sequence_u64tape_t
+        results: *mut isize,
+        results_stride: usize,
+        event: *mut *mut c_void,
+        error_message: *mut *const c_char,
+    ) -> Status;
+
+    fn szs_smith_waterman_scores_submit_u32tape(
+        engine: SmithWatermanScoresHandle,
+        device: *mut c_void,
+        a: *const c_void, // sz_sequence_u32tape_t
+        b: *const c_void, // sz_sequence_u32tape_t
+        results: *mut isize,
+        results_stride: usize,
+        event: *mut *mut c_void,
+        error_message: *mut *const c_char,
+    ) -> Status;
+
+    fn szs_smith_waterman_scores_submit_u64tape(
+        engine: SmithWatermanScoresHandle,
+        device: *mut c_void,
+        a: *const c_void, // sz_sequence_u64tape_t
+        b: *const c_void, // sz_sequence_u64tape_t
+        results: *mut isize,
+        results_stride: usize,
+        event: *mut *mut c_void,
+        error_message: *mut *const c_char,
+    ) -> Status;
+
+    // Event functions: observe or block on the completion of a submitted batch.
+    fn szs_event_poll(event: *mut c_void, is_done: *mut u8, result_status: *mut Status) -> Status;
+    fn szs_event_wait(event: *mut c_void, result_status: *mut Status, error_message: *mut *const c_char) -> Status;
+    fn szs_event_free(event: *mut c_void);
+
+    // Fingerprinting functions
+    fn szs_fingerprints_init(
+        dimensions: usize,
+        alphabet_size: usize,
+        window_widths: *const usize,
+        window_widths_count: usize,
+        alloc: *const c_void, // MemoryAllocator - using null for default
+        capabilities: Capability,
+        hash_family: u32, // HashFamily, resolved (never `Auto`)
+        engine: *mut FingerprintsHandle,
+        error_message: *mut *const c_char,
+    ) -> Status;
+
+    fn szs_fingerprints_sequence(
+        engine: FingerprintsHandle,
+        device: *mut c_void,  // DeviceScope
+        texts: *const c_void, // sz_sequence_t
+        min_hashes: *mut u32,
+        min_hashes_stride: usize,
+        min_counts: *mut u32,
+        min_counts_stride: usize,
+        error_message: *mut *const c_char,
+    ) -> Status;
+
+    fn szs_fingerprints_u32tape(
+        engine: FingerprintsHandle,
+        device: *mut c_void,  // DeviceScope
+        texts: *const c_void, // sz_sequence_u32tape_t
         min_hashes: *mut u32,
         min_hashes_stride: usize,
         min_counts: *mut u32,
@@ -824,12 +1513,40 @@ extern "C" {
         error_message: *mut *const c_char,
     ) -> Status;
 
+    fn szs_fingerprints_submit_u32tape(
+        engine: FingerprintsHandle,
+        device: *mut c_void,  // DeviceScope
+        texts: *const c_void, // sz_sequence_u32tape_t
+        min_hashes: *mut u32,
+        min_hashes_stride: usize,
+        min_counts: *mut u32,
+        min_counts_stride: usize,
+        event: *mut *mut c_void,
+        error_message: *mut *const c_char,
+    ) -> Status;
+
+    fn szs_fingerprints_submit_u64tape(
+        engine: FingerprintsHandle,
+        device: *mut c_void,  // DeviceScope
+        texts: *const c_void, // sz_sequence_u64tape_t
+        min_hashes: *mut u32,
+        min_hashes_stride: usize,
+        min_counts: *mut u32,
+        min_counts_stride: usize,
+        event: *mut *mut c_void,
+        error_message: *mut *const c_char,
+    ) -> Status;
+
     fn szs_fingerprints_free(engine: FingerprintsHandle);
 
     // Unified allocator functions
     fn szs_unified_alloc(size_bytes: usize) -> *mut c_void;
     fn szs_unified_free(ptr: *mut c_void, size_bytes: usize);
 
+    // Mode-selectable allocator functions, backing `PooledAlloc`
+    fn szs_alloc_in_mode(size_bytes: usize, mode: AllocationMode) -> *mut c_void;
+    fn szs_free_in_mode(ptr: *mut c_void, size_bytes: usize, mode: AllocationMode);
+
 }
 
 /// Unified memory allocator that uses CUDA unified memory when available,
@@ -864,6 +1581,74 @@ unsafe impl Allocator for UnifiedAlloc {
 /// Type alias for Vec with unified allocator
 pub type UnifiedVec<T> = allocator_api2::vec::Vec<T, UnifiedAlloc>;
 
+/// A size-classed free-list pool over [`AllocationMode`] memory, built with
+/// [`DeviceScope::allocator`].
+///
+/// Rounds every request up to the next power-of-two size class (minimum 64 bytes) and keeps
+/// freed blocks on a per-class free list instead of returning them to the OS/driver, so repeated
+/// `StringTape`/`BytesTape` builds in a hot loop reuse blocks rather than round-tripping through
+/// `cudaMalloc`/`cudaHostRegister` each time. Pass it anywhere `UnifiedAlloc` is accepted today,
+/// e.g. `BytesTape::new_in(pool)`.
+pub struct PooledAlloc {
+    mode: AllocationMode,
+    free_lists: std::sync::Mutex<std::collections::HashMap<usize, Vec<*mut u8>>>,
+}
+
+impl PooledAlloc {
+    fn size_class(size: usize) -> usize {
+        size.next_power_of_two().max(64)
+    }
+}
+
+unsafe impl Allocator for PooledAlloc {
+    fn allocate(&self, layout: Layout) -> Result<core::ptr::NonNull<[u8]>, AllocError> {
+        let size = layout.size();
+        if size == 0 {
+            let ptr = core::ptr::NonNull::new(layout.align() as *mut u8).ok_or(AllocError)?;
+            return Ok(core::ptr::NonNull::slice_from_raw_parts(ptr, 0));
+        }
+
+        let class = Self::size_class(size);
+        if let Some(raw) = {
+            let mut free_lists = self.free_lists.lock().unwrap();
+            free_lists.get_mut(&class).and_then(Vec::pop)
+        } {
+            let ptr = core::ptr::NonNull::new(raw).ok_or(AllocError)?;
+            return Ok(core::ptr::NonNull::slice_from_raw_parts(ptr, size));
+        }
+
+        let raw = unsafe { szs_alloc_in_mode(class, self.mode) };
+        if raw.is_null() {
+            return Err(AllocError);
+        }
+        let ptr = core::ptr::NonNull::new(raw as *mut u8).ok_or(AllocError)?;
+        Ok(core::ptr::NonNull::slice_from_raw_parts(ptr, size))
+    }
+
+    unsafe fn deallocate(&self, ptr: core::ptr::NonNull<u8>, layout: Layout) {
+        if layout.size() == 0 {
+            return;
+        }
+        let class = Self::size_class(layout.size());
+        let mut free_lists = self.free_lists.lock().unwrap();
+        free_lists.entry(class).or_default().push(ptr.as_ptr());
+    }
+}
+
+impl Drop for PooledAlloc {
+    fn drop(&mut self) {
+        let mut free_lists = self.free_lists.lock().unwrap();
+        for (class, blocks) in free_lists.drain() {
+            for block in blocks {
+                unsafe { szs_free_in_mode(block as *mut c_void, class, self.mode) };
+            }
+        }
+    }
+}
+
+unsafe impl Send for PooledAlloc {}
+unsafe impl Sync for PooledAlloc {}
+
 /// Returns StringZillas similarity engine version information.
 pub fn version() -> crate::stringzilla::SemVer {
     crate::stringzilla::SemVer {
@@ -1051,18 +1836,32 @@ impl LevenshteinDistances {
         a: AnyBytesTape<'a>,
         b: AnyBytesTape<'a>,
         results: &mut UnifiedVec<usize>,
+    ) -> Result<(), Error> {
+        self.compute_into_refs(device, &a, &b, results)
+    }
+
+    /// Shared dispatch core behind [`Self::compute_into`] and [`Self::broadcast_into`]: validates
+    /// matching offset widths and a large-enough `results` slice, then runs the FFI call. Takes
+    /// borrowed tapes so [`Self::broadcast_into`] can reuse the same query across several
+    /// references without moving it into each call.
+    fn compute_into_refs(
+        &self,
+        device: &DeviceScope,
+        a: &AnyBytesTape<'_>,
+        b: &AnyBytesTape<'_>,
+        results: &mut [usize],
     ) -> Result<(), Error> {
         // Convert to FFI views and validate matching offset widths
         let mut error_msg: *const c_char = ptr::null();
         let results_stride = core::mem::size_of::<usize>();
 
         // Convert both inputs to 64-bit views if possible, else to 32-bit views.
-        let a64 = match &a {
+        let a64 = match a {
             AnyBytesTape::Tape64(t) => Some(SzSequenceU64Tape::from(t)),
             AnyBytesTape::View64(v) => Some(SzSequenceU64Tape::from(v)),
             _ => None,
         };
-        let b64 = match &b {
+        let b64 = match b {
             AnyBytesTape::Tape64(t) => Some(SzSequenceU64Tape::from(t)),
             AnyBytesTape::View64(v) => Some(SzSequenceU64Tape::from(v)),
             _ => None,
@@ -1089,12 +1888,12 @@ impl LevenshteinDistances {
             };
         }
 
-        let a32 = match &a {
+        let a32 = match a {
             AnyBytesTape::Tape32(t) => Some(SzSequenceU32Tape::from(t)),
             AnyBytesTape::View32(v) => Some(SzSequenceU32Tape::from(v)),
             _ => None,
         };
-        let b32 = match &b {
+        let b32 = match b {
             AnyBytesTape::Tape32(t) => Some(SzSequenceU32Tape::from(t)),
             AnyBytesTape::View32(v) => Some(SzSequenceU32Tape::from(v)),
             _ => None,
@@ -1124,6 +1923,220 @@ impl LevenshteinDistances {
         // Mixed widths are unsupported to avoid implicit widening and extra copies
         Err(Error::from(SzStatus::UnexpectedDimensions))
     }
+
+    /// Scores one query tape against every reference tape in `references`, partitioning the work
+    /// across `devices`. Each device gets exactly one worker thread, which pulls references off a
+    /// shared work queue one at a time until the queue is drained — never more than one thread
+    /// drives a given [`DeviceScope`] at once, the same one-thread-per-device discipline
+    /// [`DeviceSet::run_partitioned`] uses, since `DeviceScope` is just an FFI handle with no
+    /// internal locking of its own.
+    ///
+    /// Returns one results column per reference, in the same order as `references`. If any
+    /// dispatch fails, the first observed [`Error`] is returned once every already-dispatched
+    /// column has finished — columns that completed successfully before the failure are not
+    /// discarded, just not surfaced, since a partial fan-out isn't a usable result.
+    ///
+    /// Requirements and errors are otherwise the same as [`Self::compute_into`], applied
+    /// independently to each `(query, references[i])` pair.
+    pub fn broadcast_into<'a>(
+        &self,
+        devices: &[DeviceScope],
+        query: &AnyBytesTape<'a>,
+        references: &[AnyBytesTape<'a>],
+    ) -> Result<Vec<UnifiedVec<usize>>, Error> {
+        let query_len = any_bytes_tape_len(query);
+        let mut results: Vec<UnifiedVec<usize>> = references
+            .iter()
+            .map(|reference| {
+                let need = core::cmp::min(query_len, any_bytes_tape_len(reference));
+                let mut column = UnifiedVec::with_capacity_in(need, UnifiedAlloc);
+                column.resize(need, 0);
+                column
+            })
+            .collect();
+
+        // No devices to dispatch onto: mirrors `DeviceSet::run_partitioned`, which likewise treats
+        // an empty device list as "nothing to do" rather than an error.
+        if devices.is_empty() || references.is_empty() {
+            return Ok(results);
+        }
+
+        let first_error: std::sync::Mutex<Option<Error>> = std::sync::Mutex::new(None);
+        let next_index = std::sync::atomic::AtomicUsize::new(0);
+        let results_ptr = SendPtr(results.as_mut_ptr());
+
+        std::thread::scope(|scope| {
+            for device in devices {
+                let first_error = &first_error;
+                let next_index = &next_index;
+                scope.spawn(move || loop {
+                    let index = next_index.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                    if index >= references.len() {
+                        break;
+                    }
+                    // SAFETY: `index` comes from a strictly-increasing shared counter, so no two
+                    // worker threads ever touch the same column at the same time.
+                    let column = unsafe { &mut *results_ptr.get().add(index) };
+                    if let Err(error) = self.compute_into_refs(device, query, &references[index], column) {
+                        let mut guard = first_error.lock().unwrap();
+                        if guard.is_none() {
+                            *guard = Some(error);
+                        }
+                    }
+                });
+            }
+        });
+
+        match first_error.into_inner().unwrap() {
+            Some(error) => Err(error),
+            None => Ok(results),
+        }
+    }
+
+    /// Submits Levenshtein distances for computation without blocking.
+    ///
+    /// Mirrors [`Self::compute_into`]'s tape dispatch (64-bit offsets preferred, falling back to
+    /// 32-bit; mixed widths rejected), but enqueues the batch on `device` and returns immediately
+    /// with a [`Completion`] instead of waiting for it to finish. `results` stays borrowed for the
+    /// `Completion`'s lifetime and must not be read until [`Completion::wait`] (or a successful
+    /// [`Completion::poll`]) confirms the batch is done.
+    ///
+    /// Requirements and errors are the same as [`Self::compute_into`].
+    pub fn submit_into<'a>(
+        &self,
+        device: &DeviceScope,
+        a: AnyBytesTape<'a>,
+        b: AnyBytesTape<'a>,
+        results: &'a mut UnifiedVec<usize>,
+    ) -> Result<Completion<'a>, Error> {
+        let mut error_msg: *const c_char = ptr::null();
+        let results_stride = core::mem::size_of::<usize>();
+
+        let a64 = match &a {
+            AnyBytesTape::Tape64(t) => Some(SzSequenceU64Tape::from(t)),
+            AnyBytesTape::View64(v) => Some(SzSequenceU64Tape::from(v)),
+            _ => None,
+        };
+        let b64 = match &b {
+            AnyBytesTape::Tape64(t) => Some(SzSequenceU64Tape::from(t)),
+            AnyBytesTape::View64(v) => Some(SzSequenceU64Tape::from(v)),
+            _ => None,
+        };
+        if let (Some(va), Some(vb)) = (a64, b64) {
+            let need = core::cmp::min(va.count, vb.count);
+            if results.len() < need {
+                return Err(Error::from(SzStatus::UnexpectedDimensions));
+            }
+            let mut event: *mut c_void = ptr::null_mut();
+            let status = unsafe {
+                szs_levenshtein_distances_submit_u64tape(
+                    self.handle,
+                    device.handle,
+                    &va as *const _ as *const c_void,
+                    &vb as *const _ as *const c_void,
+                    results.as_mut_ptr(),
+                    results_stride,
+                    &mut event,
+                    &mut error_msg,
+                )
+            };
+            return match status {
+                Status::Success => Ok(Completion::new(event)),
+                err => Err(rust_error_from_c_message(err, error_msg)),
+            };
+        }
+
+        let a32 = match &a {
+            AnyBytesTape::Tape32(t) => Some(SzSequenceU32Tape::from(t)),
+            AnyBytesTape::View32(v) => Some(SzSequenceU32Tape::from(v)),
+            _ => None,
+        };
+        let b32 = match &b {
+            AnyBytesTape::Tape32(t) => Some(SzSequenceU32Tape::from(t)),
+            AnyBytesTape::View32(v) => Some(SzSequenceU32Tape::from(v)),
+            _ => None,
+        };
+        if let (Some(va), Some(vb)) = (a32, b32) {
+            let need = core::cmp::min(va.count, vb.count);
+            if results.len() < need {
+                return Err(Error::from(SzStatus::UnexpectedDimensions));
+            }
+            let mut event: *mut c_void = ptr::null_mut();
+            let status = unsafe {
+                szs_levenshtein_distances_submit_u32tape(
+                    self.handle,
+                    device.handle,
+                    &va as *const _ as *const c_void,
+                    &vb as *const _ as *const c_void,
+                    results.as_mut_ptr(),
+                    results_stride,
+                    &mut event,
+                    &mut error_msg,
+                )
+            };
+            return match status {
+                Status::Success => Ok(Completion::new(event)),
+                err => Err(rust_error_from_c_message(err, error_msg)),
+            };
+        }
+
+        Err(Error::from(SzStatus::UnexpectedDimensions))
+    }
+
+    /// Submits Levenshtein distances for computation without blocking, returning an owned
+    /// [`JobHandle`] instead of the borrowed [`Completion`] that [`Self::submit_into`] returns.
+    ///
+    /// Unlike `submit_into`, `submit` builds and owns its own tapes and results buffer --
+    /// mirroring [`Self::compute`]'s allocation -- so the returned [`JobHandle`] can be moved
+    /// freely, e.g. `.await`ed under an async runtime or handed to another thread, instead of
+    /// being tied to a borrow of caller-owned state.
+    ///
+    /// # Errors
+    ///
+    /// Fails immediately (without spawning a background worker) if building the tapes or
+    /// enqueuing the batch on `device` fails; errors from the batch itself surface from
+    /// [`JobHandle::join`] or the `Future` impl instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use stringzilla::szs::{DeviceScope, LevenshteinDistances};
+    /// let device = DeviceScope::default().unwrap();
+    /// let engine = LevenshteinDistances::new(&device, 0, 1, 1, 1).unwrap();
+    /// let job = engine.submit(&device, &["cat", "dog"], &["bat", "fog"]).unwrap();
+    /// let distances = job.join().unwrap();
+    /// assert_eq!(&*distances, &[1, 1]);
+    /// ```
+    pub fn submit<T, S>(
+        &self,
+        device: &DeviceScope,
+        sequences_a: T,
+        sequences_b: T,
+    ) -> Result<JobHandle<Box<UnifiedVec<usize>>>, Error>
+    where
+        T: AsRef<[S]>,
+        S: AsRef<[u8]>,
+    {
+        let seq_a_slice = sequences_a.as_ref();
+        let seq_b_slice = sequences_b.as_ref();
+        let num_pairs = seq_a_slice.len().min(seq_b_slice.len());
+
+        let mut results = Box::new(UnifiedVec::with_capacity_in(num_pairs, UnifiedAlloc));
+        results.resize(num_pairs, 0);
+
+        let force_64bit = should_use_64bit_for_bytes(seq_a_slice, seq_b_slice);
+        let tape_a = copy_bytes_into_tape(seq_a_slice, force_64bit)?;
+        let tape_b = copy_bytes_into_tape(seq_b_slice, force_64bit)?;
+
+        // SAFETY: `results` is moved into the returned `JobHandle`, which keeps it alive until
+        // `join`/`poll` observes the background worker's outcome, so this borrow never outlives
+        // the allocation it points to, even though we assert a `'static` lifetime for it here.
+        let results_ptr: *mut UnifiedVec<usize> = Box::as_mut(&mut results);
+        let results_ref: &'static mut UnifiedVec<usize> = unsafe { &mut *results_ptr };
+        let completion = self.submit_into(device, tape_a, tape_b, results_ref)?;
+
+        Ok(JobHandle::spawn(results, completion))
+    }
 }
 
 impl Drop for LevenshteinDistances {
@@ -1156,6 +2169,9 @@ unsafe impl Sync for LevenshteinDistances {}
 /// ```
 pub struct LevenshteinDistancesUtf8 {
     handle: LevenshteinDistancesUtf8Handle,
+    match_cost: i8,
+    mismatch_cost: i8,
+    extend_cost: i8,
 }
 
 impl LevenshteinDistancesUtf8 {
@@ -1215,7 +2231,7 @@ impl LevenshteinDistancesUtf8 {
             )
         };
         match status {
-            Status::Success => Ok(Self { handle }),
+            Status::Success => Ok(Self { handle, match_cost, mismatch_cost, extend_cost }),
             err => Err(rust_error_from_c_message(err, error_msg)),
         }
     }
@@ -1389,21 +2405,754 @@ impl LevenshteinDistancesUtf8 {
 
         Err(Error::from(SzStatus::UnexpectedDimensions))
     }
-}
-
-impl Drop for LevenshteinDistancesUtf8 {
-    fn drop(&mut self) {
-        if !self.handle.is_null() {
-            unsafe { szs_levenshtein_distances_utf8_free(self.handle) };
-        }
-    }
-}
-
-unsafe impl Send for LevenshteinDistancesUtf8 {}
-unsafe impl Sync for LevenshteinDistancesUtf8 {}
 
-/// Needleman-Wunsch global sequence alignment scoring engine.
-///
+    /// Computes UTF-8 Levenshtein distances capped at `max_distance`, using Ukkonen's banding.
+    ///
+    /// Only the diagonals within `max_distance` of the main diagonal are evaluated (cells
+    /// outside the band are treated as unreachable), so the work per pair is `O(len * k)` instead
+    /// of `O(len_a * len_b)`. If the true distance exceeds `max_distance`, the returned value is
+    /// the sentinel `max_distance + 1` rather than the exact distance, and the row scan exits as
+    /// soon as every cell in the current band already exceeds `max_distance`. This is a host-side
+    /// computation (no GPU dispatch): it needs the narrow band, not a dense matrix.
+    ///
+    /// This uses `match_cost`/`mismatch_cost` and a single linear per-character `extend_cost` as
+    /// the gap step cost; `open_cost` (relevant to the affine [`Self::compute`]) is not applied,
+    /// since Ukkonen's banding assumes a linear (per-character) gap cost.
+    ///
+    /// # Returns
+    ///
+    /// One `u32` per pair, in `(a[0], b[0]), (a[1], b[1]), ...` order, truncated to
+    /// `min(sequences_a.len(), sequences_b.len())`.
+    pub fn compute_bounded<T, S>(&self, sequences_a: T, sequences_b: T, max_distance: u32) -> Vec<u32>
+    where
+        T: AsRef<[S]>,
+        S: AsRef<str>,
+    {
+        let seq_a_slice = sequences_a.as_ref();
+        let seq_b_slice = sequences_b.as_ref();
+        let num_pairs = seq_a_slice.len().min(seq_b_slice.len());
+
+        (0..num_pairs)
+            .map(|idx| {
+                let a: Vec<char> = seq_a_slice[idx].as_ref().chars().collect();
+                let b: Vec<char> = seq_b_slice[idx].as_ref().chars().collect();
+                self.banded_distance(&a, &b, max_distance)
+            })
+            .collect()
+    }
+
+    /// Ukkonen-banded edit distance between two already-decoded character slices, capped at `k`.
+    fn banded_distance(&self, a: &[char], b: &[char], k: u32) -> u32 {
+        let n = a.len();
+        let m = b.len();
+        let k_signed = k as i64;
+        if (n as i64 - m as i64).abs() > k_signed {
+            return k + 1;
+        }
+
+        let match_cost = self.match_cost as i64;
+        let mismatch_cost = self.mismatch_cost as i64;
+        let gap_cost = self.extend_cost as i64;
+        let sentinel = k_signed + 1;
+
+        let band_lo = |i: usize| -> usize { (i as i64 - k_signed).max(0) as usize };
+        let band_hi = |i: usize| -> usize { ((i as i64 + k_signed) as usize).min(m) };
+
+        let mut prev = vec![sentinel; m + 1];
+        let mut cur = vec![sentinel; m + 1];
+
+        for j in band_lo(0)..=band_hi(0) {
+            prev[j] = (j as i64) * gap_cost;
+        }
+
+        for i in 1..=n {
+            let lo = band_lo(i);
+            let hi = band_hi(i);
+            // Only the band plus one cell of margin on either side needs clearing: the cell at
+            // `lo - 1` is read as `cur[j - 1]` when the row's loop starts at `j == lo`, and the
+            // cell at `hi + 1` is read as `prev[j]` once the next row's band grows past `hi`.
+            // Resetting the full row here (rather than this `O(k)` slice) is what made this
+            // routine `O(len * len)` despite the banding.
+            let reset_lo = lo.saturating_sub(1);
+            let reset_hi = (hi + 1).min(m);
+            for slot in cur[reset_lo..=reset_hi].iter_mut() {
+                *slot = sentinel;
+            }
+            if lo == 0 {
+                cur[0] = (i as i64) * gap_cost;
+            }
+            for j in lo.max(1)..=hi {
+                let sub_cost = if a[i - 1] == b[j - 1] { match_cost } else { mismatch_cost };
+                let diag = prev[j - 1] + sub_cost;
+                let up = prev[j] + gap_cost;
+                let left = cur[j - 1] + gap_cost;
+                cur[j] = diag.min(up).min(left).min(sentinel);
+            }
+            if cur[lo..=hi].iter().all(|&v| v >= sentinel) {
+                return k + 1;
+            }
+            core::mem::swap(&mut prev, &mut cur);
+        }
+
+        let distance = prev[m];
+        if distance > k_signed {
+            k + 1
+        } else {
+            distance as u32
+        }
+    }
+}
+
+impl Drop for LevenshteinDistancesUtf8 {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            unsafe { szs_levenshtein_distances_utf8_free(self.handle) };
+        }
+    }
+}
+
+unsafe impl Send for LevenshteinDistancesUtf8 {}
+unsafe impl Sync for LevenshteinDistancesUtf8 {}
+
+/// One run of an alignment path, as produced by [`NeedlemanWunschScores::compute_alignments`] or
+/// [`SmithWatermanScores::compute_alignments`].
+///
+/// The `usize` is the run length, so a path is a run-length-encoded CIGAR string: e.g.
+/// `[Match(4), Delete(1), Match(3)]` is `4M1D3M` in SAM/BAM notation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlignOp {
+    /// A run of aligned, identical characters.
+    Match(usize),
+    /// A run of aligned, differing characters.
+    Mismatch(usize),
+    /// A run of characters present in `a` but not `b` (a gap in `b`).
+    Delete(usize),
+    /// A run of characters present in `b` but not `a` (a gap in `a`).
+    Insert(usize),
+}
+
+/// The optimal alignment for one sequence pair, as returned by `compute_alignments`.
+///
+/// `[start_a, end_a)` and `[start_b, end_b)` are the aligned spans within each input sequence:
+/// the whole sequence for a global (Needleman-Wunsch) alignment, or the best-scoring substrings
+/// for a local (Smith-Waterman) alignment. `ops` is the edit path between those spans, in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alignment {
+    pub score: isize,
+    pub start_a: usize,
+    pub start_b: usize,
+    pub end_a: usize,
+    pub end_b: usize,
+    pub ops: Vec<AlignOp>,
+}
+
+impl Alignment {
+    /// Walks [`Self::ops`] as `(a_range, b_range, op)` triples, so callers don't have to parse the
+    /// CIGAR-style run list and track `a`/`b` offsets by hand. Ranges are relative to the whole
+    /// input sequences, starting from [`Self::start_a`]/[`Self::start_b`]. A run with a gap on one
+    /// side (an [`AlignOp::Insert`] or [`AlignOp::Delete`]) yields an empty range on that side.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use stringzilla::szs::{Alignment, AlignOp};
+    /// let alignment = Alignment {
+    ///     score: 2,
+    ///     start_a: 0,
+    ///     start_b: 0,
+    ///     end_a: 4,
+    ///     end_b: 3,
+    ///     ops: vec![AlignOp::Match(3), AlignOp::Delete(1)],
+    /// };
+    /// let runs: Vec<_> = alignment.ranges().collect();
+    /// assert_eq!(runs, vec![(0..3, 0..3, AlignOp::Match(3)), (3..4, 3..3, AlignOp::Delete(1))]);
+    /// ```
+    pub fn ranges(&self) -> impl Iterator<Item = (Range<usize>, Range<usize>, AlignOp)> + '_ {
+        let (mut a, mut b) = (self.start_a, self.start_b);
+        self.ops.iter().map(move |&op| {
+            let (len_a, len_b) = match op {
+                AlignOp::Match(n) | AlignOp::Mismatch(n) => (n, n),
+                AlignOp::Delete(n) => (n, 0),
+                AlignOp::Insert(n) => (0, n),
+            };
+            let (a_range, b_range) = (a..a + len_a, b..b + len_b);
+            a += len_a;
+            b += len_b;
+            (a_range, b_range, op)
+        })
+    }
+}
+
+/// Backtrack pointer for one DP cell: which of the three affine-gap matrices (M, Ix, Iy) the
+/// optimal path through this cell came from.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TraceFrom {
+    Diagonal,
+    Up,
+    Left,
+    /// Sentinel for a cell the traceback should stop at without emitting an op: the implicit
+    /// "before the matrix" origin, a free (unpenalized) border cell, or a local-alignment reset.
+    None,
+}
+
+/// Which sequence ends (if any) may carry free, unpenalized gaps, spanning the full
+/// global-to-local spectrum in one DP core.
+///
+/// Used by `align_with_traceback`, and selectable per engine via
+/// [`NeedlemanWunschScores::with_mode`] / [`SmithWatermanScores::with_mode`] to change how
+/// [`NeedlemanWunschScores::compute_alignments`] / [`SmithWatermanScores::compute_alignments`]
+/// score each pair's ends. Only [`Self::Global`] and [`Self::Local`] are reachable through the
+/// native GPU path (`compute`); the others are host-side (traceback) only, since the underlying
+/// native engines are strictly global/local.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AlignmentMode {
+    /// Both sequences are aligned end to end; leading and trailing gaps on either side cost the
+    /// usual gap-open/extend penalties. This is what [`NeedlemanWunschScores`] does natively.
+    #[default]
+    Global,
+    /// `a`'s leading and trailing gaps are free; `b` is aligned end to end. Useful for fitting a
+    /// short probe (`b`) into a long reference (`a`): the reference can start and end anywhere.
+    SemiGlobalA,
+    /// `b`'s leading and trailing gaps are free; `a` is aligned end to end. The mirror image of
+    /// [`Self::SemiGlobalA`], for when the long reference is passed as `a` in the other order.
+    SemiGlobalB,
+    /// Leading and trailing gaps are free on both sequences, as long as the alignment reaches the
+    /// end of at least one of them. The classic "overlap" mode used to detect read-to-reference
+    /// or read-to-read overlaps during assembly.
+    Overlap,
+    /// Free gaps everywhere: the optimal alignment can start and end anywhere in either sequence,
+    /// and any negative-scoring prefix is discarded. This is what [`SmithWatermanScores`] does
+    /// natively.
+    Local,
+}
+
+impl AlignmentMode {
+    fn free_leading_a(self) -> bool {
+        matches!(self, Self::SemiGlobalA | Self::Overlap)
+    }
+
+    fn free_leading_b(self) -> bool {
+        matches!(self, Self::SemiGlobalB | Self::Overlap)
+    }
+
+    fn free_trailing_a(self) -> bool {
+        matches!(self, Self::SemiGlobalA | Self::Overlap)
+    }
+
+    fn free_trailing_b(self) -> bool {
+        matches!(self, Self::SemiGlobalB | Self::Overlap)
+    }
+}
+
+/// Runs an affine-gap (Gotoh) DP over `a`/`b` and backtracks from the best-scoring terminal cell
+/// to the first free (unpenalized) border cell, shared by `NeedlemanWunschScores::compute_alignments`
+/// and `SmithWatermanScores::compute_alignments`. `mode` (see [`AlignmentMode`]) selects which ends,
+/// if any, carry free gaps; [`AlignmentMode::Local`] additionally resets negative-scoring cells to
+/// zero anywhere in the matrix, as classic Smith-Waterman does.
+fn align_with_traceback(
+    a: &[u8],
+    b: &[u8],
+    substitution_matrix: &[[i8; 256]; 256],
+    open_cost: i8,
+    extend_cost: i8,
+    mode: AlignmentMode,
+) -> Alignment {
+    let rows = a.len() + 1;
+    let cols = b.len() + 1;
+    let neg_inf = isize::MIN / 2;
+    let open = open_cost as isize;
+    let extend = extend_cost as isize;
+    let local = mode == AlignmentMode::Local;
+
+    // M[i][j]: best score aligning a[..i] with b[..j] ending in a substitution (or at the origin).
+    // Ix[i][j]: best score ending in a gap in `b` (consumes a[i-1], i.e. a "delete").
+    // Iy[i][j]: best score ending in a gap in `a` (consumes b[j-1], i.e. an "insert").
+    let mut m = vec![vec![0isize; cols]; rows];
+    let mut ix = vec![vec![neg_inf; cols]; rows];
+    let mut iy = vec![vec![neg_inf; cols]; rows];
+    let mut m_from = vec![vec![TraceFrom::None; cols]; rows];
+
+    if !local {
+        for i in 1..rows {
+            if mode.free_leading_a() {
+                // m[i][0] stays 0 and m_from stays None: the traceback stops here, treating
+                // a[..i] as a free (unpenalized) leading gap instead of emitting Delete ops.
+                continue;
+            }
+            ix[i][0] = open + extend * (i as isize - 1);
+            m[i][0] = ix[i][0];
+            m_from[i][0] = TraceFrom::Up;
+        }
+        for j in 1..cols {
+            if mode.free_leading_b() {
+                continue;
+            }
+            iy[0][j] = open + extend * (j as isize - 1);
+            m[0][j] = iy[0][j];
+            m_from[0][j] = TraceFrom::Left;
+        }
+    }
+
+    let mut best_score = m[0][0];
+    let mut best_cell = (0usize, 0usize);
+
+    for i in 1..rows {
+        for j in 1..cols {
+            let sub = substitution_matrix[a[i - 1] as usize][b[j - 1] as usize] as isize;
+            let diag = m[i - 1][j - 1] + sub;
+
+            ix[i][j] = core::cmp::max(m[i - 1][j] + open, ix[i - 1][j] + extend);
+            iy[i][j] = core::cmp::max(m[i][j - 1] + open, iy[i][j - 1] + extend);
+
+            let (mut best, mut from) = (diag, TraceFrom::Diagonal);
+            if ix[i][j] > best {
+                best = ix[i][j];
+                from = TraceFrom::Up;
+            }
+            if iy[i][j] > best {
+                best = iy[i][j];
+                from = TraceFrom::Left;
+            }
+            if local && best < 0 {
+                best = 0;
+                from = TraceFrom::None;
+            }
+            m[i][j] = best;
+            m_from[i][j] = from;
+
+            if local && best > best_score {
+                best_score = best;
+                best_cell = (i, j);
+            }
+        }
+    }
+    if !local {
+        best_cell = (rows - 1, cols - 1);
+        best_score = m[rows - 1][cols - 1];
+        if mode.free_trailing_a() {
+            for i in 0..rows {
+                if m[i][cols - 1] > best_score {
+                    best_score = m[i][cols - 1];
+                    best_cell = (i, cols - 1);
+                }
+            }
+        }
+        if mode.free_trailing_b() {
+            for j in 0..cols {
+                if m[rows - 1][j] > best_score {
+                    best_score = m[rows - 1][j];
+                    best_cell = (rows - 1, j);
+                }
+            }
+        }
+    }
+
+    // Backtrack from `best_cell`, through whichever matrix holds the running score, emitting one
+    // op per step; the run-length merge and final reversal happen once the raw path is collected.
+    let (mut i, mut j) = best_cell;
+    let mut raw_ops = Vec::new();
+    let mut in_matrix = TraceFrom::Diagonal; // M unless we enter a gap below
+    while i > 0 || j > 0 {
+        if local && m[i][j] == 0 && in_matrix == TraceFrom::Diagonal {
+            break;
+        }
+        match in_matrix {
+            TraceFrom::Diagonal => match m_from[i][j] {
+                TraceFrom::Diagonal => {
+                    let op = if a[i - 1] == b[j - 1] { AlignOp::Match(1) } else { AlignOp::Mismatch(1) };
+                    raw_ops.push(op);
+                    i -= 1;
+                    j -= 1;
+                }
+                TraceFrom::Up => in_matrix = TraceFrom::Up,
+                TraceFrom::Left => in_matrix = TraceFrom::Left,
+                TraceFrom::None => break,
+            },
+            TraceFrom::Up => {
+                raw_ops.push(AlignOp::Delete(1));
+                let came_from_m = ix[i][j] == m[i - 1][j] + open;
+                i -= 1;
+                if came_from_m {
+                    in_matrix = TraceFrom::Diagonal;
+                }
+            }
+            TraceFrom::Left => {
+                raw_ops.push(AlignOp::Insert(1));
+                let came_from_m = iy[i][j] == m[i][j - 1] + open;
+                j -= 1;
+                if came_from_m {
+                    in_matrix = TraceFrom::Diagonal;
+                }
+            }
+            TraceFrom::None => break,
+        }
+    }
+    raw_ops.reverse();
+
+    // Merge consecutive same-kind ops into runs, e.g. [Match(1), Match(1)] -> [Match(2)].
+    let mut ops: Vec<AlignOp> = Vec::new();
+    for op in raw_ops {
+        match (ops.last_mut(), op) {
+            (Some(AlignOp::Match(n)), AlignOp::Match(1)) => *n += 1,
+            (Some(AlignOp::Mismatch(n)), AlignOp::Mismatch(1)) => *n += 1,
+            (Some(AlignOp::Delete(n)), AlignOp::Delete(1)) => *n += 1,
+            (Some(AlignOp::Insert(n)), AlignOp::Insert(1)) => *n += 1,
+            _ => ops.push(op),
+        }
+    }
+
+    Alignment { score: best_score, start_a: i, start_b: j, end_a: best_cell.0, end_b: best_cell.1, ops }
+}
+
+/// Score assigned to any pair involving a byte outside the matrix's alphabet
+/// (e.g. a non-IUPAC nucleotide code, or a byte that isn't one of the 20 amino acids
+/// plus ambiguity codes). Chosen well below the worst in-alphabet mismatch so stray
+/// bytes never look like a plausible substitution.
+const SUBSTITUTION_MATRIX_OFF_ALPHABET_PENALTY: i8 = -128;
+
+/// Factory for ready-to-use 256x256 substitution matrices for [`NeedlemanWunschScores`]
+/// and [`SmithWatermanScores`], so callers don't have to hand-roll one cell at a time.
+///
+/// Amino acid presets ([`Self::blosum62`], [`Self::blosum50`], [`Self::pam250`]) place
+/// scores at the 20 canonical amino acid codes plus the `B`/`Z`/`X`/`*` ambiguity and
+/// stop codes, in both upper and lower case; every other byte scores
+/// [`SUBSTITUTION_MATRIX_OFF_ALPHABET_PENALTY`] against anything, including itself.
+pub struct SubstitutionMatrix;
+
+impl SubstitutionMatrix {
+    /// Identity-style matrix: `match_score` on the diagonal, `mismatch_score` everywhere
+    /// else. Works over the full byte range, so it's a reasonable default for inputs that
+    /// aren't protein or nucleotide codes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use stringzilla::szs::{DeviceScope, NeedlemanWunschScores, SubstitutionMatrix};
+    /// let device = DeviceScope::default().unwrap();
+    /// let matrix = SubstitutionMatrix::identity(2, -1);
+    /// let engine = NeedlemanWunschScores::new(&device, &matrix, -2, -1).unwrap();
+    /// ```
+    pub fn identity(match_score: i8, mismatch_score: i8) -> Box<[[i8; 256]; 256]> {
+        let mut matrix = Box::new([[mismatch_score; 256]; 256]);
+        for i in 0..256 {
+            matrix[i][i] = match_score;
+        }
+        matrix
+    }
+
+    /// BLOSUM62: the default substitution matrix for protein BLAST, tuned for alignments
+    /// of moderately diverged sequences (roughly 62% identity clusters).
+    pub fn blosum62() -> Box<[[i8; 256]; 256]> {
+        Self::from_amino_acid_table(&BLOSUM62_SCORES)
+    }
+
+    /// BLOSUM50: like [`Self::blosum62`], tuned for more diverged sequences.
+    pub fn blosum50() -> Box<[[i8; 256]; 256]> {
+        Self::from_amino_acid_table(&BLOSUM50_SCORES)
+    }
+
+    /// PAM250: a point-accepted-mutation matrix extrapolated to 250 mutations per 100
+    /// residues, traditionally used for distantly related protein sequences.
+    pub fn pam250() -> Box<[[i8; 256]; 256]> {
+        Self::from_amino_acid_table(&PAM250_SCORES)
+    }
+
+    /// NUC.4.4-style nucleotide matrix: `+5` for an exact ACGT/U match, `-4` for an exact
+    /// mismatch, and a partial score for IUPAC ambiguity codes (`R`, `Y`, `S`, `W`, `K`,
+    /// `M`, `B`, `D`, `H`, `V`, `N`) based on how much their represented base sets overlap.
+    pub fn nuc44() -> Box<[[i8; 256]; 256]> {
+        let mut matrix = Box::new([[SUBSTITUTION_MATRIX_OFF_ALPHABET_PENALTY; 256]; 256]);
+        let codes: &[u8] = b"ACGTURYSWKMBDHVN";
+        for &code_a in codes {
+            for &code_b in codes {
+                let score = Self::nuc44_pair_score(code_a, code_b);
+                for &a in &[code_a, code_a.to_ascii_lowercase()] {
+                    for &b in &[code_b, code_b.to_ascii_lowercase()] {
+                        matrix[a as usize][b as usize] = score;
+                    }
+                }
+            }
+        }
+        matrix
+    }
+
+    /// Bases represented by an IUPAC nucleotide ambiguity code (uppercase `A`/`C`/`G`/`T`/`U`).
+    fn iupac_bases(code: u8) -> &'static [u8] {
+        match code {
+            b'A' => b"A",
+            b'C' => b"C",
+            b'G' => b"G",
+            b'T' | b'U' => b"T",
+            b'R' => b"AG",
+            b'Y' => b"CT",
+            b'S' => b"GC",
+            b'W' => b"AT",
+            b'K' => b"GT",
+            b'M' => b"AC",
+            b'B' => b"CGT",
+            b'D' => b"AGT",
+            b'H' => b"ACT",
+            b'V' => b"ACG",
+            b'N' => b"ACGT",
+            _ => b"",
+        }
+    }
+
+    /// Score for a pair of IUPAC nucleotide codes, based on the overlap between the base
+    /// sets they represent: `+5` for an unambiguous exact match, `-4` for an unambiguous
+    /// exact mismatch, and a value in between proportional to the overlap otherwise.
+    fn nuc44_pair_score(code_a: u8, code_b: u8) -> i8 {
+        let bases_a = Self::iupac_bases(code_a);
+        let bases_b = Self::iupac_bases(code_b);
+        if bases_a.len() == 1 && bases_b.len() == 1 {
+            return if bases_a == bases_b { 5 } else { -4 };
+        }
+        let overlap = bases_a.iter().filter(|b| bases_b.contains(b)).count();
+        let union = bases_a.iter().chain(bases_b).collect::<std::collections::HashSet<_>>().len();
+        if union == 0 {
+            return SUBSTITUTION_MATRIX_OFF_ALPHABET_PENALTY;
+        }
+        (overlap as isize * 5 / union as isize - 4).clamp(-4, 5) as i8
+    }
+
+    /// Expands a `24x24` table over [`AMINO_ACID_ALPHABET`] into a full `256x256` matrix,
+    /// mirroring each code into both upper and lower case and leaving every other byte at
+    /// [`SUBSTITUTION_MATRIX_OFF_ALPHABET_PENALTY`].
+    fn from_amino_acid_table(table: &[[i8; 24]; 24]) -> Box<[[i8; 256]; 256]> {
+        let mut matrix = Box::new([[SUBSTITUTION_MATRIX_OFF_ALPHABET_PENALTY; 256]; 256]);
+        for (row, &code_a) in AMINO_ACID_ALPHABET.iter().enumerate() {
+            for (col, &code_b) in AMINO_ACID_ALPHABET.iter().enumerate() {
+                let score = table[row][col];
+                for &a in &[code_a, code_a.to_ascii_lowercase()] {
+                    for &b in &[code_b, code_b.to_ascii_lowercase()] {
+                        matrix[a as usize][b as usize] = score;
+                    }
+                }
+            }
+        }
+        matrix
+    }
+}
+
+/// Alphabet order shared by [`BLOSUM62_SCORES`], [`BLOSUM50_SCORES`], and [`PAM250_SCORES`]:
+/// the 20 canonical amino acids, then the `B`/`Z`/`X`/`*` ambiguity and stop codes.
+const AMINO_ACID_ALPHABET: [u8; 24] = *b"ARNDCQEGHILKMFPSTWYVBZX*";
+
+#[rustfmt::skip]
+const BLOSUM62_SCORES: [[i8; 24]; 24] = [
+    [ 4,-1,-2,-2, 0,-1,-1, 0,-2,-1,-1,-1,-1,-2,-1, 1, 0,-3,-2, 0,-2,-1, 0,-4],
+    [-1, 5, 0,-2,-3, 1, 0,-2, 0,-3,-2, 2,-1,-3,-2,-1,-1,-3,-2,-3,-1, 0,-1,-4],
+    [-2, 0, 6, 1,-3, 0, 0, 0, 1,-3,-3, 0,-2,-3,-2, 1, 0,-4,-2,-3, 3, 0,-1,-4],
+    [-2,-2, 1, 6,-3, 0, 2,-1,-1,-3,-4,-1,-3,-3,-1, 0,-1,-4,-3,-3, 4, 1,-1,-4],
+    [ 0,-3,-3,-3, 9,-3,-4,-3,-3,-1,-1,-3,-1,-2,-3,-1,-1,-2,-2,-1,-3,-3,-2,-4],
+    [-1, 1, 0, 0,-3, 5, 2,-2, 0,-3,-2, 1, 0,-3,-1, 0,-1,-2,-1,-2, 0, 3,-1,-4],
+    [-1, 0, 0, 2,-4, 2, 5,-2, 0,-3,-3, 1,-2,-3,-1, 0,-1,-3,-2,-2, 1, 4,-1,-4],
+    [ 0,-2, 0,-1,-3,-2,-2, 6,-2,-4,-4,-2,-3,-3,-2, 0,-2,-2,-3,-3,-1,-2,-1,-4],
+    [-2, 0, 1,-1,-3, 0, 0,-2, 8,-3,-3,-1,-2,-1,-2,-1,-2,-2, 2,-3, 0, 0,-1,-4],
+    [-1,-3,-3,-3,-1,-3,-3,-4,-3, 4, 2,-3, 1, 0,-3,-2,-1,-3,-1, 3,-3,-3,-1,-4],
+    [-1,-2,-3,-4,-1,-2,-3,-4,-3, 2, 4,-2, 2, 0,-3,-2,-1,-2,-1, 1,-4,-3,-1,-4],
+    [-1, 2, 0,-1,-3, 1, 1,-2,-1,-3,-2, 5,-1,-3,-1, 0,-1,-3,-2,-2, 0, 1,-1,-4],
+    [-1,-1,-2,-3,-1, 0,-2,-3,-2, 1, 2,-1, 5, 0,-2,-1,-1,-1,-1, 1,-3,-1,-1,-4],
+    [-2,-3,-3,-3,-2,-3,-3,-3,-1, 0, 0,-3, 0, 6,-4,-2,-2, 1, 3,-1,-3,-3,-1,-4],
+    [-1,-2,-2,-1,-3,-1,-1,-2,-2,-3,-3,-1,-2,-4, 7,-1,-1,-4,-3,-2,-2,-1,-2,-4],
+    [ 1,-1, 1, 0,-1, 0, 0, 0,-1,-2,-2, 0,-1,-2,-1, 4, 1,-3,-2,-2, 0, 0, 0,-4],
+    [ 0,-1, 0,-1,-1,-1,-1,-2,-2,-1,-1,-1,-1,-2,-1, 1, 5,-2,-2, 0,-1,-1, 0,-4],
+    [-3,-3,-4,-4,-2,-2,-3,-2,-2,-3,-2,-3,-1, 1,-4,-3,-2,11, 2,-3,-4,-3,-2,-4],
+    [-2,-2,-2,-3,-2,-1,-2,-3, 2,-1,-1,-2,-1, 3,-3,-2,-2, 2, 7,-1,-3,-2,-1,-4],
+    [ 0,-3,-3,-3,-1,-2,-2,-3,-3, 3, 1,-2, 1,-1,-2,-2, 0,-3,-1, 4,-3,-2,-1,-4],
+    [-2,-1, 3, 4,-3, 0, 1,-1, 0,-3,-4, 0,-3,-3,-2, 0,-1,-4,-3,-3, 4, 1,-1,-4],
+    [-1, 0, 0, 1,-3, 3, 4,-2, 0,-3,-3, 1,-1,-3,-1, 0,-1,-3,-2,-2, 1, 4,-1,-4],
+    [ 0,-1,-1,-1,-2,-1,-1,-1,-1,-1,-1,-1,-1,-1,-2, 0, 0,-2,-1,-1,-1,-1,-1,-4],
+    [-4,-4,-4,-4,-4,-4,-4,-4,-4,-4,-4,-4,-4,-4,-4,-4,-4,-4,-4,-4,-4,-4,-4, 1],
+];
+
+#[rustfmt::skip]
+const BLOSUM50_SCORES: [[i8; 24]; 24] = [
+    [ 5,-2,-1,-2,-1,-1,-1, 0,-2,-1,-2,-1,-1,-3,-1, 1, 0,-3,-2, 0,-2,-1,-1,-5],
+    [-2, 7,-1,-2,-4, 1, 0,-3, 0,-4,-3, 3,-2,-3,-3,-1,-1,-3,-1,-3,-1, 0,-1,-5],
+    [-1,-1, 7, 2,-2, 0, 0, 0, 1,-3,-4, 0,-2,-4,-2, 1, 0,-4,-2,-3, 4, 0,-1,-5],
+    [-2,-2, 2, 8,-4, 0, 2,-1,-1,-4,-4,-1,-4,-5,-1, 0,-1,-5,-3,-4, 5, 1,-1,-5],
+    [-1,-4,-2,-4,13,-3,-3,-3,-3,-2,-2,-3,-2,-2,-4,-1,-1,-5,-3,-1,-3,-3,-2,-5],
+    [-1, 1, 0, 0,-3, 7, 2,-2, 1,-3,-2, 2, 0,-4,-1, 0,-1,-1,-1,-3, 0, 4,-1,-5],
+    [-1, 0, 0, 2,-3, 2, 6,-3, 0,-4,-3, 1,-2,-3,-1,-1,-1,-3,-2,-3, 1, 5,-1,-5],
+    [ 0,-3, 0,-1,-3,-2,-3, 8,-2,-4,-4,-2,-3,-4,-2, 0,-2,-3,-3,-4,-1,-2,-2,-5],
+    [-2, 0, 1,-1,-3, 1, 0,-2,10,-4,-3, 0,-1,-1,-2,-1,-2,-3, 2,-4, 0, 0,-1,-5],
+    [-1,-4,-3,-4,-2,-3,-4,-4,-4, 5, 2,-3, 2, 0,-3,-3,-1,-3,-1, 4,-4,-3,-1,-5],
+    [-2,-3,-4,-4,-2,-2,-3,-4,-3, 2, 5,-3, 3, 1,-4,-3,-1,-2,-1, 1,-4,-3,-1,-5],
+    [-1, 3, 0,-1,-3, 2, 1,-2, 0,-3,-3, 6,-2,-4,-1, 0,-1,-3,-2,-3, 0, 1,-1,-5],
+    [-1,-2,-2,-4,-2, 0,-2,-3,-1, 2, 3,-2, 7, 0,-3,-2,-1,-1, 0, 1,-3,-1,-1,-5],
+    [-3,-3,-4,-5,-2,-4,-3,-4,-1, 0, 1,-4, 0, 8,-4,-3,-2, 1, 4,-1,-4,-4,-2,-5],
+    [-1,-3,-2,-1,-4,-1,-1,-2,-2,-3,-4,-1,-3,-4,10,-1,-1,-4,-3,-3,-2,-1,-2,-5],
+    [ 1,-1, 1, 0,-1, 0,-1, 0,-1,-3,-3, 0,-2,-3,-1, 5, 2,-4,-2,-2, 0, 0,-1,-5],
+    [ 0,-1, 0,-1,-1,-1,-1,-2,-2,-1,-1,-1,-1,-2,-1, 2, 5,-3,-2, 0, 0,-1,-1,-5],
+    [-3,-3,-4,-5,-5,-1,-3,-3,-3,-3,-2,-3,-1, 1,-4,-4,-3,15, 2,-3,-5,-2,-3,-5],
+    [-2,-1,-2,-3,-3,-1,-2,-3, 2,-1,-1,-2, 0, 4,-3,-2,-2, 2, 8,-1,-3,-2,-1,-5],
+    [ 0,-3,-3,-4,-1,-3,-3,-4,-4, 4, 1,-3, 1,-1,-3,-2, 0,-3,-1, 5,-3,-3,-1,-5],
+    [-2,-1, 4, 5,-3, 0, 1,-1, 0,-4,-4, 0,-3,-4,-2, 0, 0,-5,-3,-3, 5, 2,-1,-5],
+    [-1, 0, 0, 1,-3, 4, 5,-2, 0,-3,-3, 1,-1,-4,-1, 0,-1,-2,-2,-3, 2, 5,-1,-5],
+    [-1,-1,-1,-1,-2,-1,-1,-2,-1,-1,-1,-1,-1,-2,-2,-1,-1,-3,-1,-1,-1,-1,-1,-5],
+    [-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5, 1],
+];
+
+#[rustfmt::skip]
+const PAM250_SCORES: [[i8; 24]; 24] = [
+    [ 2,-2, 0, 0,-2, 0, 0, 1,-1,-1,-2,-1,-1,-3, 1, 1, 1,-6,-3, 0, 0, 0, 0,-8],
+    [-2, 6, 0,-1,-4, 1,-1,-3, 2,-2,-3, 3, 0,-4, 0, 0,-1, 2,-4,-2,-1, 0,-1,-8],
+    [ 0, 0, 2, 2,-4, 1, 1, 0, 2,-2,-3, 1,-2,-3, 0, 1, 0,-4,-2,-2, 2, 1, 0,-8],
+    [ 0,-1, 2, 4,-5, 2, 3, 1, 1,-2,-4, 0,-3,-6,-1, 0, 0,-7,-4,-2, 3, 3,-1,-8],
+    [-2,-4,-4,-5,12,-5,-5,-3,-3,-2,-6,-5,-5,-4,-3, 0,-2,-8, 0,-2,-4,-5,-3,-8],
+    [ 0, 1, 1, 2,-5, 4, 2,-1, 3,-2,-2, 1,-1,-5, 0,-1,-1,-5,-4,-2, 1, 3,-1,-8],
+    [ 0,-1, 1, 3,-5, 2, 4, 0, 1,-2,-3, 0,-2,-5,-1, 0, 0,-7,-4,-2, 3, 3,-1,-8],
+    [ 1,-3, 0, 1,-3,-1, 0, 5,-2,-3,-4,-2,-3,-5, 0, 1, 0,-7,-5,-1, 0, 0,-1,-8],
+    [-1, 2, 2, 1,-3, 3, 1,-2, 6,-2,-2, 0,-2,-2, 0,-1,-1,-3, 0,-2, 1, 2,-1,-8],
+    [-1,-2,-2,-2,-2,-2,-2,-3,-2, 5, 2,-2, 2, 1,-2,-1, 0,-5,-1, 4,-2,-2,-1,-8],
+    [-2,-3,-3,-4,-6,-2,-3,-4,-2, 2, 6,-3, 4, 2,-3,-3,-2,-2,-1, 2,-3,-3,-1,-8],
+    [-1, 3, 1, 0,-5, 1, 0,-2, 0,-2,-3, 5, 0,-5,-1, 0, 0,-3,-4,-2, 1, 0,-1,-8],
+    [-1, 0,-2,-3,-5,-1,-2,-3,-2, 2, 4, 0, 6, 0,-2,-2,-1,-4,-2, 2,-2,-2,-1,-8],
+    [-3,-4,-3,-6,-4,-5,-5,-5,-2, 1, 2,-5, 0, 9,-5,-3,-3, 0, 7,-1,-4,-5,-2,-8],
+    [ 1, 0, 0,-1,-3, 0,-1, 0, 0,-2,-3,-1,-2,-5, 6, 1, 0,-6,-5,-1,-1, 0,-1,-8],
+    [ 1, 0, 1, 0, 0,-1, 0, 1,-1,-1,-3, 0,-2,-3, 1, 2, 1,-2,-3,-1, 0, 0, 0,-8],
+    [ 1,-1, 0, 0,-2,-1, 0, 0,-1, 0,-2, 0,-1,-3, 0, 1, 3,-5,-3, 0, 0,-1, 0,-8],
+    [-6, 2,-4,-7,-8,-5,-7,-7,-3,-5,-2,-3,-4, 0,-6,-2,-5,17, 0,-6,-5,-6,-4,-8],
+    [-3,-4,-2,-4, 0,-4,-4,-5, 0,-1,-1,-4,-2, 7,-5,-3,-3, 0,10,-2,-3,-4,-2,-8],
+    [ 0,-2,-2,-2,-2,-2,-2,-1,-2, 4, 2,-2, 2,-1,-1,-1, 0,-6,-2, 4,-2,-2,-1,-8],
+    [ 0,-1, 2, 3,-4, 1, 3, 0, 1,-2,-3, 1,-2,-4,-1, 0, 0,-5,-3,-2, 3, 2,-1,-8],
+    [ 0, 0, 1, 3,-5, 3, 3, 0, 2,-2,-3, 0,-2,-5, 0, 0,-1,-6,-4,-2, 2, 3,-1,-8],
+    [ 0,-1, 0,-1,-3,-1,-1,-1,-1,-1,-1,-1,-1,-2,-1, 0, 0,-4,-2,-1,-1,-1,-1,-8],
+    [-8,-8,-8,-8,-8,-8,-8,-8,-8,-8,-8,-8,-8,-8,-8,-8,-8,-8,-8,-8,-8,-8,-8, 1],
+];
+
+/// Amino acid (or stop, `*`) placed at a codon that contains a byte outside `ACGTU`
+/// (case-insensitively), e.g. an IUPAC ambiguity code or a gap character.
+const GENETIC_CODE_AMBIGUOUS_CODON: u8 = b'X';
+
+/// A DNA-to-protein translation table, used by [`translate_six_frames`] to turn nucleotide
+/// sequences into amino acid sequences before running them through [`NeedlemanWunschScores`] or
+/// [`SmithWatermanScores`].
+///
+/// Codons are packed into a 64-entry table indexed 2 bits per base (`A=0, C=1, G=2, T/U=3`), so
+/// alternate tables (e.g. mitochondrial codes) can be added as further constructors alongside
+/// [`Self::standard`] without changing the lookup machinery.
+pub struct GeneticCode {
+    codons: [u8; 64],
+}
+
+impl GeneticCode {
+    /// The standard genetic code (NCBI translation table 1), used by the vast majority of
+    /// nuclear genomes.
+    pub fn standard() -> Self {
+        #[rustfmt::skip]
+        let codons = [
+            b'K', b'N', b'K', b'N', b'T', b'T', b'T', b'T',
+            b'R', b'S', b'R', b'S', b'I', b'I', b'M', b'I',
+            b'Q', b'H', b'Q', b'H', b'P', b'P', b'P', b'P',
+            b'R', b'R', b'R', b'R', b'L', b'L', b'L', b'L',
+            b'E', b'D', b'E', b'D', b'A', b'A', b'A', b'A',
+            b'G', b'G', b'G', b'G', b'V', b'V', b'V', b'V',
+            b'*', b'Y', b'*', b'Y', b'S', b'S', b'S', b'S',
+            b'*', b'C', b'W', b'C', b'L', b'F', b'L', b'F',
+        ];
+        Self { codons }
+    }
+
+    /// Translates one codon into an amino acid byte (uppercase, or `*` for a stop codon).
+    /// Any base outside `ACGTU` (case-insensitively) yields [`GENETIC_CODE_AMBIGUOUS_CODON`].
+    fn translate_codon(&self, codon: [u8; 3]) -> u8 {
+        let mut index = 0usize;
+        for base in codon {
+            let bits = match base.to_ascii_uppercase() {
+                b'A' => 0,
+                b'C' => 1,
+                b'G' => 2,
+                b'T' | b'U' => 3,
+                _ => return GENETIC_CODE_AMBIGUOUS_CODON,
+            };
+            index = (index << 2) | bits;
+        }
+        self.codons[index]
+    }
+}
+
+/// Reverse-complements a DNA sequence, mapping `A<->T`, `C<->G`, `U->A` and leaving any other
+/// byte (ambiguity codes, gaps) unchanged while still reversing its position.
+fn reverse_complement_dna(dna: &[u8]) -> Vec<u8> {
+    dna.iter()
+        .rev()
+        .map(|&base| match base.to_ascii_uppercase() {
+            b'A' => b'T',
+            b'T' | b'U' => b'A',
+            b'C' => b'G',
+            b'G' => b'C',
+            other => other,
+        })
+        .collect()
+}
+
+/// One reading frame of a translated DNA sequence, as produced by [`translate_six_frames`].
+///
+/// `frame` follows the blastx convention: `1`/`2`/`3` are the forward frames starting at offsets
+/// `0`/`1`/`2` of `dna`, and `-1`/`-2`/`-3` are the same offsets read off the reverse complement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslatedFrame {
+    pub frame: i8,
+    pub protein: Vec<u8>,
+}
+
+/// Translates `dna` into all six reading frames (three forward, three reverse-complement) using
+/// `code`, the way protein-level homology search tools (e.g. blastx) prepare nucleotide queries
+/// for alignment against protein references. Trailing bases that don't complete a codon are
+/// dropped, as is conventional for six-frame translation.
+pub fn translate_six_frames(dna: &[u8], code: &GeneticCode) -> [TranslatedFrame; 6] {
+    let reverse = reverse_complement_dna(dna);
+    let translate_from = |strand: &[u8], offset: usize| -> Vec<u8> {
+        strand[offset.min(strand.len())..].chunks_exact(3).map(|codon| code.translate_codon([codon[0], codon[1], codon[2]])).collect()
+    };
+    [
+        TranslatedFrame { frame: 1, protein: translate_from(dna, 0) },
+        TranslatedFrame { frame: 2, protein: translate_from(dna, 1) },
+        TranslatedFrame { frame: 3, protein: translate_from(dna, 2) },
+        TranslatedFrame { frame: -1, protein: translate_from(&reverse, 0) },
+        TranslatedFrame { frame: -2, protein: translate_from(&reverse, 1) },
+        TranslatedFrame { frame: -3, protein: translate_from(&reverse, 2) },
+    ]
+}
+
+/// One DNA-to-protein alignment produced by `compute_translated_alignments`: the best-scoring of
+/// the six translated reading frames of the query, and which frame won.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslatedAlignment {
+    /// Which reading frame of the DNA query produced the best-scoring alignment, using the
+    /// blastx convention documented on [`TranslatedFrame::frame`].
+    pub frame: i8,
+    /// The alignment of that frame's translated protein against the reference.
+    pub alignment: Alignment,
+}
+
+/// Translates `dna` into all six frames and aligns each against `protein` with
+/// `align_with_traceback`, keeping the highest-scoring frame. Shared by
+/// `NeedlemanWunschScores::compute_translated_alignments` and
+/// `SmithWatermanScores::compute_translated_alignments`, each passing its own `mode`.
+fn best_translated_alignment(
+    dna: &[u8],
+    protein: &[u8],
+    code: &GeneticCode,
+    substitution_matrix: &[[i8; 256]; 256],
+    open_cost: i8,
+    extend_cost: i8,
+    mode: AlignmentMode,
+) -> TranslatedAlignment {
+    translate_six_frames(dna, code)
+        .into_iter()
+        .map(|translated| {
+            let alignment = align_with_traceback(&translated.protein, protein, substitution_matrix, open_cost, extend_cost, mode);
+            TranslatedAlignment { frame: translated.frame, alignment }
+        })
+        .max_by_key(|translated_alignment| translated_alignment.alignment.score)
+        .expect("translate_six_frames always returns exactly six frames")
+}
+
+/// Needleman-Wunsch global sequence alignment scoring engine.
+///
 /// Finds optimal global alignments using a substitution matrix and gap penalties.
 /// Returns alignment scores rather than distances.
 ///
@@ -1426,6 +3175,10 @@ unsafe impl Sync for LevenshteinDistancesUtf8 {}
 /// ```
 pub struct NeedlemanWunschScores {
     handle: NeedlemanWunschScoresHandle,
+    substitution_matrix: Box<[[i8; 256]; 256]>,
+    open_cost: i8,
+    extend_cost: i8,
+    mode: AlignmentMode,
 }
 
 impl NeedlemanWunschScores {
@@ -1440,6 +3193,21 @@ impl NeedlemanWunschScores {
         substitution_matrix: &[[i8; 256]; 256],
         open_cost: i8,
         extend_cost: i8,
+    ) -> Result<Self, Error> {
+        Self::with_mode(device, substitution_matrix, open_cost, extend_cost, AlignmentMode::Global)
+    }
+
+    /// Same as [`Self::new`], but selects the free-end-gap behavior (see [`AlignmentMode`]) used
+    /// by the host-side [`Self::compute_alignments`] and [`Self::compute_translated_alignments`].
+    ///
+    /// [`Self::compute`] is unaffected by `mode`: the native engine this type wraps is always
+    /// strictly global, so only the traceback methods see the selected mode.
+    pub fn with_mode(
+        device: &DeviceScope,
+        substitution_matrix: &[[i8; 256]; 256],
+        open_cost: i8,
+        extend_cost: i8,
+        mode: AlignmentMode,
     ) -> Result<Self, Error> {
         let mut handle = ptr::null_mut();
         let capabilities = device.get_capabilities().unwrap_or(0);
@@ -1456,7 +3224,13 @@ impl NeedlemanWunschScores {
             )
         };
         match status {
-            Status::Success => Ok(Self { handle }),
+            Status::Success => Ok(Self {
+                handle,
+                substitution_matrix: Box::new(*substitution_matrix),
+                open_cost,
+                extend_cost,
+                mode,
+            }),
             err => Err(rust_error_from_c_message(err, error_msg)),
         }
     }
@@ -1657,78 +3431,293 @@ impl NeedlemanWunschScores {
         }
         Err(Error::from(SzStatus::UnexpectedDimensions))
     }
-}
-
-impl Drop for NeedlemanWunschScores {
-    fn drop(&mut self) {
-        if !self.handle.is_null() {
-            unsafe { szs_needleman_wunsch_scores_free(self.handle) };
-        }
-    }
-}
-
-unsafe impl Send for NeedlemanWunschScores {}
-unsafe impl Sync for NeedlemanWunschScores {}
-
-/// Smith-Waterman local sequence alignment scoring engine.
-///
-/// Finds optimal local alignments within sequences using a substitution matrix
-/// and gap penalties. Returns maximum scores found anywhere in the alignment matrix.
-///
-/// # Examples
-///
-/// ```rust
-/// # use stringzilla::szs::{DeviceScope, SmithWatermanScores};
-/// // Create scoring matrix
-/// let mut matrix = [[-1i8; 256]; 256];
-/// for i in 0..256 {
-///     matrix[i][i] = 2;
-/// }
-///
-/// let device = DeviceScope::default().unwrap();
-/// let engine = SmithWatermanScores::new(&device, &matrix, -2, -1).unwrap();
-///
-/// let seq_a = vec!["ACGTAAACGT"];
-/// let seq_b = vec!["ACGT"];
-/// let scores = engine.compute(&device, &seq_a, &seq_b).unwrap();
-/// ```
-pub struct SmithWatermanScores {
-    handle: SmithWatermanScoresHandle,
-}
 
-impl SmithWatermanScores {
-    /// Create a new Smith-Waterman local alignment scoring engine.
-    ///
-    /// Initializes the engine for local sequence alignment with custom scoring parameters.
-    /// The engine automatically adapts to available hardware capabilities.
-    ///
-    /// # Parameters
-    ///
-    /// - `device`: Device scope for execution context
-    /// - `substitution_matrix`: 256x256 scoring matrix for character pairs
-    /// - `open_cost`: Gap opening penalty (typically negative)
-    /// - `extend_cost`: Gap extension penalty (typically negative, ‚â• open_cost)
-    ///
-    /// # Matrix Design for Local Alignment
-    ///
-    /// For effective local alignment, the matrix should have:
-    /// - **Positive match scores**: Reward similar characters
-    /// - **Negative mismatch scores**: Penalize dissimilar characters
-    /// - **Balanced penalties**: Prevent excessive gap formation
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// # use stringzilla::szs::{DeviceScope, SmithWatermanScores};
-    /// let device = DeviceScope::default().unwrap();
+    /// Submits Needleman–Wunsch scoring for computation without blocking.
     ///
-    /// // Protein alignment matrix (simplified)
-    /// let mut protein_matrix = [[-1i8; 256]; 256];  // Default mismatch
+    /// Mirrors [`Self::compute_into`]'s tape dispatch (64-bit offsets preferred, falling back to
+    /// 32-bit; mixed widths rejected), but enqueues the batch on `device` and returns immediately
+    /// with a [`Completion`] instead of waiting for it to finish. `results` stays borrowed for the
+    /// `Completion`'s lifetime and must not be read until [`Completion::wait`] (or a successful
+    /// [`Completion::poll`]) confirms the batch is done.
     ///
-    /// // Set positive scores for similar amino acids
-    /// let amino_acids = b"ACDEFGHIKLMNPQRSTVWY";
-    /// for &aa in amino_acids {
-    ///     protein_matrix[aa as usize][aa as usize] = 5; // Identity
+    /// Requirements and errors are the same as [`Self::compute_into`].
+    pub fn submit_into<'a>(
+        &self,
+        device: &DeviceScope,
+        a: AnyBytesTape<'a>,
+        b: AnyBytesTape<'a>,
+        results: &'a mut UnifiedVec<isize>,
+    ) -> Result<Completion<'a>, Error> {
+        let mut error_msg: *const c_char = ptr::null();
+        let results_stride = core::mem::size_of::<isize>();
+
+        let a64 = match &a {
+            AnyBytesTape::Tape64(t) => Some(SzSequenceU64Tape::from(t)),
+            AnyBytesTape::View64(v) => Some(SzSequenceU64Tape::from(v)),
+            _ => None,
+        };
+        let b64 = match &b {
+            AnyBytesTape::Tape64(t) => Some(SzSequenceU64Tape::from(t)),
+            AnyBytesTape::View64(v) => Some(SzSequenceU64Tape::from(v)),
+            _ => None,
+        };
+        if let (Some(va), Some(vb)) = (a64, b64) {
+            let need = core::cmp::min(va.count, vb.count);
+            if results.len() < need {
+                return Err(Error::from(SzStatus::UnexpectedDimensions));
+            }
+            let mut event: *mut c_void = ptr::null_mut();
+            let status = unsafe {
+                szs_needleman_wunsch_scores_submit_u64tape(
+                    self.handle,
+                    device.handle,
+                    &va as *const _ as *const c_void,
+                    &vb as *const _ as *const c_void,
+                    results.as_mut_ptr(),
+                    results_stride,
+                    &mut event,
+                    &mut error_msg,
+                )
+            };
+            return match status {
+                Status::Success => Ok(Completion::new(event)),
+                err => Err(rust_error_from_c_message(err, error_msg)),
+            };
+        }
+
+        let a32 = match &a {
+            AnyBytesTape::Tape32(t) => Some(SzSequenceU32Tape::from(t)),
+            AnyBytesTape::View32(v) => Some(SzSequenceU32Tape::from(v)),
+            _ => None,
+        };
+        let b32 = match &b {
+            AnyBytesTape::Tape32(t) => Some(SzSequenceU32Tape::from(t)),
+            AnyBytesTape::View32(v) => Some(SzSequenceU32Tape::from(v)),
+            _ => None,
+        };
+        if let (Some(va), Some(vb)) = (a32, b32) {
+            let need = core::cmp::min(va.count, vb.count);
+            if results.len() < need {
+                return Err(Error::from(SzStatus::UnexpectedDimensions));
+            }
+            let mut event: *mut c_void = ptr::null_mut();
+            let status = unsafe {
+                szs_needleman_wunsch_scores_submit_u32tape(
+                    self.handle,
+                    device.handle,
+                    &va as *const _ as *const c_void,
+                    &vb as *const _ as *const c_void,
+                    results.as_mut_ptr(),
+                    results_stride,
+                    &mut event,
+                    &mut error_msg,
+                )
+            };
+            return match status {
+                Status::Success => Ok(Completion::new(event)),
+                err => Err(rust_error_from_c_message(err, error_msg)),
+            };
+        }
+
+        Err(Error::from(SzStatus::UnexpectedDimensions))
+    }
+
+    /// Submits Needleman–Wunsch scoring for computation without blocking, returning an owned
+    /// [`JobHandle`] instead of the borrowed [`Completion`] that [`Self::submit_into`] returns.
+    ///
+    /// Unlike `submit_into`, `submit` builds and owns its own tapes and results buffer --
+    /// mirroring [`Self::compute`]'s allocation -- so the returned [`JobHandle`] can be moved
+    /// freely, e.g. `.await`ed under an async runtime or handed to another thread, instead of
+    /// being tied to a borrow of caller-owned state.
+    ///
+    /// # Errors
+    ///
+    /// Fails immediately (without spawning a background worker) if building the tapes or
+    /// enqueuing the batch on `device` fails; errors from the batch itself surface from
+    /// [`JobHandle::join`] or the `Future` impl instead.
+    pub fn submit<T, S>(
+        &self,
+        device: &DeviceScope,
+        sequences_a: T,
+        sequences_b: T,
+    ) -> Result<JobHandle<Box<UnifiedVec<isize>>>, Error>
+    where
+        T: AsRef<[S]>,
+        S: AsRef<[u8]>,
+    {
+        let seq_a_slice = sequences_a.as_ref();
+        let seq_b_slice = sequences_b.as_ref();
+        let num_pairs = seq_a_slice.len().min(seq_b_slice.len());
+
+        let mut results = Box::new(UnifiedVec::with_capacity_in(num_pairs, UnifiedAlloc));
+        results.resize(num_pairs, 0);
+
+        let force_64bit = should_use_64bit_for_bytes(seq_a_slice, seq_b_slice);
+        let tape_a = copy_bytes_into_tape(seq_a_slice, force_64bit)?;
+        let tape_b = copy_bytes_into_tape(seq_b_slice, force_64bit)?;
+
+        // SAFETY: see the matching comment in `LevenshteinDistances::submit`.
+        let results_ptr: *mut UnifiedVec<isize> = Box::as_mut(&mut results);
+        let results_ref: &'static mut UnifiedVec<isize> = unsafe { &mut *results_ptr };
+        let completion = self.submit_into(device, tape_a, tape_b, results_ref)?;
+
+        Ok(JobHandle::spawn(results, completion))
+    }
+
+    /// Computes the optimal alignment for each sequence pair, including its edit path.
+    ///
+    /// Unlike [`Self::compute`], which only reports the final score, this retraces the affine-gap
+    /// DP matrix to recover where and how each pair aligns. This is a host-side computation (no
+    /// GPU dispatch), since it needs the full traceback, not just the bottom-right score.
+    ///
+    /// # Returns
+    ///
+    /// One [`Alignment`] per pair, in `(a[0], b[0]), (a[1], b[1]), ...` order, truncated to
+    /// `min(sequences_a.len(), sequences_b.len())`. With the default [`AlignmentMode::Global`],
+    /// `start_a`/`start_b` are always `0` and `end_a`/`end_b` always the full sequence lengths;
+    /// [`Self::with_mode`] frees one or both sequences' ends instead (see [`AlignmentMode`]).
+    pub fn compute_alignments<T, S>(&self, sequences_a: T, sequences_b: T) -> Vec<Alignment>
+    where
+        T: AsRef<[S]>,
+        S: AsRef<[u8]>,
+    {
+        let seq_a_slice = sequences_a.as_ref();
+        let seq_b_slice = sequences_b.as_ref();
+        let num_pairs = seq_a_slice.len().min(seq_b_slice.len());
+
+        (0..num_pairs)
+            .map(|idx| {
+                align_with_traceback(
+                    seq_a_slice[idx].as_ref(),
+                    seq_b_slice[idx].as_ref(),
+                    &self.substitution_matrix,
+                    self.open_cost,
+                    self.extend_cost,
+                    self.mode,
+                )
+            })
+            .collect()
+    }
+
+    /// Aligns DNA queries against protein references, blastx-style: translates each DNA query
+    /// into all six reading frames via `code` (see [`translate_six_frames`]), aligns every frame
+    /// against the paired protein reference using this engine's substitution matrix, gap costs
+    /// and [`AlignmentMode`] (global unless set with [`Self::with_mode`]), and keeps the
+    /// best-scoring frame per pair.
+    ///
+    /// Like [`Self::compute_alignments`], this recovers the full traceback and therefore runs on
+    /// the host (no GPU dispatch).
+    ///
+    /// # Returns
+    ///
+    /// One [`TranslatedAlignment`] per pair, truncated to `min(dna_queries.len(),
+    /// protein_references.len())`.
+    pub fn compute_translated_alignments<T, S, U, P>(
+        &self,
+        dna_queries: T,
+        protein_references: U,
+        code: &GeneticCode,
+    ) -> Vec<TranslatedAlignment>
+    where
+        T: AsRef<[S]>,
+        S: AsRef<[u8]>,
+        U: AsRef<[P]>,
+        P: AsRef<[u8]>,
+    {
+        let dna_slice = dna_queries.as_ref();
+        let protein_slice = protein_references.as_ref();
+        let num_pairs = dna_slice.len().min(protein_slice.len());
+
+        (0..num_pairs)
+            .map(|idx| {
+                best_translated_alignment(
+                    dna_slice[idx].as_ref(),
+                    protein_slice[idx].as_ref(),
+                    code,
+                    &self.substitution_matrix,
+                    self.open_cost,
+                    self.extend_cost,
+                    self.mode,
+                )
+            })
+            .collect()
+    }
+}
+
+impl Drop for NeedlemanWunschScores {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            unsafe { szs_needleman_wunsch_scores_free(self.handle) };
+        }
+    }
+}
+
+unsafe impl Send for NeedlemanWunschScores {}
+unsafe impl Sync for NeedlemanWunschScores {}
+
+/// Smith-Waterman local sequence alignment scoring engine.
+///
+/// Finds optimal local alignments within sequences using a substitution matrix
+/// and gap penalties. Returns maximum scores found anywhere in the alignment matrix.
+///
+/// # Examples
+///
+/// ```rust
+/// # use stringzilla::szs::{DeviceScope, SmithWatermanScores};
+/// // Create scoring matrix
+/// let mut matrix = [[-1i8; 256]; 256];
+/// for i in 0..256 {
+///     matrix[i][i] = 2;
+/// }
+///
+/// let device = DeviceScope::default().unwrap();
+/// let engine = SmithWatermanScores::new(&device, &matrix, -2, -1).unwrap();
+///
+/// let seq_a = vec!["ACGTAAACGT"];
+/// let seq_b = vec!["ACGT"];
+/// let scores = engine.compute(&device, &seq_a, &seq_b).unwrap();
+/// ```
+pub struct SmithWatermanScores {
+    handle: SmithWatermanScoresHandle,
+    substitution_matrix: Box<[[i8; 256]; 256]>,
+    open_cost: i8,
+    extend_cost: i8,
+    mode: AlignmentMode,
+}
+
+impl SmithWatermanScores {
+    /// Create a new Smith-Waterman local alignment scoring engine.
+    ///
+    /// Initializes the engine for local sequence alignment with custom scoring parameters.
+    /// The engine automatically adapts to available hardware capabilities.
+    ///
+    /// # Parameters
+    ///
+    /// - `device`: Device scope for execution context
+    /// - `substitution_matrix`: 256x256 scoring matrix for character pairs
+    /// - `open_cost`: Gap opening penalty (typically negative)
+    /// - `extend_cost`: Gap extension penalty (typically negative, ‚â• open_cost)
+    ///
+    /// # Matrix Design for Local Alignment
+    ///
+    /// For effective local alignment, the matrix should have:
+    /// - **Positive match scores**: Reward similar characters
+    /// - **Negative mismatch scores**: Penalize dissimilar characters
+    /// - **Balanced penalties**: Prevent excessive gap formation
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use stringzilla::szs::{DeviceScope, SmithWatermanScores};
+    /// let device = DeviceScope::default().unwrap();
+    ///
+    /// // Protein alignment matrix (simplified)
+    /// let mut protein_matrix = [[-1i8; 256]; 256];  // Default mismatch
+    ///
+    /// // Set positive scores for similar amino acids
+    /// let amino_acids = b"ACDEFGHIKLMNPQRSTVWY";
+    /// for &aa in amino_acids {
+    ///     protein_matrix[aa as usize][aa as usize] = 5; // Identity
     /// }
     ///
     /// // Similar amino acids get positive but lower scores
@@ -1755,6 +3744,21 @@ impl SmithWatermanScores {
         substitution_matrix: &[[i8; 256]; 256],
         open_cost: i8,
         extend_cost: i8,
+    ) -> Result<Self, Error> {
+        Self::with_mode(device, substitution_matrix, open_cost, extend_cost, AlignmentMode::Local)
+    }
+
+    /// Same as [`Self::new`], but selects the free-end-gap behavior (see [`AlignmentMode`]) used
+    /// by the host-side [`Self::compute_alignments`] and [`Self::compute_translated_alignments`].
+    ///
+    /// [`Self::compute`] is unaffected by `mode`: the native engine this type wraps is always
+    /// strictly local, so only the traceback methods see the selected mode.
+    pub fn with_mode(
+        device: &DeviceScope,
+        substitution_matrix: &[[i8; 256]; 256],
+        open_cost: i8,
+        extend_cost: i8,
+        mode: AlignmentMode,
     ) -> Result<Self, Error> {
         let mut handle = ptr::null_mut();
         let capabilities = device.get_capabilities().unwrap_or(0);
@@ -1771,7 +3775,13 @@ impl SmithWatermanScores {
             )
         };
         match status {
-            Status::Success => Ok(Self { handle }),
+            Status::Success => Ok(Self {
+                handle,
+                substitution_matrix: Box::new(*substitution_matrix),
+                open_cost,
+                extend_cost,
+                mode,
+            }),
             err => Err(rust_error_from_c_message(err, error_msg)),
         }
     }
@@ -1977,63 +3987,577 @@ impl SmithWatermanScores {
         }
         Err(Error::from(SzStatus::UnexpectedDimensions))
     }
-}
-
-impl Drop for SmithWatermanScores {
-    fn drop(&mut self) {
-        if !self.handle.is_null() {
-            unsafe { szs_smith_waterman_scores_free(self.handle) };
-        }
-    }
-}
-
-unsafe impl Send for SmithWatermanScores {}
-unsafe impl Sync for SmithWatermanScores {}
-
-/// Builder for configuring fingerprinting engines with optimal parameters.
-///
-/// Provides preset configurations for common use cases and allows fine-tuning
-/// of parameters for specific applications.
-///
-/// # Examples
-///
-/// ```rust
-/// # use stringzilla::szs::{Fingerprints, DeviceScope};
-/// let device = DeviceScope::default().unwrap();
-///
-/// // DNA sequence analysis
-/// let dna_engine = Fingerprints::builder()
-///     .dna()
-///     .dimensions(256)
-///     .build(&device)
-///     .unwrap();
-///
-/// // Text processing
-/// let text_engine = Fingerprints::builder()
-///     .ascii()
-///     .dimensions(512)
-///     .build(&device)
-///     .unwrap();
-/// ```
-pub struct FingerprintsBuilder {
-    alphabet_size: usize,
-    window_widths: Option<Vec<usize>>,
-    dimensions: usize,
-}
 
-impl FingerprintsBuilder {
-    /// Create a new builder with system-optimized defaults.
-    ///
-    /// Uses intelligent defaults that adapt to available hardware capabilities:
-    /// - Alphabet size: 256 (suitable for binary data and most text)
-    /// - Window widths: Hardware-optimized selection
-    /// - Dimensions: 1024 (balances accuracy and performance)
+    /// Submits Smith–Waterman scoring for computation without blocking.
     ///
-    /// # Returns
-    ///
-    /// - `Self`: New builder with defaults
+    /// Mirrors [`Self::compute_into`]'s tape dispatch (64-bit offsets preferred, falling back to
+    /// 32-bit; mixed widths rejected), but enqueues the batch on `device` and returns immediately
+    /// with a [`Completion`] instead of waiting for it to finish. `results` stays borrowed for the
+    /// `Completion`'s lifetime and must not be read until [`Completion::wait`] (or a successful
+    /// [`Completion::poll`]) confirms the batch is done.
     ///
-    /// # Examples
+    /// Requirements and errors are the same as [`Self::compute_into`].
+    pub fn submit_into<'a>(
+        &self,
+        device: &DeviceScope,
+        a: AnyBytesTape<'a>,
+        b: AnyBytesTape<'a>,
+        results: &'a mut UnifiedVec<isize>,
+    ) -> Result<Completion<'a>, Error> {
+        let mut error_msg: *const c_char = ptr::null();
+        let results_stride = core::mem::size_of::<isize>();
+
+        let a64 = match &a {
+            AnyBytesTape::Tape64(t) => Some(SzSequenceU64Tape::from(t)),
+            AnyBytesTape::View64(v) => Some(SzSequenceU64Tape::from(v)),
+            _ => None,
+        };
+        let b64 = match &b {
+            AnyBytesTape::Tape64(t) => Some(SzSequenceU64Tape::from(t)),
+            AnyBytesTape::View64(v) => Some(SzSequenceU64Tape::from(v)),
+            _ => None,
+        };
+        if let (Some(va), Some(vb)) = (a64, b64) {
+            let need = core::cmp::min(va.count, vb.count);
+            if results.len() < need {
+                return Err(Error::from(SzStatus::UnexpectedDimensions));
+            }
+            let mut event: *mut c_void = ptr::null_mut();
+            let status = unsafe {
+                szs_smith_waterman_scores_submit_u64tape(
+                    self.handle,
+                    device.handle,
+                    &va as *const _ as *const c_void,
+                    &vb as *const _ as *const c_void,
+                    results.as_mut_ptr(),
+                    results_stride,
+                    &mut event,
+                    &mut error_msg,
+                )
+            };
+            return match status {
+                Status::Success => Ok(Completion::new(event)),
+                err => Err(rust_error_from_c_message(err, error_msg)),
+            };
+        }
+
+        let a32 = match &a {
+            AnyBytesTape::Tape32(t) => Some(SzSequenceU32Tape::from(t)),
+            AnyBytesTape::View32(v) => Some(SzSequenceU32Tape::from(v)),
+            _ => None,
+        };
+        let b32 = match &b {
+            AnyBytesTape::Tape32(t) => Some(SzSequenceU32Tape::from(t)),
+            AnyBytesTape::View32(v) => Some(SzSequenceU32Tape::from(v)),
+            _ => None,
+        };
+        if let (Some(va), Some(vb)) = (a32, b32) {
+            let need = core::cmp::min(va.count, vb.count);
+            if results.len() < need {
+                return Err(Error::from(SzStatus::UnexpectedDimensions));
+            }
+            let mut event: *mut c_void = ptr::null_mut();
+            let status = unsafe {
+                szs_smith_waterman_scores_submit_u32tape(
+                    self.handle,
+                    device.handle,
+                    &va as *const _ as *const c_void,
+                    &vb as *const _ as *const c_void,
+                    results.as_mut_ptr(),
+                    results_stride,
+                    &mut event,
+                    &mut error_msg,
+                )
+            };
+            return match status {
+                Status::Success => Ok(Completion::new(event)),
+                err => Err(rust_error_from_c_message(err, error_msg)),
+            };
+        }
+
+        Err(Error::from(SzStatus::UnexpectedDimensions))
+    }
+
+    /// Submits Smith–Waterman scoring for computation without blocking, returning an owned
+    /// [`JobHandle`] instead of the borrowed [`Completion`] that [`Self::submit_into`] returns.
+    ///
+    /// Unlike `submit_into`, `submit` builds and owns its own tapes and results buffer --
+    /// mirroring [`Self::compute`]'s allocation -- so the returned [`JobHandle`] can be moved
+    /// freely, e.g. `.await`ed under an async runtime or handed to another thread, instead of
+    /// being tied to a borrow of caller-owned state.
+    ///
+    /// # Errors
+    ///
+    /// Fails immediately (without spawning a background worker) if building the tapes or
+    /// enqueuing the batch on `device` fails; errors from the batch itself surface from
+    /// [`JobHandle::join`] or the `Future` impl instead.
+    pub fn submit<T, S>(
+        &self,
+        device: &DeviceScope,
+        sequences_a: T,
+        sequences_b: T,
+    ) -> Result<JobHandle<Box<UnifiedVec<isize>>>, Error>
+    where
+        T: AsRef<[S]>,
+        S: AsRef<[u8]>,
+    {
+        let seq_a_slice = sequences_a.as_ref();
+        let seq_b_slice = sequences_b.as_ref();
+        let num_pairs = seq_a_slice.len().min(seq_b_slice.len());
+
+        let mut results = Box::new(UnifiedVec::with_capacity_in(num_pairs, UnifiedAlloc));
+        results.resize(num_pairs, 0);
+
+        let force_64bit = should_use_64bit_for_bytes(seq_a_slice, seq_b_slice);
+        let tape_a = copy_bytes_into_tape(seq_a_slice, force_64bit)?;
+        let tape_b = copy_bytes_into_tape(seq_b_slice, force_64bit)?;
+
+        // SAFETY: see the matching comment in `LevenshteinDistances::submit`.
+        let results_ptr: *mut UnifiedVec<isize> = Box::as_mut(&mut results);
+        let results_ref: &'static mut UnifiedVec<isize> = unsafe { &mut *results_ptr };
+        let completion = self.submit_into(device, tape_a, tape_b, results_ref)?;
+
+        Ok(JobHandle::spawn(results, completion))
+    }
+
+    /// Computes the optimal local alignment for each sequence pair, including its edit path.
+    ///
+    /// Unlike [`Self::compute`], which only reports the best local score, this retraces the
+    /// affine-gap DP matrix to recover where and how the best-scoring substrings align. This is
+    /// a host-side computation (no GPU dispatch), since it needs the full traceback, not just the
+    /// max-scoring cell.
+    ///
+    /// # Returns
+    ///
+    /// One [`Alignment`] per pair, in `(a[0], b[0]), (a[1], b[1]), ...` order, truncated to
+    /// `min(sequences_a.len(), sequences_b.len())`. `start_a`/`end_a` and `start_b`/`end_b` bound
+    /// the best-scoring substrings found within each sequence, not necessarily the whole sequence.
+    pub fn compute_alignments<T, S>(&self, sequences_a: T, sequences_b: T) -> Vec<Alignment>
+    where
+        T: AsRef<[S]>,
+        S: AsRef<[u8]>,
+    {
+        let seq_a_slice = sequences_a.as_ref();
+        let seq_b_slice = sequences_b.as_ref();
+        let num_pairs = seq_a_slice.len().min(seq_b_slice.len());
+
+        (0..num_pairs)
+            .map(|idx| {
+                align_with_traceback(
+                    seq_a_slice[idx].as_ref(),
+                    seq_b_slice[idx].as_ref(),
+                    &self.substitution_matrix,
+                    self.open_cost,
+                    self.extend_cost,
+                    self.mode,
+                )
+            })
+            .collect()
+    }
+
+    /// Aligns DNA queries against protein references, blastx-style: translates each DNA query
+    /// into all six reading frames via `code` (see [`translate_six_frames`]), aligns every frame
+    /// against the paired protein reference using this engine's substitution matrix, gap costs
+    /// and [`AlignmentMode`] (local unless set with [`Self::with_mode`]), and keeps the
+    /// best-scoring frame per pair.
+    ///
+    /// Like [`Self::compute_alignments`], this recovers the full traceback and therefore runs on
+    /// the host (no GPU dispatch).
+    ///
+    /// # Returns
+    ///
+    /// One [`TranslatedAlignment`] per pair, truncated to `min(dna_queries.len(),
+    /// protein_references.len())`.
+    pub fn compute_translated_alignments<T, S, U, P>(
+        &self,
+        dna_queries: T,
+        protein_references: U,
+        code: &GeneticCode,
+    ) -> Vec<TranslatedAlignment>
+    where
+        T: AsRef<[S]>,
+        S: AsRef<[u8]>,
+        U: AsRef<[P]>,
+        P: AsRef<[u8]>,
+    {
+        let dna_slice = dna_queries.as_ref();
+        let protein_slice = protein_references.as_ref();
+        let num_pairs = dna_slice.len().min(protein_slice.len());
+
+        (0..num_pairs)
+            .map(|idx| {
+                best_translated_alignment(
+                    dna_slice[idx].as_ref(),
+                    protein_slice[idx].as_ref(),
+                    code,
+                    &self.substitution_matrix,
+                    self.open_cost,
+                    self.extend_cost,
+                    self.mode,
+                )
+            })
+            .collect()
+    }
+}
+
+impl Drop for SmithWatermanScores {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            unsafe { szs_smith_waterman_scores_free(self.handle) };
+        }
+    }
+}
+
+unsafe impl Send for SmithWatermanScores {}
+unsafe impl Sync for SmithWatermanScores {}
+
+/// Batch Hamming distance engine for fixed-width byte records.
+///
+/// Unlike [`LevenshteinDistances`], [`NeedlemanWunschScores`], and [`SmithWatermanScores`], this
+/// engine has no native CPU/GPU kernel in the underlying C library yet, so it is implemented
+/// directly in Rust: the byte-wise mode counts differing positions with a straight-line,
+/// auto-vectorizable scan, and [`HammingDistances::compute_packed`] treats each record as a
+/// bit-packed fingerprint and does XOR + popcount over whole machine words, in the spirit of
+/// SimSIMD's binary-vector distance kernels. Because of that, GPU device scopes are rejected with
+/// [`SzStatus::MissingGpu`] rather than silently falling back to the CPU path.
+///
+/// # Examples
+///
+/// ```rust
+/// # use stringzilla::szs::{DeviceScope, HammingDistances};
+/// let device = DeviceScope::default().unwrap();
+/// let engine = HammingDistances::new(&device).unwrap();
+///
+/// let strings_a = vec!["karolin", "kathrin"];
+/// let strings_b = vec!["kathrin", "kerstin"];
+/// let distances = engine.compute(&strings_a, &strings_b).unwrap();
+/// assert_eq!(&distances[..], &[3, 4]);
+/// ```
+pub struct HammingDistances;
+
+impl HammingDistances {
+    /// Create a new Hamming distance engine.
+    ///
+    /// Returns [`Error`] wrapping [`SzStatus::MissingGpu`] if `device` is a GPU scope, since this
+    /// engine only ever runs on the CPU.
+    pub fn new(device: &DeviceScope) -> Result<Self, Error> {
+        if device.is_gpu() {
+            return Err(Error::from(SzStatus::MissingGpu));
+        }
+        Ok(Self)
+    }
+
+    /// Computes byte-wise Hamming distances between aligned records.
+    ///
+    /// Pairs records by index: `(a[0], b[0])`, `(a[1], b[1])`, etc. Result length equals
+    /// `min(records_a.len(), records_b.len())`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] wrapping [`SzStatus::UnexpectedDimensions`] if any paired records have
+    /// different lengths, since Hamming distance is only defined for equal-length records.
+    pub fn compute<T, S>(&self, records_a: T, records_b: T) -> Result<UnifiedVec<usize>, Error>
+    where
+        T: AsRef<[S]>,
+        S: AsRef<[u8]>,
+    {
+        let records_a = records_a.as_ref();
+        let records_b = records_b.as_ref();
+        let num_pairs = records_a.len().min(records_b.len());
+
+        let mut results = UnifiedVec::with_capacity_in(num_pairs, UnifiedAlloc);
+        for (record_a, record_b) in records_a.iter().zip(records_b).take(num_pairs) {
+            let record_a = record_a.as_ref();
+            let record_b = record_b.as_ref();
+            if record_a.len() != record_b.len() {
+                return Err(Error::from(SzStatus::UnexpectedDimensions));
+            }
+            let distance = record_a.iter().zip(record_b).filter(|(a, b)| a != b).count();
+            results.push(distance);
+        }
+        Ok(results)
+    }
+
+    /// Computes Hamming distances between bit-packed, fixed-width records using XOR + popcount.
+    ///
+    /// `records_a`/`records_b` are flat buffers holding back-to-back fingerprints, each exactly
+    /// `stride_bytes` long (as produced by [`Fingerprints::compute`] or any other fixed-width
+    /// bit/byte encoding). Each record is scanned in 8-byte words via [`u64::count_ones`] with the
+    /// remainder handled byte by byte, which the compiler auto-vectorizes into wide XOR+popcount
+    /// lanes on CPUs with SIMD popcount support.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] wrapping [`SzStatus::UnexpectedDimensions`] if `stride_bytes == 0`, or if
+    /// either buffer's length isn't an exact multiple of `stride_bytes`.
+    pub fn compute_packed(
+        &self,
+        records_a: &[u8],
+        records_b: &[u8],
+        stride_bytes: usize,
+    ) -> Result<UnifiedVec<usize>, Error> {
+        if stride_bytes == 0 || records_a.len() % stride_bytes != 0 || records_b.len() % stride_bytes != 0 {
+            return Err(Error::from(SzStatus::UnexpectedDimensions));
+        }
+        let num_pairs = (records_a.len() / stride_bytes).min(records_b.len() / stride_bytes);
+
+        let mut results = UnifiedVec::with_capacity_in(num_pairs, UnifiedAlloc);
+        for pair_index in 0..num_pairs {
+            let record_a = &records_a[pair_index * stride_bytes..(pair_index + 1) * stride_bytes];
+            let record_b = &records_b[pair_index * stride_bytes..(pair_index + 1) * stride_bytes];
+
+            let mut distance = 0usize;
+            let mut offset = 0usize;
+            while offset + 8 <= stride_bytes {
+                let word_a = u64::from_le_bytes(record_a[offset..offset + 8].try_into().unwrap());
+                let word_b = u64::from_le_bytes(record_b[offset..offset + 8].try_into().unwrap());
+                distance += (word_a ^ word_b).count_ones() as usize;
+                offset += 8;
+            }
+            while offset < stride_bytes {
+                distance += (record_a[offset] ^ record_b[offset]).count_ones() as usize;
+                offset += 1;
+            }
+            results.push(distance);
+        }
+        Ok(results)
+    }
+}
+
+/// Streaming sort-and-dedup pipeline for huge newline-delimited wordlists.
+///
+/// Like [`HammingDistances`], this pipeline has no native CPU/GPU kernel of its own: it chunks
+/// the input into `chunk_records`-sized runs, sorts each run in place with
+/// [`crate::stringzilla::argsort_permutation`] (the crate's existing SIMD-accelerated comparison
+/// sort), and then k-way merges the sorted runs with a binary heap, collapsing duplicate lines as
+/// they are merged. Every sorted run stays in memory until the merge ends; beyond the runs, the
+/// merge holds only one line per run in its heap, so the full wordlist is never duplicated into a
+/// second fully-sorted copy in RAM. This is
+/// the common preprocessing step for turning raw wordlists into sorted, deduplicated rule-based
+/// cracking dictionaries and training corpora.
+///
+/// # Examples
+///
+/// ```rust
+/// # use stringzilla::szs::{DeviceScope, WordlistDedup};
+/// let device = DeviceScope::default().unwrap();
+/// let pipeline = WordlistDedup::new(&device).unwrap();
+///
+/// let input = b"banana\napple\nbanana\ncherry\napple\n";
+/// let mut output = Vec::new();
+/// let unique_lines = pipeline.dedup_sorted(&input[..], &mut output, false).unwrap();
+/// assert_eq!(unique_lines, 3);
+/// assert_eq!(output, b"apple\nbanana\ncherry\n");
+/// ```
+pub struct WordlistDedup {
+    chunk_records: usize,
+}
+
+impl WordlistDedup {
+    /// Number of lines sorted together per run when no explicit chunk size is given.
+    const DEFAULT_CHUNK_RECORDS: usize = 1 << 20;
+
+    /// Creates a pipeline that sorts [`Self::DEFAULT_CHUNK_RECORDS`] lines at a time before merging.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] wrapping [`SzStatus::MissingGpu`] if `device` is a GPU scope, since this
+    /// pipeline only ever runs on the CPU.
+    pub fn new(device: &DeviceScope) -> Result<Self, Error> {
+        Self::with_chunk_records(device, Self::DEFAULT_CHUNK_RECORDS)
+    }
+
+    /// Creates a pipeline that sorts `chunk_records` lines at a time before merging.
+    ///
+    /// Every run stays in memory until the merge ends, so the chunk size does not bound peak
+    /// memory use: smaller chunks mean smaller sorts and a wider k-way merge, larger chunks mean
+    /// fewer runs to merge.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] wrapping [`SzStatus::MissingGpu`] if `device` is a GPU scope, since this
+    /// pipeline only ever runs on the CPU.
+    pub fn with_chunk_records(device: &DeviceScope, chunk_records: usize) -> Result<Self, Error> {
+        if device.is_gpu() {
+            return Err(Error::from(SzStatus::MissingGpu));
+        }
+        Ok(Self {
+            chunk_records: chunk_records.max(1),
+        })
+    }
+
+    /// Reads newline-delimited records from `reader`, sorts and deduplicates them, and writes the
+    /// sorted unique lines (each still newline-terminated) to `writer`.
+    ///
+    /// Returns the number of unique lines written. If `with_counts` is set, each output line is
+    /// followed by a tab and the number of times it occurred in the input, e.g. `"apple\t2\n"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any [`std::io::Error`] raised while reading from `reader` or writing to `writer`.
+    pub fn dedup_sorted<R: std::io::BufRead, W: std::io::Write>(
+        &self,
+        mut reader: R,
+        mut writer: W,
+        with_counts: bool,
+    ) -> std::io::Result<usize> {
+        // Phase 1: split the input into chunk_records-sized runs, each sorted independently.
+        let mut runs: Vec<Vec<Vec<u8>>> = Vec::new();
+        let mut chunk: Vec<Vec<u8>> = Vec::with_capacity(self.chunk_records);
+        loop {
+            let mut line = Vec::new();
+            if reader.read_until(b'\n', &mut line)? == 0 {
+                break;
+            }
+            if line.last() == Some(&b'\n') {
+                line.pop();
+                if line.last() == Some(&b'\r') {
+                    line.pop();
+                }
+            }
+            chunk.push(line);
+            if chunk.len() >= self.chunk_records {
+                runs.push(Self::sort_chunk(core::mem::take(&mut chunk)));
+                chunk = Vec::with_capacity(self.chunk_records);
+            }
+        }
+        if !chunk.is_empty() {
+            runs.push(Self::sort_chunk(chunk));
+        }
+
+        // Phase 2: k-way merge the sorted runs, deduplicating and counting lines on the fly.
+        let mut cursors = vec![0usize; runs.len()];
+        let mut heap = std::collections::BinaryHeap::with_capacity(runs.len());
+        for (run_index, run) in runs.iter().enumerate() {
+            if let Some(first) = run.first() {
+                heap.push(core::cmp::Reverse(_WordlistHeapEntry { line: first.clone(), run_index }));
+            }
+        }
+
+        let mut unique_lines = 0usize;
+        let mut pending: Option<(Vec<u8>, usize)> = None;
+        while let Some(core::cmp::Reverse(_WordlistHeapEntry { line, run_index })) = heap.pop() {
+            cursors[run_index] += 1;
+            if let Some(next) = runs[run_index].get(cursors[run_index]) {
+                heap.push(core::cmp::Reverse(_WordlistHeapEntry { line: next.clone(), run_index }));
+            }
+
+            pending = match pending.take() {
+                Some((current, count)) if current == line => Some((current, count + 1)),
+                Some((current, count)) => {
+                    Self::write_line(&mut writer, &current, count, with_counts)?;
+                    unique_lines += 1;
+                    Some((line, 1))
+                }
+                None => Some((line, 1)),
+            };
+        }
+        if let Some((current, count)) = pending {
+            Self::write_line(&mut writer, &current, count, with_counts)?;
+            unique_lines += 1;
+        }
+
+        Ok(unique_lines)
+    }
+
+    /// Sorts one in-memory run with the crate's SIMD argsort, falling back to a plain comparison
+    /// sort only if the FFI call reports an allocation failure.
+    fn sort_chunk(chunk: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
+        let mut order = vec![0usize; chunk.len()];
+        if crate::stringzilla::argsort_permutation(&chunk, &mut order).is_err() {
+            let mut sorted = chunk;
+            sorted.sort();
+            return sorted;
+        }
+        let mut slots: Vec<Option<Vec<u8>>> = chunk.into_iter().map(Some).collect();
+        order.into_iter().map(|idx| slots[idx].take().unwrap()).collect()
+    }
+
+    fn write_line<W: std::io::Write>(writer: &mut W, line: &[u8], count: usize, with_counts: bool) -> std::io::Result<()> {
+        writer.write_all(line)?;
+        if with_counts {
+            write!(writer, "\t{count}")?;
+        }
+        writer.write_all(b"\n")
+    }
+}
+
+/// Min-heap entry used by [`WordlistDedup::dedup_sorted`]'s k-way merge: orders by line first so
+/// equal lines from different runs sort adjacent to each other regardless of which run they came
+/// from.
+struct _WordlistHeapEntry {
+    line: Vec<u8>,
+    run_index: usize,
+}
+
+impl PartialEq for _WordlistHeapEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.line == other.line
+    }
+}
+
+impl Eq for _WordlistHeapEntry {}
+
+impl PartialOrd for _WordlistHeapEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for _WordlistHeapEntry {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.line.cmp(&other.line)
+    }
+}
+
+/// Builder for configuring fingerprinting engines with optimal parameters.
+///
+/// Provides preset configurations for common use cases and allows fine-tuning
+/// of parameters for specific applications.
+///
+/// # Examples
+///
+/// ```rust
+/// # use stringzilla::szs::{Fingerprints, DeviceScope};
+/// let device = DeviceScope::default().unwrap();
+///
+/// // DNA sequence analysis
+/// let dna_engine = Fingerprints::builder()
+///     .dna()
+///     .dimensions(256)
+///     .build(&device)
+///     .unwrap();
+///
+/// // Text processing
+/// let text_engine = Fingerprints::builder()
+///     .ascii()
+///     .dimensions(512)
+///     .build(&device)
+///     .unwrap();
+/// ```
+pub struct FingerprintsBuilder {
+    alphabet_size: usize,
+    window_widths: Option<Vec<usize>>,
+    dimensions: usize,
+    chunking: Option<ContentDefinedChunking>,
+    lsh_bands: Option<usize>,
+    lsh_rows_per_band: Option<usize>,
+    hash_family: HashFamily,
+}
+
+impl FingerprintsBuilder {
+    /// Create a new builder with system-optimized defaults.
+    ///
+    /// Uses intelligent defaults that adapt to available hardware capabilities:
+    /// - Alphabet size: 256 (suitable for binary data and most text)
+    /// - Window widths: Hardware-optimized selection
+    /// - Dimensions: 1024 (balances accuracy and performance)
+    ///
+    /// # Returns
+    ///
+    /// - `Self`: New builder with defaults
+    ///
+    /// # Examples
     ///
     /// ```rust
     /// # use stringzilla::szs::FingerprintsBuilder;
@@ -2045,6 +4569,10 @@ impl FingerprintsBuilder {
             alphabet_size: 0,
             window_widths: None,
             dimensions: 1024, // Default dimensions
+            chunking: None,
+            lsh_bands: None,
+            lsh_rows_per_band: None,
+            hash_family: HashFamily::Auto,
         }
     }
 
@@ -2352,6 +4880,112 @@ impl FingerprintsBuilder {
         self
     }
 
+    /// Enable content-defined chunking for fingerprinting a single long document.
+    ///
+    /// Instead of hashing the whole input as one string, [`Fingerprints::compute_chunks`] first
+    /// splits it into variable-length pieces at data-dependent boundaries (via
+    /// [`content_defined_chunk_offsets`]'s GEAR rolling hash) and MinHashes each piece on its own.
+    /// Because boundaries are chosen by content rather than by fixed offset, inserting or deleting
+    /// bytes in one region only reshuffles the chunks touching that region — the rest of the
+    /// document's fingerprint is unaffected, unlike a fixed-window scheme where every window past
+    /// the edit shifts. This is the natural complement to [`Self::window_widths`]'s n-gram
+    /// machinery for documents too large, or too edit-prone, to fingerprint as a single string.
+    ///
+    /// Chunk lengths are clamped to `[avg_size / 4, avg_size * 4]` so pathological inputs (runs of
+    /// a single repeated byte, for example) still produce bounded chunks. Use
+    /// [`Self::content_defined_chunks_bounded`] to set the bounds explicitly.
+    ///
+    /// # Returns
+    ///
+    /// - `Self`: Updated builder
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use stringzilla::szs::{Fingerprints, DeviceScope};
+    /// let device = DeviceScope::default().unwrap();
+    /// let engine = Fingerprints::builder()
+    ///     .binary()
+    ///     .dimensions(128)
+    ///     .content_defined_chunks(4096) // ~4KB chunks
+    ///     .build(&device)
+    ///     .unwrap();
+    /// ```
+    pub fn content_defined_chunks(self, avg_size: usize) -> Self {
+        self.content_defined_chunks_bounded(avg_size, (avg_size / 4).max(1), avg_size * 4)
+    }
+
+    /// Like [`Self::content_defined_chunks`], but with explicit `[min_size, max_size]` clamps
+    /// instead of the `avg_size / 4` / `avg_size * 4` defaults.
+    ///
+    /// # Returns
+    ///
+    /// - `Self`: Updated builder
+    pub fn content_defined_chunks_bounded(mut self, avg_size: usize, min_size: usize, max_size: usize) -> Self {
+        self.chunking = Some(ContentDefinedChunking { avg_size, min_size, max_size });
+        self
+    }
+
+    /// Configure the number of LSH bands used by [`Fingerprints::candidate_pairs`] to bucket
+    /// signatures into near-duplicate clusters without an all-pairs scan. `dimensions` must be
+    /// evenly divisible by `bands`; [`Self::build`] rejects the configuration otherwise.
+    ///
+    /// More bands (fewer rows per band) raise recall at the cost of precision -- the banding
+    /// collision probability for two signatures of true Jaccard similarity `s` is approximately
+    /// `1 - (1 - s^r)^b`, where `r = dimensions / bands`. See [`Self::rows_per_band`] to configure
+    /// the same tradeoff from the other side.
+    ///
+    /// # Returns
+    ///
+    /// - `Self`: Updated builder
+    pub fn bands(mut self, bands: usize) -> Self {
+        self.lsh_bands = Some(bands);
+        self
+    }
+
+    /// Configure the number of rows (signature lanes) per LSH band, as an alternative to
+    /// [`Self::bands`]. `dimensions` must be evenly divisible by `rows_per_band`.
+    ///
+    /// Fewer rows per band raise recall at the cost of precision -- see [`Self::bands`] for the
+    /// collision-probability formula.
+    ///
+    /// # Returns
+    ///
+    /// - `Self`: Updated builder
+    pub fn rows_per_band(mut self, rows_per_band: usize) -> Self {
+        self.lsh_rows_per_band = Some(rows_per_band);
+        self
+    }
+
+    /// Choose the permutation/hash function family used to seed the MinHash lanes.
+    ///
+    /// Defaults to [`HashFamily::Auto`], which picks [`HashFamily::Aes`] on CPUs that expose
+    /// AES-NI (see [`CAPABILITY_AES`]) and falls back to [`HashFamily::RollingHash`] everywhere
+    /// else -- no configuration needed for the common case. Request a specific family to pin the
+    /// behavior instead: [`Self::build`] fails with [`SzStatus::DeviceCodeMismatch`] if the target
+    /// [`DeviceScope`] can't support it.
+    ///
+    /// # Returns
+    ///
+    /// - `Self`: Updated builder
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use stringzilla::szs::{Fingerprints, DeviceScope, HashFamily};
+    /// let device = DeviceScope::default().unwrap();
+    /// let engine = Fingerprints::builder()
+    ///     .ascii()
+    ///     .dimensions(256)
+    ///     .hash_family(HashFamily::Aes)
+    ///     .build(&device)
+    ///     .unwrap();
+    /// ```
+    pub fn hash_family(mut self, family: HashFamily) -> Self {
+        self.hash_family = family;
+        self
+    }
+
     /// Build the fingerprinting engine with the configured parameters.
     ///
     /// Creates an optimized fingerprinting engine based on the builder configuration
@@ -2388,8 +5022,36 @@ impl FingerprintsBuilder {
     /// assert!(result.is_ok());
     /// ```
     pub fn build(self, device: &DeviceScope) -> Result<Fingerprints, Error> {
+        let lsh_bands = match (self.lsh_bands, self.lsh_rows_per_band) {
+            (Some(bands), Some(rows_per_band)) => {
+                if bands == 0 || rows_per_band == 0 || bands * rows_per_band != self.dimensions {
+                    return Err(Error::from(SzStatus::UnexpectedDimensions));
+                }
+                Some(bands)
+            }
+            (Some(bands), None) => {
+                if bands == 0 || self.dimensions % bands != 0 {
+                    return Err(Error::from(SzStatus::UnexpectedDimensions));
+                }
+                Some(bands)
+            }
+            (None, Some(rows_per_band)) => {
+                if rows_per_band == 0 || self.dimensions % rows_per_band != 0 {
+                    return Err(Error::from(SzStatus::UnexpectedDimensions));
+                }
+                Some(self.dimensions / rows_per_band)
+            }
+            (None, None) => None,
+        };
+
         let mut engine: FingerprintsHandle = ptr::null_mut();
         let capabilities = device.get_capabilities().unwrap_or(0);
+        let has_aes = capabilities & CAPABILITY_AES != 0;
+        let hash_family = match self.hash_family {
+            HashFamily::Auto => if has_aes { HashFamily::Aes } else { HashFamily::RollingHash },
+            HashFamily::Aes if !has_aes => return Err(Error::from(SzStatus::DeviceCodeMismatch)),
+            explicit => explicit,
+        };
 
         let (widths_ptr, widths_len) = match &self.window_widths {
             Some(widths) => (widths.as_ptr(), widths.len()),
@@ -2405,18 +5067,52 @@ impl FingerprintsBuilder {
                 widths_len,
                 ptr::null(), // No custom allocator
                 capabilities,
+                hash_family as u32,
                 &mut engine,
                 &mut error_msg,
             )
         };
 
         match status {
-            Status::Success => Ok(Fingerprints { handle: engine }),
+            Status::Success => Ok(Fingerprints { handle: engine, chunking: self.chunking, lsh_bands }),
             err => Err(rust_error_from_c_message(err, error_msg)),
         }
     }
 }
 
+/// Permutation/hash function family used to seed the MinHash lanes, selected via
+/// [`FingerprintsBuilder::hash_family`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(u32)]
+pub enum HashFamily {
+    /// Picks [`Self::Aes`] on CPUs that expose [`CAPABILITY_AES`], falling back to
+    /// [`Self::RollingHash`] otherwise. Never reaches the native engine as-is -- resolved to one
+    /// of the other two variants in [`FingerprintsBuilder::build`].
+    #[default]
+    Auto = 0,
+    /// The scalar/SIMD rolling hash already used to compute window n-grams. Portable, no
+    /// hardware prerequisites.
+    RollingHash = 1,
+    /// An AES-instruction-backed mixer: one or two AES round instructions combine the rolling
+    /// window value with a per-lane key, giving very high-quality, low-collision hashing at
+    /// near-memcpy throughput. Improves Jaccard-estimate accuracy at low
+    /// [`FingerprintsBuilder::dimensions`]. Requires [`CAPABILITY_AES`]; requesting it explicitly
+    /// on a [`DeviceScope`] without that capability fails [`FingerprintsBuilder::build`] with
+    /// [`SzStatus::DeviceCodeMismatch`].
+    Aes = 2,
+}
+
+/// Content-defined chunking parameters configured via
+/// [`FingerprintsBuilder::content_defined_chunks`], consumed by
+/// [`Fingerprints::compute_chunks`]. See [`content_defined_chunk_offsets`] for the GEAR
+/// rolling-hash algorithm used to find chunk boundaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentDefinedChunking {
+    avg_size: usize,
+    min_size: usize,
+    max_size: usize,
+}
+
 /// High-performance fingerprinting engine for similarity detection and clustering.
 ///
 /// Computes Min-Hash signatures and Count-Min-Sketch data structures for efficient
@@ -2442,6 +5138,8 @@ impl FingerprintsBuilder {
 /// ```
 pub struct Fingerprints {
     handle: FingerprintsHandle,
+    chunking: Option<ContentDefinedChunking>,
+    lsh_bands: Option<usize>,
 }
 
 impl Fingerprints {
@@ -2652,39 +5350,693 @@ impl Fingerprints {
                     )
                 }
             }
-            AnyBytesTape::View32(vv) => {
-                let v = SzSequenceU32Tape::from(vv);
-                unsafe {
-                    szs_fingerprints_u32tape(
-                        self.handle,
-                        device.handle,
-                        &v as *const _ as *const c_void,
-                        min_hashes.as_mut_ptr(),
-                        hashes_stride,
-                        min_counts.as_mut_ptr(),
-                        counts_stride,
-                        &mut error_msg,
-                    )
+            AnyBytesTape::View32(vv) => {
+                let v = SzSequenceU32Tape::from(vv);
+                unsafe {
+                    szs_fingerprints_u32tape(
+                        self.handle,
+                        device.handle,
+                        &v as *const _ as *const c_void,
+                        min_hashes.as_mut_ptr(),
+                        hashes_stride,
+                        min_counts.as_mut_ptr(),
+                        counts_stride,
+                        &mut error_msg,
+                    )
+                }
+            }
+        };
+        match status {
+            Status::Success => Ok(()),
+            err => Err(rust_error_from_c_message(err, error_msg)),
+        }
+    }
+
+    /// Submits Min-Hash and Count-Min-Sketch computation without blocking.
+    ///
+    /// Mirrors [`Self::compute_into`]'s tape dispatch, but enqueues the batch on `device` and
+    /// returns immediately with a [`Completion`] instead of waiting for it to finish.
+    /// `min_hashes`/`min_counts` stay borrowed for the `Completion`'s lifetime and must not be
+    /// read until [`Completion::wait`] (or a successful [`Completion::poll`]) confirms the batch
+    /// is done.
+    ///
+    /// Requirements and errors are the same as [`Self::compute_into`].
+    pub fn submit_into<'a>(
+        &self,
+        device: &DeviceScope,
+        texts: AnyBytesTape<'a>,
+        dimensions: usize,
+        min_hashes: &'a mut UnifiedVec<u32>,
+        min_counts: &'a mut UnifiedVec<u32>,
+    ) -> Result<Completion<'a>, Error> {
+        let mut error_msg: *const c_char = ptr::null();
+        let count = match &texts {
+            AnyBytesTape::Tape64(t) => SzSequenceU64Tape::from(t).count,
+            AnyBytesTape::View64(v) => SzSequenceU64Tape::from(v).count,
+            AnyBytesTape::Tape32(t) => SzSequenceU32Tape::from(t).count,
+            AnyBytesTape::View32(v) => SzSequenceU32Tape::from(v).count,
+        };
+        let need = count * dimensions;
+        if min_hashes.len() < need || min_counts.len() < need {
+            return Err(Error::from(SzStatus::UnexpectedDimensions));
+        }
+        let hashes_stride = dimensions * core::mem::size_of::<u32>();
+        let counts_stride = dimensions * core::mem::size_of::<u32>();
+        let mut event: *mut c_void = ptr::null_mut();
+        let status = match &texts {
+            AnyBytesTape::Tape64(t) => {
+                let v = SzSequenceU64Tape::from(t);
+                unsafe {
+                    szs_fingerprints_submit_u64tape(
+                        self.handle,
+                        device.handle,
+                        &v as *const _ as *const c_void,
+                        min_hashes.as_mut_ptr(),
+                        hashes_stride,
+                        min_counts.as_mut_ptr(),
+                        counts_stride,
+                        &mut event,
+                        &mut error_msg,
+                    )
+                }
+            }
+            AnyBytesTape::View64(vv) => {
+                let v = SzSequenceU64Tape::from(vv);
+                unsafe {
+                    szs_fingerprints_submit_u64tape(
+                        self.handle,
+                        device.handle,
+                        &v as *const _ as *const c_void,
+                        min_hashes.as_mut_ptr(),
+                        hashes_stride,
+                        min_counts.as_mut_ptr(),
+                        counts_stride,
+                        &mut event,
+                        &mut error_msg,
+                    )
+                }
+            }
+            AnyBytesTape::Tape32(t) => {
+                let v = SzSequenceU32Tape::from(t);
+                unsafe {
+                    szs_fingerprints_submit_u32tape(
+                        self.handle,
+                        device.handle,
+                        &v as *const _ as *const c_void,
+                        min_hashes.as_mut_ptr(),
+                        hashes_stride,
+                        min_counts.as_mut_ptr(),
+                        counts_stride,
+                        &mut event,
+                        &mut error_msg,
+                    )
+                }
+            }
+            AnyBytesTape::View32(vv) => {
+                let v = SzSequenceU32Tape::from(vv);
+                unsafe {
+                    szs_fingerprints_submit_u32tape(
+                        self.handle,
+                        device.handle,
+                        &v as *const _ as *const c_void,
+                        min_hashes.as_mut_ptr(),
+                        hashes_stride,
+                        min_counts.as_mut_ptr(),
+                        counts_stride,
+                        &mut event,
+                        &mut error_msg,
+                    )
+                }
+            }
+        };
+        match status {
+            Status::Success => Ok(Completion::new(event)),
+            err => Err(rust_error_from_c_message(err, error_msg)),
+        }
+    }
+
+    /// Submits Min-Hash and Count-Min-Sketch computation without blocking, returning an owned
+    /// [`JobHandle`] instead of the borrowed [`Completion`] that [`Self::submit_into`] returns.
+    ///
+    /// Unlike `submit_into`, `submit` builds and owns its own tape and result buffers --
+    /// mirroring [`Self::compute`]'s allocation -- so the returned [`JobHandle`] can be moved
+    /// freely, e.g. `.await`ed under an async runtime or handed to another thread, instead of
+    /// being tied to a borrow of caller-owned state. Resolves to `(min_hashes, min_counts)`, just
+    /// like [`Self::compute`].
+    ///
+    /// # Errors
+    ///
+    /// Fails immediately (without spawning a background worker) if building the tape or
+    /// enqueuing the batch on `device` fails; errors from the batch itself surface from
+    /// [`JobHandle::join`] or the `Future` impl instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use stringzilla::szs::{DeviceScope, Fingerprints};
+    /// let device = DeviceScope::default().unwrap();
+    /// let engine = Fingerprints::builder().dimensions(64).build(&device).unwrap();
+    /// let job = engine.submit(&device, &["hello world", "hello word"], 64).unwrap();
+    /// let (hashes, _counts) = job.join().unwrap();
+    /// assert_eq!(hashes.len(), 2 * 64);
+    /// ```
+    pub fn submit<T, S>(
+        &self,
+        device: &DeviceScope,
+        strings: T,
+        dimensions: usize,
+    ) -> Result<JobHandle<(Box<UnifiedVec<u32>>, Box<UnifiedVec<u32>>)>, Error>
+    where
+        T: AsRef<[S]>,
+        S: AsRef<[u8]>,
+    {
+        let strings_slice = strings.as_ref();
+        let hashes_size = strings_slice.len() * dimensions;
+
+        let mut min_hashes = Box::new(UnifiedVec::with_capacity_in(hashes_size, UnifiedAlloc));
+        min_hashes.resize(hashes_size, 0);
+        let mut min_counts = Box::new(UnifiedVec::with_capacity_in(hashes_size, UnifiedAlloc));
+        min_counts.resize(hashes_size, 0);
+
+        // For fingerprints we only have one collection, so estimate if it needs 64-bit offsets.
+        let total_size: usize = strings_slice.iter().map(|s| s.as_ref().len()).sum();
+        let force_64bit = total_size > u32::MAX as usize || strings_slice.len() > u32::MAX as usize;
+        let texts = copy_bytes_into_tape(strings_slice, force_64bit)?;
+
+        // SAFETY: see the matching comment in `LevenshteinDistances::submit`.
+        let hashes_ptr: *mut UnifiedVec<u32> = Box::as_mut(&mut min_hashes);
+        let counts_ptr: *mut UnifiedVec<u32> = Box::as_mut(&mut min_counts);
+        let hashes_ref: &'static mut UnifiedVec<u32> = unsafe { &mut *hashes_ptr };
+        let counts_ref: &'static mut UnifiedVec<u32> = unsafe { &mut *counts_ptr };
+        let completion = self.submit_into(device, texts, dimensions, hashes_ref, counts_ref)?;
+
+        Ok(JobHandle::spawn((min_hashes, min_counts), completion))
+    }
+}
+
+impl Fingerprints {
+    /// Borrows the `dimensions`-wide Min-Hash signature for string `row` out of the flat buffer
+    /// returned by [`Self::compute`] (or filled by [`Self::compute_into`]), so callers don't have
+    /// to re-derive the `row * dimensions` indexing by hand.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use stringzilla::szs::{Fingerprints, DeviceScope};
+    /// let device = DeviceScope::default().unwrap();
+    /// let engine = Fingerprints::builder().dimensions(64).build(&device).unwrap();
+    /// let (hashes, _counts) = engine.compute(&device, &["hello world", "hello word"], 64).unwrap();
+    /// let first = Fingerprints::signature(&hashes, 64, 0);
+    /// assert_eq!(first.len(), 64);
+    /// ```
+    pub fn signature(hashes: &[u32], dimensions: usize, row: usize) -> &[u32] {
+        &hashes[row * dimensions..(row + 1) * dimensions]
+    }
+
+    /// Counts the Min-Hash lanes where `signature_a` and `signature_b` agree: the raw,
+    /// unnormalized count behind [`Self::jaccard_similarity`]'s ratio, mirroring the
+    /// absolute-score/normalized-ratio pair convention used by gear-fingerprint similarity scores.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two signatures have different lengths.
+    pub fn matching_dimensions(signature_a: &[u32], signature_b: &[u32]) -> usize {
+        assert_eq!(
+            signature_a.len(),
+            signature_b.len(),
+            "Min-Hash signatures must share the same dimensions to compare"
+        );
+        signature_a.iter().zip(signature_b).filter(|(a, b)| a == b).count()
+    }
+
+    /// Estimates the Jaccard similarity between two equal-length Min-Hash signatures as the
+    /// fraction of matching lanes (see [`Self::matching_dimensions`]), in `[0.0, 1.0]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use stringzilla::szs::Fingerprints;
+    /// let a = [1u32, 2, 3, 4];
+    /// let b = [1u32, 2, 30, 40];
+    /// assert_eq!(Fingerprints::jaccard_similarity(&a, &b), 0.5);
+    /// ```
+    pub fn jaccard_similarity(signature_a: &[u32], signature_b: &[u32]) -> f64 {
+        if signature_a.is_empty() {
+            return 0.0;
+        }
+        Self::matching_dimensions(signature_a, signature_b) as f64 / signature_a.len() as f64
+    }
+
+    /// Splits a single long `document` into content-defined chunks (per the engine's
+    /// [`FingerprintsBuilder::content_defined_chunks`] configuration) and computes a MinHash
+    /// signature for each chunk independently, so a small edit to `document` only changes the
+    /// fingerprint of the chunk(s) touching it.
+    ///
+    /// # Returns
+    ///
+    /// `(min_hashes, min_counts, offsets)` where `min_hashes`/`min_counts` are laid out exactly
+    /// like [`Self::compute`]'s output (`num_chunks × dimensions`), and `offsets` are the
+    /// CSR-style chunk boundaries into `document` returned by [`content_defined_chunk_offsets`]
+    /// (`document[offsets[i]..offsets[i + 1]]` is the `i`-th chunk).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] wrapping [`SzStatus::UnexpectedDimensions`] if the engine was built
+    /// without [`FingerprintsBuilder::content_defined_chunks`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use stringzilla::szs::{Fingerprints, DeviceScope};
+    /// let device = DeviceScope::default().unwrap();
+    /// let engine = Fingerprints::builder()
+    ///     .dimensions(64)
+    ///     .content_defined_chunks(16)
+    ///     .build(&device)
+    ///     .unwrap();
+    /// let document = "the quick brown fox jumps over the lazy dog, again and again and again";
+    /// let (hashes, _counts, offsets) = engine.compute_chunks(&device, document, 64).unwrap();
+    /// assert_eq!(hashes.len(), (offsets.len() - 1) * 64);
+    /// ```
+    pub fn compute_chunks<S: AsRef<[u8]>>(
+        &self,
+        device: &DeviceScope,
+        document: S,
+        dimensions: usize,
+    ) -> Result<(UnifiedVec<u32>, UnifiedVec<u32>, Vec<usize>), Error> {
+        let chunking = self
+            .chunking
+            .ok_or_else(|| Error::from(SzStatus::UnexpectedDimensions))?;
+        let bytes = document.as_ref();
+        let offsets = content_defined_chunk_offsets(bytes, chunking.avg_size, chunking.min_size, chunking.max_size);
+        let chunks: Vec<&[u8]> = offsets.windows(2).map(|w| &bytes[w[0]..w[1]]).collect();
+        let (hashes, counts) = self.compute(device, &chunks, dimensions)?;
+        Ok((hashes, counts, offsets))
+    }
+
+    /// Condenses a Min-Hash `signature` row (as produced by [`Self::compute`] and sliced out with
+    /// [`Self::signature`]) into a fixed-width [`Fingerprint128`], by hashing the signature's
+    /// bytes twice with different seeds for the two 64-bit halves.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use stringzilla::szs::Fingerprints;
+    /// let a = [1u32, 2, 3, 4];
+    /// let b = [1u32, 2, 3, 4];
+    /// assert_eq!(Fingerprints::condense(&a), Fingerprints::condense(&b));
+    /// ```
+    pub fn condense(signature: &[u32]) -> Fingerprint128 {
+        let mut bytes = Vec::with_capacity(signature.len() * core::mem::size_of::<u32>());
+        for word in signature {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        let first_half = crate::stringzilla::hash_with_seed(&bytes, 0);
+        let second_half = crate::stringzilla::hash_with_seed(&bytes, 1);
+        Fingerprint128(first_half, second_half)
+    }
+
+    /// Buckets `signatures` (laid out like [`Self::compute`]'s output, `num_signatures ×
+    /// dimensions`) with LSH banding and returns every candidate near-duplicate pair, using the
+    /// band count configured via [`FingerprintsBuilder::bands`] /
+    /// [`FingerprintsBuilder::rows_per_band`]. See [`lsh_candidate_pairs`] for the banding
+    /// algorithm this delegates to.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] wrapping [`SzStatus::UnexpectedDimensions`] if the engine was built
+    /// without `bands`/`rows_per_band`, if `dimensions` doesn't divide evenly by the configured
+    /// band count, or if `signatures.len()` isn't a multiple of `dimensions`.
+    pub fn candidate_pairs(&self, signatures: &[u32], dimensions: usize, seed: u64) -> Result<Vec<CandidatePair>, Error> {
+        let bands = self.lsh_bands.ok_or_else(|| Error::from(SzStatus::UnexpectedDimensions))?;
+        if dimensions == 0 || bands == 0 || dimensions % bands != 0 {
+            return Err(Error::from(SzStatus::UnexpectedDimensions));
+        }
+        let num_signatures = signatures.len() / dimensions;
+        lsh_candidate_pairs(signatures, num_signatures, dimensions, bands, seed)
+    }
+
+    /// Computes the dense, all-pairs Jaccard similarity matrix over `signatures` (laid out like
+    /// [`Self::compute`]'s output, `num_signatures × dimensions`), so callers don't have to
+    /// hand-loop the `matching_dimensions` counting [`Self::jaccard_similarity`]'s doc comment
+    /// shows for a single pair. `device` is accepted for symmetry with [`Self::compute`] and to
+    /// leave room for a GPU-dispatched implementation of the `O(n^2)` scan on large batches; today
+    /// every entry is computed on the host.
+    ///
+    /// Returns a row-major `num_signatures × num_signatures` matrix, with `1.0` on the diagonal.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] wrapping [`SzStatus::UnexpectedDimensions`] if `dimensions == 0` or
+    /// `signatures.len()` isn't a multiple of `dimensions`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use stringzilla::szs::{Fingerprints, DeviceScope};
+    /// let device = DeviceScope::default().unwrap();
+    /// let engine = Fingerprints::builder().dimensions(64).build(&device).unwrap();
+    /// let (hashes, _counts) = engine.compute(&device, &["abc", "abd", "xyz"], 64).unwrap();
+    /// let matrix = engine.similarity_matrix(&device, &hashes, 64).unwrap();
+    /// assert_eq!(matrix[0 * 3 + 0], 1.0);
+    /// ```
+    pub fn similarity_matrix(&self, _device: &DeviceScope, signatures: &[u32], dimensions: usize) -> Result<Vec<f64>, Error> {
+        if dimensions == 0 || signatures.len() % dimensions != 0 {
+            return Err(Error::from(SzStatus::UnexpectedDimensions));
+        }
+        let num_signatures = signatures.len() / dimensions;
+        let mut matrix = vec![0.0; num_signatures * num_signatures];
+        for i in 0..num_signatures {
+            matrix[i * num_signatures + i] = 1.0;
+            let signature_i = Self::signature(signatures, dimensions, i);
+            for j in (i + 1)..num_signatures {
+                let signature_j = Self::signature(signatures, dimensions, j);
+                let similarity = Self::jaccard_similarity(signature_i, signature_j);
+                matrix[i * num_signatures + j] = similarity;
+                matrix[j * num_signatures + i] = similarity;
+            }
+        }
+        Ok(matrix)
+    }
+
+    /// Finds the `k` nearest neighbors of `query_signature` within `corpus_signatures` (laid out
+    /// like [`Self::compute`]'s output) by Jaccard similarity, without building the full
+    /// [`Self::similarity_matrix`]: every corpus row is scored against the query, then the scores
+    /// are sorted and cut to `k`. `device` is accepted for the same reason as
+    /// [`Self::similarity_matrix`].
+    ///
+    /// Returns up to `k` `(row_index, similarity)` pairs into `corpus_signatures`, sorted by
+    /// descending similarity; fewer than `k` if the corpus itself is smaller.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] wrapping [`SzStatus::UnexpectedDimensions`] if `dimensions == 0`,
+    /// `query_signature.len() != dimensions`, or `corpus_signatures.len()` isn't a multiple of
+    /// `dimensions`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use stringzilla::szs::{Fingerprints, DeviceScope};
+    /// let device = DeviceScope::default().unwrap();
+    /// let engine = Fingerprints::builder().dimensions(64).build(&device).unwrap();
+    /// let (query, _) = engine.compute(&device, &["hello world"], 64).unwrap();
+    /// let (corpus, _) = engine.compute(&device, &["hello word", "goodbye world", "hello world"], 64).unwrap();
+    /// let neighbors = engine.nearest_neighbors(&device, &query, &corpus, 64, 1).unwrap();
+    /// assert_eq!(neighbors[0].0, 2);
+    /// ```
+    pub fn nearest_neighbors(
+        &self,
+        _device: &DeviceScope,
+        query_signature: &[u32],
+        corpus_signatures: &[u32],
+        dimensions: usize,
+        k: usize,
+    ) -> Result<Vec<(usize, f64)>, Error> {
+        if dimensions == 0 || query_signature.len() != dimensions || corpus_signatures.len() % dimensions != 0 {
+            return Err(Error::from(SzStatus::UnexpectedDimensions));
+        }
+        let num_corpus = corpus_signatures.len() / dimensions;
+        let mut scored: Vec<(usize, f64)> = (0..num_corpus)
+            .map(|row| {
+                let candidate = Self::signature(corpus_signatures, dimensions, row);
+                (row, Self::jaccard_similarity(query_signature, candidate))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).expect("similarity scores are always finite"));
+        scored.truncate(k);
+        Ok(scored)
+    }
+}
+
+impl Drop for Fingerprints {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            unsafe { szs_fingerprints_free(self.handle) };
+        }
+    }
+}
+
+unsafe impl Send for Fingerprints {}
+unsafe impl Sync for Fingerprints {}
+
+/// A 128-bit condensation of a Min-Hash signature row, produced by [`Fingerprints::condense`].
+///
+/// A full `dimensions`-wide `u32` signature is too heavy to use as a map key or shard index;
+/// condensing it down to two `u64` halves keeps exact-match dedup and sharding cheap once
+/// [`lsh_candidate_pairs`] (or a full pairwise scan) has already confirmed which rows are
+/// near-duplicates and only a stable, compact identity is needed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Fingerprint128(u64, u64);
+
+impl Fingerprint128 {
+    /// Reduces the 128-bit value to a single `u64` by combining both halves, rather than
+    /// truncating to just one, so that signatures sharing a constant prefix -- documents drawn
+    /// from the same small alphabet, for instance -- still land in well-distributed hash-map
+    /// buckets. Follows the rustc `Fingerprint::to_smaller_hash` convention.
+    pub fn to_smaller_hash(&self) -> u64 {
+        self.0.wrapping_mul(3).wrapping_add(self.1)
+    }
+}
+
+/// A candidate near-duplicate pair surfaced by [`lsh_candidate_pairs`], with its indices into the
+/// original signature batch and its refined, full-signature Jaccard estimate (as opposed to the
+/// coarser single-band hash collision that surfaced the pair in the first place).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CandidatePair {
+    /// Index of the first signature in the batch passed to [`lsh_candidate_pairs`].
+    pub first: usize,
+    /// Index of the second signature, always greater than `first`.
+    pub second: usize,
+    /// Estimated Jaccard similarity between the two signatures, in `[0.0, 1.0]`.
+    pub similarity: f64,
+}
+
+/// Estimates the Jaccard similarity between two equal-length Min-Hash signatures (as produced by
+/// [`Fingerprints::compute`]) as the fraction of lane positions where both signatures agree,
+/// the standard Min-Hash estimator. Both slices are scanned lane by lane so the compiler can
+/// auto-vectorize the equality count, in the spirit of SimSIMD's binary/integer distance kernels.
+///
+/// # Panics
+///
+/// Panics if `signature_a.len() != signature_b.len()`, since similarity is only defined for
+/// signatures produced with the same `dimensions` and hash-function seeds.
+///
+/// # Examples
+///
+/// ```rust
+/// # use stringzilla::szs::minhash_similarity;
+/// let a = [1u32, 2, 3, 4];
+/// let b = [1u32, 2, 30, 40];
+/// assert_eq!(minhash_similarity(&a, &b), 0.5);
+/// ```
+pub fn minhash_similarity(signature_a: &[u32], signature_b: &[u32]) -> f64 {
+    Fingerprints::jaccard_similarity(signature_a, signature_b)
+}
+
+/// Buckets a batch of Min-Hash signatures with locality-sensitive hashing (banding) and returns
+/// every candidate near-duplicate pair, each refined with a full-signature [`minhash_similarity`]
+/// score. This finds likely near-duplicates in `O(n)` band-hash bucketing instead of the `O(n^2)`
+/// all-pairs comparison a naive scan would need.
+///
+/// `signatures` holds `num_signatures` rows of `dimensions` `u32` words each, laid out exactly
+/// like [`Fingerprints::compute`]'s output (`signatures[i * dimensions + j]` is the j-th hash of
+/// the i-th string). Each signature is split into `bands` equal bands of `dimensions / bands`
+/// rows; two signatures become a candidate pair as soon as they share the same band hash in any
+/// single band, per the standard LSH banding scheme. `seed` is mixed into every band hash so two
+/// unrelated batches hashed with different seeds never collide by coincidence.
+///
+/// # Errors
+///
+/// Returns [`Error`] wrapping [`SzStatus::UnexpectedDimensions`] if `dimensions % bands != 0` or
+/// if `signatures.len() != num_signatures * dimensions`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use stringzilla::szs::lsh_candidate_pairs;
+/// // 3 signatures, 4 dimensions each, split into 2 bands of 2 rows.
+/// let signatures: Vec<u32> = vec![
+///     1, 2, 3, 4, // signature 0
+///     1, 2, 9, 9, // signature 1: shares band 0 ([1, 2]) with signature 0
+///     9, 9, 7, 8, // signature 2: shares no band with either
+/// ];
+/// let pairs = lsh_candidate_pairs(&signatures, 3, 4, 2, 0).unwrap();
+/// assert_eq!(pairs.len(), 1);
+/// assert_eq!((pairs[0].first, pairs[0].second), (0, 1));
+/// assert_eq!(pairs[0].similarity, 0.5);
+/// ```
+pub fn lsh_candidate_pairs(
+    signatures: &[u32],
+    num_signatures: usize,
+    dimensions: usize,
+    bands: usize,
+    seed: u64,
+) -> Result<Vec<CandidatePair>, Error> {
+    if bands == 0 || dimensions % bands != 0 || signatures.len() != num_signatures * dimensions {
+        return Err(Error::from(SzStatus::UnexpectedDimensions));
+    }
+    let rows_per_band = dimensions / bands;
+
+    let mut candidates: std::collections::HashSet<(usize, usize)> = std::collections::HashSet::new();
+    let mut band_bytes = Vec::with_capacity(rows_per_band * core::mem::size_of::<u32>());
+    for band in 0..bands {
+        let mut buckets: std::collections::HashMap<u64, Vec<usize>> = std::collections::HashMap::new();
+        for signature_index in 0..num_signatures {
+            let row_start = signature_index * dimensions + band * rows_per_band;
+            let band_words = &signatures[row_start..row_start + rows_per_band];
+
+            band_bytes.clear();
+            for word in band_words {
+                band_bytes.extend_from_slice(&word.to_le_bytes());
+            }
+            let band_key = crate::stringzilla::hash_with_seed(&band_bytes, seed);
+            buckets.entry(band_key).or_default().push(signature_index);
+        }
+        for bucket in buckets.values() {
+            for i in 0..bucket.len() {
+                for j in (i + 1)..bucket.len() {
+                    candidates.insert((bucket[i].min(bucket[j]), bucket[i].max(bucket[j])));
                 }
             }
-        };
-        match status {
-            Status::Success => Ok(()),
-            err => Err(rust_error_from_c_message(err, error_msg)),
         }
     }
+
+    let mut pairs: Vec<CandidatePair> = candidates
+        .into_iter()
+        .map(|(first, second)| {
+            let signature_a = &signatures[first * dimensions..(first + 1) * dimensions];
+            let signature_b = &signatures[second * dimensions..(second + 1) * dimensions];
+            CandidatePair { first, second, similarity: minhash_similarity(signature_a, signature_b) }
+        })
+        .collect();
+    pairs.sort_by(|a, b| a.first.cmp(&b.first).then(a.second.cmp(&b.second)));
+    Ok(pairs)
 }
 
-impl Drop for Fingerprints {
-    fn drop(&mut self) {
-        if !self.handle.is_null() {
-            unsafe { szs_fingerprints_free(self.handle) };
+/// Lookup table of 256 pseudo-random 64-bit constants, one per byte value, used by
+/// [`content_defined_chunk_offsets`]'s GEAR rolling hash. Fixed and baked in (rather than
+/// generated at runtime) so chunk boundaries are reproducible across processes and platforms,
+/// the same tradeoff [`BLOSUM62_SCORES`] and friends make for substitution scoring.
+#[rustfmt::skip]
+const GEAR_TABLE: [u64; 256] = [
+    0x6E789E6AA1B965F4, 0x06C45D188009454F, 0xF88BB8A8724C81EC, 0x1B39896A51A8749B,
+    0x53CB9F0C747EA2EA, 0x2C829ABE1F4532E1, 0xC584133AC916AB3C, 0x3EE5789041C98AC3,
+    0xF3B8488C368CB0A6, 0x657EECDD3CB13D09, 0xC2D326E0055BDEF6, 0x8621A03FE0BBDB7B,
+    0x8E1F7555983AA92F, 0xB54E0F1600CC4D19, 0x84BB3F97971D80AB, 0x7D29825C75521255,
+    0xC3CF17102B7F7F86, 0x3466E9A083914F64, 0xD81A8D2B5A4485AC, 0xDB01602B100B9ED7,
+    0xA9038A921825F10D, 0xEDF5F1D90DCA2F6A, 0x54496AD67BD2634C, 0xDD7C01D4F5407269,
+    0x935E82F1DB4C4F7B, 0x69B82EBC92233300, 0x40D29EB57DE1D510, 0xA2F09DABB45C6316,
+    0xEE521D7A0F4D3872, 0xF16952EE72F3454F, 0x377D35DEA8E40225, 0x0C7DE8064963BAB0,
+    0x05582D37111AC529, 0xD254741F599DC6F7, 0x69630F7593D108C3, 0x417EF96181DAA383,
+    0x3C3C41A3B43343A1, 0x6E19905DCBE531DF, 0x4FA9FA7324851729, 0x84EB4454A792922A,
+    0x134F7096918175CE, 0x07DC930B302278A8, 0x12C015A97019E937, 0xCC06C31652EBF438,
+    0xECEE65630A691E37, 0x3E84ECB1763E79AD, 0x690ED476743AAE49, 0x774615D7B1A1F2E1,
+    0x22B353F04F4F52DA, 0xE3DDD86BA71A5EB1, 0xDF268ADEB6513356, 0x2098EB73D4367D77,
+    0x03D6845323CE3C71, 0xC952C5620043C714, 0x9B196BCA844F1705, 0x30260345DD9E0EC1,
+    0xCF448A5882BB9698, 0xF4A578DCCBC87656, 0xBFDEAED9A17B3C8F, 0xED79402D1D5C5D7B,
+    0x55F070AB1CBBF170, 0x3E00A34929A88F1D, 0xE255B237B8BB18FB, 0x2A7B67AF6C6AD50E,
+    0x466D5E7F3E46F143, 0x42375CB399A4FC72, 0x8C8A1F148A8BB259, 0x32FCAB5DAED5BDFC,
+    0x9E60398C8D8553C0, 0xEE89CCEB8C4064C0, 0xDB0215941D86A66F, 0x5CCDE78203C367A8,
+    0xF1BCBC6A1EC11786, 0xEF054FCEEE954551, 0xDF82012D0555C6DF, 0x292566FF72403C08,
+    0xC4DD302A1BFA1137, 0xD85F219DB5C554E1, 0x6A27FF807441BCD2, 0x96A573E9B48216E8,
+    0x46A9FDAC40BF0048, 0x3DD12464A0EE15B4, 0x451E521296A7EEA1, 0x56E4398A98F8A0FD,
+    0x7B7DC2160E3335A7, 0xC679EE0BEBCB1CCA, 0x928D6F2D7453424E, 0x1B38994205234C6D,
+    0x8086D193A6F2B568, 0x21C6E26639AC2C65, 0xD9DCCAC414D23C6F, 0x91CD642057E00235,
+    0x77FC607DC6589373, 0x05B8ABE26DD3AEE7, 0x12F6436AC376CC66, 0x64952424897B2307,
+    0xEE8C2BAF6343E5C3, 0xDC4C613D9EBA2304, 0x3505B7796BD1A506, 0x8176DAF800A05F50,
+    0x8BD8FF7A0385CDBC, 0x1A764A3CD78101DA, 0xBE4D15BF6CA266AC, 0xA85E1F38BB2DC749,
+    0x56759A968493CD8C, 0xF3A9BCE7336BD182, 0x365B15013741519B, 0x1F7A44A6B109AC94,
+    0x3521D628813CB177, 0x6A77AFAB0F7C9370, 0x179642D8CDE95015, 0x5EF102A8FB354461,
+    0xF51C504764ED82F2, 0xC58427F041CE6808, 0xFAD8FC45C9643C37, 0xCF8682F9A70FA9C0,
+    0x7E1B3B75A4005729, 0x992DD867927B52D8, 0x7FBD5DB142F6791F, 0x370595AACAB4ADAE,
+    0xB1392DBDC5AB61D6, 0x9FEA7DFC79D452D9, 0x40B12B120085641C, 0xA192AFE3157C85D0,
+    0xC847729F4E08F3A3, 0x6F1384A306C41FC2, 0x12D05C4045A39C19, 0x9899202FD20F0841,
+    0xE9C7191857E774B8, 0x4EEAD809AF5B0CC3, 0xE809ACAFA23864A4, 0x4DA1EDABA1D0F7BD,
+    0x846EB9673349F8E4, 0x87BAE55B86039FE8, 0x7F367B8BD953EFF2, 0x3884700F650D04E1,
+    0xBFE4B2AB46980CAD, 0xC5FC89075299106C, 0x37B2FA361ADEA7CD, 0x7D75D813F04895B4,
+    0x702F5B393F62C0E0, 0x0A3FC775F4ECF37F, 0xE4B23787A352437F, 0xF83FA245C34D6363,
+    0xB99BCF040786CF50, 0x38B6EA0A0E6C9D8A, 0x093FDC76776E37E1, 0x1A75E6F76BA7EEE8,
+    0x442CDCFEE9660C62, 0x22D58D35116B5E0B, 0x87D4A5180F6A3645, 0x589FB216BD82131B,
+    0x91D031CAD319AEC0, 0xABECF76A553D320B, 0xB8686CB347612DCF, 0xFCAB66337C0A77F5,
+    0xAC318214381EC437, 0x6EB7F0FCA24494AE, 0xCF42861DCDC895A9, 0x4ABAD7A1586D7A91,
+    0xC21B318DC2F49745, 0xD49474DC2ACBD1F0, 0xB1D4873747C1C8E1, 0x5434DC8C7D015BF6,
+    0xE1C486287511B6A9, 0xA8616DF62E89A193, 0x31CE6319498D8347, 0xAFD0B486123D6FAA,
+    0xE6495F5D102301EB, 0x0DC51CED17A43C52, 0x8BCBCDE81355EF2D, 0x2412AF73FDEE7CFC,
+    0xC8D589E486E29EED, 0x23390E8664517F89, 0x251ADE58E8A6849D, 0xF8555DBD2E8F9CB0,
+    0xCB417C3EEF54F7C3, 0x8028F8E1AAC3A919, 0x10E31052ACF748A0, 0x2D886C073B1E1B78,
+    0x972974D90DF9FAEE, 0xBC1B7B38796893BA, 0x1958ED432070E652, 0xCA5F297197A12DCC,
+    0xE025A27375704F28, 0x418010A570A924FB, 0x9828E2941BFC419C, 0x4FBACD2F52B85C1F,
+    0x33DD5B756211CC67, 0x23C8DFDD1DB57FF0, 0x32F81801A1A8E901, 0x26884EAC5ADA36DA,
+    0xCAA82F9BB42E37D4, 0x19FB1A7491D6A7D1, 0x5AA0243AA357F38E, 0xB31D917809E447F0,
+    0x3F9C197225215BE0, 0xDC3C315A1E33C095, 0x3DD399AD533E80AC, 0x566F32CCE8301D95,
+    0xC880188083D9BA21, 0xB9CC357F3B0E7D2E, 0x0237D2123A8A8D6C, 0xBF636E9AA7CBF6BD,
+    0xD7BD4284C4E2A6A7, 0xDA2EBB47D50577A9, 0x90BA1C11B539087D, 0x44993D31552B4F57,
+    0x32C2D6F80A8A8898, 0x450583ED7FB54B19, 0xEC2B0B09E50EF3EF, 0xD918A0B6E2EFD65C,
+    0xE37A868D9785F572, 0x7D1A6118F2B0F37A, 0x9E2E3CC13B343439, 0xEFD82C11212E37E8,
+    0xAF89C05CD4FC75ED, 0x55BC16BB9697108E, 0x6C4701FA5DB69BEE, 0x9237338441DAF445,
+    0x248CF0831E81A5FC, 0xACC13557E77DE273, 0x520970C25E06513A, 0x657329CB02987CAB,
+    0xA9B0B3366A4E55A8, 0xC4D06CA2F39ACDD4, 0x5DCE37D68170CDE1, 0x5F1E44E77E1854C9,
+    0x6883D452D55DF899, 0x05C5BD62F1067032, 0xE680B683CE60FAB0, 0x5DC9DA3F286D18B1,
+    0x94B4BF3AB85ED6D8, 0xCE65F449E3ACC5A3, 0x34B0209642CEA639, 0xC14C3C771D904827,
+    0x6ADDCEE2BD9CDEE5, 0xE24EED137FFBB613, 0x75DD58EF79963D1B, 0xFDB83ECF6CC24920,
+    0x7A1D0057C57169FB, 0x339200F4FEB62D07, 0xD33F4D4AC88469F4, 0x8226F234E68DFEE4,
+    0x320DEF4F2A105536, 0x7786F3B13AEFC159, 0xB28225AC9DF63EE2, 0x781B9D0376CC6044,
+    0x05BD0115226C6AB6, 0xD302230207BDFDAB, 0xDB898ABD8E0D2933, 0x9E79A397BA00B9CC,
+    0x89DF84A5F0003EE8, 0x011F04F2A75FB9BE, 0x5A5832BB47BCF19E, 0xCBDC6D34B7C7534D,
+];
+
+/// Splits `data` into variable-length, content-defined chunks using the GEAR rolling hash, so
+/// that inserting or deleting bytes in one region only shifts the chunk boundaries touching that
+/// region instead of every fixed-size window downstream of the edit. This is the chunking layer
+/// behind [`FingerprintsBuilder::content_defined_chunks`]: feed each chunk independently into
+/// [`Fingerprints::compute`] and near-duplicate documents that differ in only one region still
+/// share most of their MinHash signature.
+///
+/// The rolling hash accumulates `h = (h << 1).wrapping_add(GEAR_TABLE[byte])` over the bytes seen
+/// since the last boundary, and a boundary is declared whenever the low bits of `h` are all zero
+/// (`h & mask == 0`, `mask` having `avg_size.next_power_of_two().ilog2()` bits set), which happens
+/// on average once every `avg_size` bytes regardless of alignment. `min_size` and `max_size` clamp
+/// every chunk so pathological inputs (all-zero buffers, adversarial data) still terminate in
+/// bounded chunks.
+///
+/// Returns chunk boundaries in CSR-style offsets: `offsets[0] == 0`, `offsets[offsets.len() - 1]
+/// == data.len()`, and the `i`-th chunk is `data[offsets[i]..offsets[i + 1]]`. Empty input
+/// produces `[0]` (zero chunks).
+///
+/// # Panics
+///
+/// Panics if `min_size == 0`, `max_size < min_size`, or `avg_size == 0`.
+pub fn content_defined_chunk_offsets(data: &[u8], avg_size: usize, min_size: usize, max_size: usize) -> Vec<usize> {
+    assert!(min_size > 0, "min_size must be positive");
+    assert!(max_size >= min_size, "max_size must be at least min_size");
+    assert!(avg_size > 0, "avg_size must be positive");
+
+    if data.is_empty() {
+        return vec![0];
+    }
+
+    let mask = (avg_size.next_power_of_two() - 1) as u64;
+    let mut offsets = vec![0usize];
+    let mut chunk_start = 0usize;
+    let mut hash: u64 = 0;
+
+    for (i, &byte) in data.iter().enumerate() {
+        let chunk_len = i - chunk_start + 1;
+        hash = (hash << 1).wrapping_add(GEAR_TABLE[byte as usize]);
+
+        if chunk_len >= max_size || (chunk_len >= min_size && hash & mask == 0) {
+            offsets.push(i + 1);
+            chunk_start = i + 1;
+            hash = 0;
         }
     }
-}
 
-unsafe impl Send for Fingerprints {}
-unsafe impl Sync for Fingerprints {}
+    if chunk_start != data.len() {
+        offsets.push(data.len());
+    }
+    offsets
+}
 
 /// Creates a diagonal substitution matrix for sequence alignment.
 /// Diagonal entries (matches) get `match_score`, off-diagonal (mismatches) get `mismatch_score`.
@@ -2706,6 +6058,94 @@ pub fn error_costs_256x256_unary() -> [[i8; 256]; 256] {
     error_costs_256x256_diagonal(0, -1)
 }
 
+/// Named substitution-matrix presets selectable via [`error_costs_256x256_preset`]. Covers the
+/// same well-known tables as [`SubstitutionMatrix`]'s named constructors, for callers who want to
+/// pick one by value (e.g. from a CLI flag or config file) rather than calling a constructor
+/// directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubstitutionMatrixPreset {
+    /// BLOSUM62: the protein BLAST default, tuned for moderately diverged sequences.
+    Blosum62,
+    /// BLOSUM45: tuned for more distantly related protein sequences than BLOSUM62.
+    Blosum45,
+    /// PAM250: point-accepted-mutation matrix extrapolated to 250 mutations per 100 residues.
+    Pam250,
+    /// IUPAC-aware nucleotide matrix (NUC.4.4-style), scoring ambiguity codes by base overlap.
+    DnaIupac,
+}
+
+/// Builds a `[[i8; 256]; 256]` substitution matrix for `preset`, with `off_alphabet_score`
+/// assigned to every byte pair outside the preset's alphabet (in place of each preset's own
+/// default penalty). Returns the matrix by value, so unlike [`SubstitutionMatrix`]'s constructors
+/// (which return `Box<[[i8; 256]; 256]>`) it drops straight into
+/// [`error_costs_256x256_diagonal`]-style call sites without an extra deref.
+pub fn error_costs_256x256_preset(preset: SubstitutionMatrixPreset, off_alphabet_score: i8) -> [[i8; 256]; 256] {
+    let mut matrix = match preset {
+        SubstitutionMatrixPreset::Blosum62 => *SubstitutionMatrix::blosum62(),
+        SubstitutionMatrixPreset::Blosum45 => expand_amino_acid_table(&BLOSUM45_SCORES, SUBSTITUTION_MATRIX_OFF_ALPHABET_PENALTY),
+        SubstitutionMatrixPreset::Pam250 => *SubstitutionMatrix::pam250(),
+        SubstitutionMatrixPreset::DnaIupac => *SubstitutionMatrix::nuc44(),
+    };
+    if off_alphabet_score != SUBSTITUTION_MATRIX_OFF_ALPHABET_PENALTY {
+        for row in matrix.iter_mut() {
+            for score in row.iter_mut() {
+                if *score == SUBSTITUTION_MATRIX_OFF_ALPHABET_PENALTY {
+                    *score = off_alphabet_score;
+                }
+            }
+        }
+    }
+    matrix
+}
+
+/// Expands a `24x24` table over [`AMINO_ACID_ALPHABET`] into a full `256x256` matrix, mirroring
+/// each code into both upper and lower case and leaving every other byte at `off_alphabet_score`.
+/// Same expansion [`SubstitutionMatrix::from_amino_acid_table`] does, but returns the matrix by
+/// value instead of boxing it.
+fn expand_amino_acid_table(table: &[[i8; 24]; 24], off_alphabet_score: i8) -> [[i8; 256]; 256] {
+    let mut matrix = [[off_alphabet_score; 256]; 256];
+    for (row, &code_a) in AMINO_ACID_ALPHABET.iter().enumerate() {
+        for (col, &code_b) in AMINO_ACID_ALPHABET.iter().enumerate() {
+            let score = table[row][col];
+            for &a in &[code_a, code_a.to_ascii_lowercase()] {
+                for &b in &[code_b, code_b.to_ascii_lowercase()] {
+                    matrix[a as usize][b as usize] = score;
+                }
+            }
+        }
+    }
+    matrix
+}
+
+/// BLOSUM45: like [`BLOSUM62_SCORES`], tuned for more distantly related protein sequences.
+#[rustfmt::skip]
+const BLOSUM45_SCORES: [[i8; 24]; 24] = [
+    [ 5,-2,-1,-2,-1,-1,-1, 0,-2,-1,-1,-1,-1,-2,-1, 1, 0,-2,-2, 0,-1,-1,-1,-5],
+    [-2, 7, 0,-1,-3, 1, 0,-2, 0,-3,-2, 3,-1,-2,-2,-1,-1,-2,-1,-2,-1, 0,-1,-5],
+    [-1, 0, 6, 2,-2, 0, 0, 0, 1,-2,-3, 0,-2,-2,-2, 1, 0,-4,-2,-3, 4, 0,-1,-5],
+    [-2,-1, 2, 7,-3, 0, 2,-1, 0,-4,-3, 0,-3,-4,-1, 0,-1,-4,-2,-3, 5, 1,-1,-5],
+    [-1,-3,-2,-3,12,-3,-3,-3,-3,-3,-2,-3,-2,-2,-4,-1,-1,-5,-3,-1,-2,-3,-2,-5],
+    [-1, 1, 0, 0,-3, 6, 2,-2, 1,-2,-2, 1, 0,-4,-1, 0,-1,-2,-1,-3, 0, 4,-1,-5],
+    [-1, 0, 0, 2,-3, 2, 6,-2, 0,-3,-2, 1,-2,-3, 0, 0,-1,-3,-2,-3, 1, 4,-1,-5],
+    [ 0,-2, 0,-1,-3,-2,-2, 7,-2,-4,-3,-2,-2,-3,-2, 0,-2,-2,-3,-3,-1,-2,-1,-5],
+    [-2, 0, 1, 0,-3, 1, 0,-2,10,-3,-2,-1, 0,-2,-2,-1, 2,-3, 2,-3, 0, 0,-1,-5],
+    [-1,-3,-2,-4,-3,-2,-3,-4,-3, 5, 2,-3, 2, 0,-2,-2,-1,-2, 0, 3,-3,-3,-1,-5],
+    [-1,-2,-3,-3,-2,-2,-2,-3,-2, 2, 5,-3, 2, 1,-3,-3,-1,-2, 0, 1,-3,-2,-1,-5],
+    [-1, 3, 0, 0,-3, 1, 1,-2,-1,-3,-3, 5,-1,-3,-1,-1,-1,-2,-1,-2, 0, 1,-1,-5],
+    [-1,-1,-2,-3,-2, 0,-2,-2, 0, 2, 2,-1, 6, 0,-2,-2,-1,-2, 0, 1,-2,-1,-1,-5],
+    [-2,-2,-2,-4,-2,-4,-3,-3,-2, 0, 1,-3, 0, 8,-3,-2,-1, 1, 3, 0,-3,-3,-1,-5],
+    [-1,-2,-2,-1,-4,-1, 0,-2,-2,-2,-3,-1,-2,-3, 9,-1,-1,-3,-3,-3,-2,-1,-1,-5],
+    [ 1,-1, 1, 0,-1, 0, 0, 0,-1,-2,-3,-1,-2,-2,-1, 4, 2,-4,-2,-1, 0, 0,-1,-5],
+    [ 0,-1, 0,-1,-1,-1,-1,-2, 2,-1,-1,-1,-1,-1,-1, 2, 5,-3,-1, 0, 0,-1,-1,-5],
+    [-2,-2,-4,-4,-5,-2,-3,-2,-3,-2,-2,-2,-2, 1,-3,-4,-3,15, 3,-3,-4,-2,-2,-5],
+    [-2,-1,-2,-2,-3,-1,-2,-3, 2, 0, 0,-1, 0, 3,-3,-2,-1, 3, 8,-1,-2,-2,-1,-5],
+    [ 0,-2,-3,-3,-1,-3,-3,-3,-3, 3, 1,-2, 1, 0,-3,-1, 0,-3,-1, 5,-3,-3,-1,-5],
+    [-1,-1, 4, 5,-2, 0, 1,-1, 0,-3,-3, 0,-2,-3,-2, 0, 0,-4,-2,-3, 4, 2,-1,-5],
+    [-1, 0, 0, 1,-3, 4, 4,-2, 0,-3,-2, 1,-1,-3,-1, 0,-1,-2,-2,-3, 2, 4,-1,-5],
+    [-1,-1,-1,-1,-2,-1,-1,-1,-1,-1,-1,-1,-1,-1,-1,-1,-1,-2,-1,-1,-1,-1,-1,-5],
+    [-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5,-5, 1],
+];
+
 /// Check if either byte collection requires 64-bit tapes
 fn should_use_64bit_for_bytes<T: AsRef<[u8]>>(seq_a: &[T], seq_b: &[T]) -> bool {
     let total_size_a: usize = seq_a.iter().map(|s| s.as_ref().len()).sum();
@@ -2746,6 +6186,359 @@ where
     }
 }
 
+/// Number of entries held by any variant of [`AnyBytesTape`], owned or borrowed alike. Used by
+/// [`LevenshteinDistances::broadcast_into`] to size each reference's results column up front.
+fn any_bytes_tape_len(tape: &AnyBytesTape<'_>) -> usize {
+    match tape {
+        AnyBytesTape::Tape32(t) => t.as_raw_parts().items_count,
+        AnyBytesTape::Tape64(t) => t.as_raw_parts().items_count,
+        AnyBytesTape::View32(v) => v.as_raw_parts().items_count,
+        AnyBytesTape::View64(v) => v.as_raw_parts().items_count,
+    }
+}
+
+/// Zero-copy-friendly ingestion of [`bytes::Buf`] payloads into a [`BytesTape`], for callers that
+/// already hold network/IO payloads in `bytes::Bytes` or a chain of buffers instead of a
+/// contiguous `&[u8]`. Gated behind the `bytes` feature, since `stringtape` itself doesn't depend
+/// on the `bytes` crate.
+#[cfg(feature = "bytes")]
+pub trait BytesTapeBufExt {
+    /// Appends `buf` as a single new tape entry, walking its chunks (`Buf::chunk`/`Buf::advance`)
+    /// instead of requiring the caller to flatten them into a contiguous slice first. When `buf`
+    /// is already contiguous -- the common case for one decoded frame -- this hands `buf.chunk()`
+    /// straight to [`BytesTape::extend`] with no extra copy; a chained/non-contiguous `buf` is
+    /// flattened into one owned buffer first, since a tape entry must be pushed in one call.
+    fn extend_from_buf<B: bytes::Buf>(&mut self, buf: B) -> Result<(), Error>;
+}
+
+/// Flattens every chunk of `buf` into a single owned buffer, advancing `buf` to empty.
+#[cfg(feature = "bytes")]
+fn buf_into_owned_entry<B: bytes::Buf>(mut buf: B) -> Vec<u8> {
+    let mut owned = Vec::with_capacity(buf.remaining());
+    while buf.has_remaining() {
+        let chunk = buf.chunk();
+        let len = chunk.len();
+        owned.extend_from_slice(chunk);
+        buf.advance(len);
+    }
+    owned
+}
+
+#[cfg(feature = "bytes")]
+impl BytesTapeBufExt for BytesTape<u32, UnifiedAlloc> {
+    fn extend_from_buf<B: bytes::Buf>(&mut self, mut buf: B) -> Result<(), Error> {
+        if buf.chunk().len() == buf.remaining() {
+            let slice = buf.chunk();
+            return self.extend(core::slice::from_ref(&slice)).map_err(|_| Error::from(SzStatus::BadAlloc));
+        }
+        let owned = buf_into_owned_entry(buf);
+        self.extend(core::slice::from_ref(&owned)).map_err(|_| Error::from(SzStatus::BadAlloc))
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl BytesTapeBufExt for BytesTape<u64, UnifiedAlloc> {
+    fn extend_from_buf<B: bytes::Buf>(&mut self, mut buf: B) -> Result<(), Error> {
+        if buf.chunk().len() == buf.remaining() {
+            let slice = buf.chunk();
+            return self.extend(core::slice::from_ref(&slice)).map_err(|_| Error::from(SzStatus::BadAlloc));
+        }
+        let owned = buf_into_owned_entry(buf);
+        self.extend(core::slice::from_ref(&owned)).map_err(|_| Error::from(SzStatus::BadAlloc))
+    }
+}
+
+impl<'a> AnyBytesTape<'a> {
+    /// Appends `buf` as a new tape entry via [`BytesTapeBufExt::extend_from_buf`], when this is
+    /// an owned [`AnyBytesTape::Tape32`]/[`AnyBytesTape::Tape64`]. Zero-copy FFI views
+    /// ([`AnyBytesTape::View32`]/[`AnyBytesTape::View64`]) borrow someone else's memory and can't
+    /// grow, so this fails with [`SzStatus::UnexpectedDimensions`] for those variants.
+    #[cfg(feature = "bytes")]
+    pub fn extend_from_buf<B: bytes::Buf>(&mut self, buf: B) -> Result<(), Error> {
+        match self {
+            AnyBytesTape::Tape32(tape) => tape.extend_from_buf(buf),
+            AnyBytesTape::Tape64(tape) => tape.extend_from_buf(buf),
+            AnyBytesTape::View32(_) | AnyBytesTape::View64(_) => Err(Error::from(SzStatus::UnexpectedDimensions)),
+        }
+    }
+
+    /// Reads the `index`-th entry back out as a [`TapeEntryBuf`], without copying its bytes.
+    /// Works for every variant, including the zero-copy FFI views, since reading only needs a
+    /// borrow. Returns `None` if `index` is out of bounds.
+    ///
+    /// Borrowed for `'b`, not `'a`: for the owned [`AnyBytesTape::Tape32`]/[`AnyBytesTape::Tape64`]
+    /// variants the entry bytes live only as long as `self` does, so tying the result to the
+    /// (possibly much longer) FFI-view lifetime `'a` would be unsound.
+    #[cfg(feature = "bytes")]
+    pub fn entry_as_buf<'b>(&'b self, index: usize) -> Option<TapeEntryBuf<'b>> {
+        match self {
+            AnyBytesTape::Tape32(tape) => bytes_tape_entry_u32(tape.as_raw_parts(), index),
+            AnyBytesTape::Tape64(tape) => bytes_tape_entry_u64(tape.as_raw_parts(), index),
+            AnyBytesTape::View32(view) => bytes_tape_entry_u32(view.as_raw_parts(), index),
+            AnyBytesTape::View64(view) => bytes_tape_entry_u64(view.as_raw_parts(), index),
+        }
+    }
+}
+
+/// Reads entry `index` out of a 32-bit-offset tape's `as_raw_parts()` output as a borrowed byte
+/// slice, per the CSR-style `offsets` convention used throughout this module
+/// (`offsets[i]..offsets[i + 1]`).
+///
+/// # Safety
+///
+/// Relies on `parts.data_ptr`/`parts.offsets_ptr` being valid for `parts.items_count` entries and
+/// `parts.items_count + 1` offsets respectively, the same invariant [`SzSequenceU32Tape::from`]
+/// already depends on when handing these pointers to the C API.
+#[cfg(feature = "bytes")]
+fn bytes_tape_entry_u32<'b>(parts: stringtape::RawParts<u32>, index: usize) -> Option<TapeEntryBuf<'b>> {
+    if index >= parts.items_count {
+        return None;
+    }
+    unsafe {
+        let offsets = core::slice::from_raw_parts(parts.offsets_ptr, parts.items_count + 1);
+        let start = offsets[index] as usize;
+        let end = offsets[index + 1] as usize;
+        let data = core::slice::from_raw_parts(parts.data_ptr, end);
+        Some(TapeEntryBuf::new(&data[start..end]))
+    }
+}
+
+/// 64-bit-offset counterpart to [`bytes_tape_entry_u32`]; see its doc comment for the layout and
+/// safety invariants, identical here but for the offset width.
+#[cfg(feature = "bytes")]
+fn bytes_tape_entry_u64<'b>(parts: stringtape::RawParts<u64>, index: usize) -> Option<TapeEntryBuf<'b>> {
+    if index >= parts.items_count {
+        return None;
+    }
+    unsafe {
+        let offsets = core::slice::from_raw_parts(parts.offsets_ptr, parts.items_count + 1);
+        let start = offsets[index] as usize;
+        let end = offsets[index + 1] as usize;
+        let data = core::slice::from_raw_parts(parts.data_ptr, end);
+        Some(TapeEntryBuf::new(&data[start..end]))
+    }
+}
+
+/// Reads one [`BytesTape`] (or [`BytesTapeView`]) entry back out as a [`bytes::Buf`], without
+/// copying its bytes. Pairs with [`BytesTapeBufExt::extend_from_buf`] so a frame ingested into a
+/// tape can be handed to a downstream `Buf`-based codec the same way it arrived.
+#[cfg(feature = "bytes")]
+pub struct TapeEntryBuf<'a> {
+    remaining: &'a [u8],
+}
+
+#[cfg(feature = "bytes")]
+impl<'a> TapeEntryBuf<'a> {
+    fn new(entry: &'a [u8]) -> Self {
+        Self { remaining: entry }
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<'a> bytes::Buf for TapeEntryBuf<'a> {
+    fn remaining(&self) -> usize {
+        self.remaining.len()
+    }
+
+    fn chunk(&self) -> &[u8] {
+        self.remaining
+    }
+
+    fn advance(&mut self, cnt: usize) {
+        self.remaining = &self.remaining[cnt..];
+    }
+}
+
+/// Growth step for [`TapeWriter`]'s scratch buffer when `chunk_mut` finds no spare capacity, so a
+/// run of tiny `put_slice` calls doesn't reserve one byte at a time.
+#[cfg(feature = "bytes")]
+const TAPE_WRITER_MIN_GROWTH: usize = 64;
+
+/// Extension trait adding an incremental, [`bytes::BufMut`]-style entry writer to [`BytesTape`].
+///
+/// Today a tape entry must be a fully-formed slice handed to `extend`; `begin_entry` lets it be
+/// assembled from many fragments instead (e.g. streaming tokenizer output or reassembled packets)
+/// without allocating an intermediate contiguous buffer up front.
+#[cfg(feature = "bytes")]
+pub trait BytesTapeWriterExt<Offset> {
+    fn begin_entry(&mut self) -> TapeWriter<'_, Offset>;
+}
+
+#[cfg(feature = "bytes")]
+impl BytesTapeWriterExt<u32> for BytesTape<u32, UnifiedAlloc> {
+    fn begin_entry(&mut self) -> TapeWriter<'_, u32> {
+        TapeWriter { scratch: Vec::new(), tape: TapeWriterTarget::Tape32(self), _offset: core::marker::PhantomData }
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl BytesTapeWriterExt<u64> for BytesTape<u64, UnifiedAlloc> {
+    fn begin_entry(&mut self) -> TapeWriter<'_, u64> {
+        TapeWriter { scratch: Vec::new(), tape: TapeWriterTarget::Tape64(self), _offset: core::marker::PhantomData }
+    }
+}
+
+/// Backing tape a [`TapeWriter`] commits into; split by offset width for the same reason every
+/// other tape helper in this module is (`BytesTape<u32, _>` and `BytesTape<u64, _>` are distinct
+/// concrete types, not a shared generic one).
+#[cfg(feature = "bytes")]
+enum TapeWriterTarget<'a> {
+    Tape32(&'a mut BytesTape<u32, UnifiedAlloc>),
+    Tape64(&'a mut BytesTape<u64, UnifiedAlloc>),
+}
+
+/// Incremental writer for one [`BytesTape`] entry, built fragment by fragment via [`bytes::BufMut`]
+/// (`put_slice`, `put_bytes`, or the raw `chunk_mut`/`advance_mut` pair) and finalized with
+/// [`TapeWriter::commit`]. Dropping a [`TapeWriter`] without committing simply discards the
+/// fragments accumulated so far; nothing is appended to the tape.
+#[cfg(feature = "bytes")]
+pub struct TapeWriter<'a, Offset> {
+    scratch: Vec<u8>,
+    tape: TapeWriterTarget<'a>,
+    _offset: core::marker::PhantomData<Offset>,
+}
+
+#[cfg(feature = "bytes")]
+impl<'a> TapeWriter<'a, u32> {
+    /// Appends the fragments written so far as a single new entry on the underlying tape.
+    pub fn commit(self) -> Result<(), Error> {
+        match self.tape {
+            TapeWriterTarget::Tape32(tape) => {
+                tape.extend(core::slice::from_ref(&self.scratch)).map_err(|_| Error::from(SzStatus::BadAlloc))
+            }
+            TapeWriterTarget::Tape64(_) => unreachable!("TapeWriter<u32> always targets a 32-bit tape"),
+        }
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<'a> TapeWriter<'a, u64> {
+    /// Appends the fragments written so far as a single new entry on the underlying tape.
+    pub fn commit(self) -> Result<(), Error> {
+        match self.tape {
+            TapeWriterTarget::Tape64(tape) => {
+                tape.extend(core::slice::from_ref(&self.scratch)).map_err(|_| Error::from(SzStatus::BadAlloc))
+            }
+            TapeWriterTarget::Tape32(_) => unreachable!("TapeWriter<u64> always targets a 64-bit tape"),
+        }
+    }
+}
+
+#[cfg(feature = "bytes")]
+unsafe impl<'a, Offset> bytes::BufMut for TapeWriter<'a, Offset> {
+    fn remaining_mut(&self) -> usize {
+        usize::MAX - self.scratch.len()
+    }
+
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        let new_len = self.scratch.len() + cnt;
+        debug_assert!(new_len <= self.scratch.capacity());
+        self.scratch.set_len(new_len);
+    }
+
+    fn chunk_mut(&mut self) -> &mut bytes::buf::UninitSlice {
+        if self.scratch.len() == self.scratch.capacity() {
+            self.scratch.reserve(TAPE_WRITER_MIN_GROWTH);
+        }
+        let len = self.scratch.len();
+        let cap = self.scratch.capacity();
+        unsafe {
+            let ptr = self.scratch.as_mut_ptr().add(len);
+            bytes::buf::UninitSlice::from_raw_parts_mut(ptr, cap - len)
+        }
+    }
+}
+
+/// Upper bound on the entry count and per-entry length an [`arbitrary`]-derived [`BytesTape`] can
+/// reach, so a single `Unstructured` budget can't blow one fuzz case up into a multi-megabyte
+/// allocation.
+#[cfg(feature = "arbitrary")]
+const ARBITRARY_TAPE_MAX_ENTRIES: usize = 64;
+
+/// See [`ARBITRARY_TAPE_MAX_ENTRIES`].
+#[cfg(feature = "arbitrary")]
+const ARBITRARY_TAPE_MAX_ENTRY_LEN: usize = 256;
+
+/// Draws a small, bounded batch of byte strings from `u`, shared by every tape width's
+/// [`arbitrary::Arbitrary`] impl below.
+#[cfg(feature = "arbitrary")]
+fn arbitrary_tape_entries<'a>(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Vec<Vec<u8>>> {
+    let entry_count = u.int_in_range(0..=ARBITRARY_TAPE_MAX_ENTRIES)?;
+    let mut entries = Vec::with_capacity(entry_count);
+    for _ in 0..entry_count {
+        let len = u.int_in_range(0..=ARBITRARY_TAPE_MAX_ENTRY_LEN)?;
+        entries.push(u.bytes(len)?.to_vec());
+    }
+    Ok(entries)
+}
+
+/// Lets a fuzzer derive a populated 32-bit-offset [`BytesTape`] directly from raw input, so a
+/// differential harness can compare the device engines against a scalar CPU reference without
+/// hand-rolling tape construction in every fuzz target.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for BytesTape<u32, UnifiedAlloc> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let entries = arbitrary_tape_entries(u)?;
+        let mut tape = BytesTape::new_in(UnifiedAlloc);
+        tape.extend(&entries).map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        Ok(tape)
+    }
+}
+
+/// 64-bit-offset counterpart to the `BytesTape<u32, _>` impl above.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for BytesTape<u64, UnifiedAlloc> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let entries = arbitrary_tape_entries(u)?;
+        let mut tape = BytesTape::new_in(UnifiedAlloc);
+        tape.extend(&entries).map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        Ok(tape)
+    }
+}
+
+/// Derives an owned [`AnyBytesTape`] ([`AnyBytesTape::Tape32`] or [`AnyBytesTape::Tape64`], never
+/// one of the borrowed view variants since there's nothing for those to borrow from), letting
+/// fuzz targets exercise both offset widths -- and the 4 GiB boundary between them -- from the
+/// same `Unstructured` byte stream.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AnyBytesTape<'static> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        if bool::arbitrary(u)? {
+            Ok(AnyBytesTape::Tape64(BytesTape::<u64, UnifiedAlloc>::arbitrary(u)?))
+        } else {
+            Ok(AnyBytesTape::Tape32(BytesTape::<u32, UnifiedAlloc>::arbitrary(u)?))
+        }
+    }
+}
+
+/// A pair of [`AnyBytesTape`]s sharing the same offset width, as every pairwise engine (e.g.
+/// [`LevenshteinDistances::compute_into`]) requires -- unlike deriving two independent
+/// [`AnyBytesTape`]s, which would mismatch widths about half the time. Exists so differential
+/// fuzz targets can derive one ready-to-compare pair straight from `Unstructured` input instead
+/// of re-deriving the coin flip themselves.
+#[cfg(feature = "arbitrary")]
+pub struct ArbitraryBytesTapePair {
+    pub first: AnyBytesTape<'static>,
+    pub second: AnyBytesTape<'static>,
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ArbitraryBytesTapePair {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        if bool::arbitrary(u)? {
+            Ok(ArbitraryBytesTapePair {
+                first: AnyBytesTape::Tape64(BytesTape::<u64, UnifiedAlloc>::arbitrary(u)?),
+                second: AnyBytesTape::Tape64(BytesTape::<u64, UnifiedAlloc>::arbitrary(u)?),
+            })
+        } else {
+            Ok(ArbitraryBytesTapePair {
+                first: AnyBytesTape::Tape32(BytesTape::<u32, UnifiedAlloc>::arbitrary(u)?),
+                second: AnyBytesTape::Tape32(BytesTape::<u32, UnifiedAlloc>::arbitrary(u)?),
+            })
+        }
+    }
+}
+
 /// Convert string sequences to StringTape
 fn copy_chars_into_tape<'a, T: AsRef<str>>(sequences: &[T], force_64bit: bool) -> Result<AnyCharsTape<'a>, Error> {
     // Estimate total size to decide between 32-bit and 64-bit tapes
@@ -2791,10 +6584,236 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_backend_info() {
-        let info = backend_info();
-        assert!(!info.is_empty());
-        println!("Backend: {}", info);
+    fn test_backend_info() {
+        let info = backend_info();
+        assert!(!info.is_empty());
+        println!("Backend: {}", info);
+    }
+
+    #[test]
+    fn hamming_distances_counts_differing_bytes() {
+        let device = match DeviceScope::default() {
+            Ok(device) => device,
+            Err(_) => return,
+        };
+        let engine = HammingDistances::new(&device).unwrap();
+        let strings_a = vec!["karolin", "kathrin"];
+        let strings_b = vec!["kathrin", "kerstin"];
+        let distances = engine.compute(&strings_a, &strings_b).unwrap();
+        assert_eq!(&distances[..], &[3, 4]);
+    }
+
+    #[test]
+    fn hamming_distances_rejects_mismatched_lengths() {
+        let device = match DeviceScope::default() {
+            Ok(device) => device,
+            Err(_) => return,
+        };
+        let engine = HammingDistances::new(&device).unwrap();
+        let result = engine.compute(&["abc"], &["ab"]);
+        assert!(matches!(result, Err(Error { status: SzStatus::UnexpectedDimensions, .. })));
+    }
+
+    #[test]
+    fn hamming_distances_packed_matches_byte_wise() {
+        let device = match DeviceScope::default() {
+            Ok(device) => device,
+            Err(_) => return,
+        };
+        let engine = HammingDistances::new(&device).unwrap();
+        let a: [u8; 16] = [0xFF; 16];
+        let mut b = a;
+        b[0] = 0x00; // differs by 8 bits
+        b[9] = 0xFE; // differs by 1 bit
+        let distances = engine.compute_packed(&a, &b, 16).unwrap();
+        assert_eq!(&distances[..], &[9]);
+    }
+
+    #[test]
+    fn hamming_distances_packed_rejects_misaligned_stride() {
+        let device = match DeviceScope::default() {
+            Ok(device) => device,
+            Err(_) => return,
+        };
+        let engine = HammingDistances::new(&device).unwrap();
+        let result = engine.compute_packed(&[0u8; 15], &[0u8; 16], 16);
+        assert!(matches!(result, Err(Error { status: SzStatus::UnexpectedDimensions, .. })));
+    }
+
+    #[test]
+    fn wordlist_dedup_sorts_and_removes_duplicates() {
+        let device = match DeviceScope::default() {
+            Ok(device) => device,
+            Err(_) => return,
+        };
+        let pipeline = WordlistDedup::new(&device).unwrap();
+
+        let input = b"banana\napple\nbanana\ncherry\napple\napple\n";
+        let mut output = Vec::new();
+        let unique_lines = pipeline.dedup_sorted(&input[..], &mut output, false).unwrap();
+        assert_eq!(unique_lines, 3);
+        assert_eq!(output, b"apple\nbanana\ncherry\n");
+    }
+
+    #[test]
+    fn wordlist_dedup_emits_occurrence_counts() {
+        let device = match DeviceScope::default() {
+            Ok(device) => device,
+            Err(_) => return,
+        };
+        let pipeline = WordlistDedup::new(&device).unwrap();
+
+        let input = b"apple\nbanana\napple\n";
+        let mut output = Vec::new();
+        let unique_lines = pipeline.dedup_sorted(&input[..], &mut output, true).unwrap();
+        assert_eq!(unique_lines, 2);
+        assert_eq!(output, b"apple\t2\nbanana\t1\n");
+    }
+
+    #[test]
+    fn wordlist_dedup_merges_across_small_chunks() {
+        let device = match DeviceScope::default() {
+            Ok(device) => device,
+            Err(_) => return,
+        };
+        let pipeline = WordlistDedup::with_chunk_records(&device, 2).unwrap();
+
+        let input = b"dog\napple\ncat\napple\nbird\ndog\n";
+        let mut output = Vec::new();
+        let unique_lines = pipeline.dedup_sorted(&input[..], &mut output, false).unwrap();
+        assert_eq!(unique_lines, 4);
+        assert_eq!(output, b"apple\nbird\ncat\ndog\n");
+    }
+
+    #[test]
+    fn minhash_similarity_counts_equal_lanes() {
+        let a = [1u32, 2, 3, 4];
+        let b = [1u32, 2, 30, 40];
+        assert_eq!(minhash_similarity(&a, &b), 0.5);
+        assert_eq!(minhash_similarity(&a, &a), 1.0);
+    }
+
+    #[test]
+    fn fingerprints_signature_and_matching_dimensions() {
+        let dimensions = 4;
+        let hashes = [1u32, 2, 3, 4, 1, 2, 30, 40];
+        let first = Fingerprints::signature(&hashes, dimensions, 0);
+        let second = Fingerprints::signature(&hashes, dimensions, 1);
+        assert_eq!(first, &[1, 2, 3, 4]);
+        assert_eq!(second, &[1, 2, 30, 40]);
+        assert_eq!(Fingerprints::matching_dimensions(first, second), 2);
+        assert_eq!(Fingerprints::jaccard_similarity(first, second), 0.5);
+    }
+
+    #[test]
+    fn fingerprints_condense_is_deterministic_and_sensitive_to_input() {
+        let a = [1u32, 2, 3, 4];
+        let b = [1u32, 2, 3, 4];
+        let c = [1u32, 2, 3, 5];
+        assert_eq!(Fingerprints::condense(&a), Fingerprints::condense(&b));
+        assert_ne!(Fingerprints::condense(&a), Fingerprints::condense(&c));
+        assert_ne!(Fingerprints::condense(&a).to_smaller_hash(), 0);
+    }
+
+    #[test]
+    fn content_defined_chunk_offsets_covers_whole_input() {
+        let data = b"the quick brown fox jumps over the lazy dog, again and again and again";
+        let offsets = content_defined_chunk_offsets(data, 16, 4, 64);
+        assert_eq!(offsets[0], 0);
+        assert_eq!(*offsets.last().unwrap(), data.len());
+        assert!(offsets.windows(2).all(|w| w[1] - w[0] <= 64));
+    }
+
+    #[test]
+    fn content_defined_chunk_offsets_are_unaffected_by_later_bytes() {
+        // Content-defined boundaries only depend on the bytes seen so far, so appending more
+        // data must never change a boundary already decided earlier in the stream -- only the
+        // forced end-of-input boundary (the last entry) can move.
+        let full = b"the quick brown fox jumps over the lazy dog, again and again and again";
+        let prefix = &full[..40];
+
+        let prefix_offsets = content_defined_chunk_offsets(prefix, 16, 4, 64);
+        let full_offsets = content_defined_chunk_offsets(full, 16, 4, 64);
+
+        for &offset in &prefix_offsets[..prefix_offsets.len() - 1] {
+            assert!(full_offsets.contains(&offset));
+        }
+    }
+
+    #[test]
+    fn content_defined_chunk_offsets_handles_empty_input() {
+        assert_eq!(content_defined_chunk_offsets(b"", 16, 4, 64), vec![0]);
+    }
+
+    #[test]
+    fn minhash_similarity_handles_empty_signatures() {
+        let a: [u32; 0] = [];
+        assert_eq!(minhash_similarity(&a, &a), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn minhash_similarity_panics_on_mismatched_lengths() {
+        let a = [1u32, 2, 3];
+        let b = [1u32, 2];
+        minhash_similarity(&a, &b);
+    }
+
+    #[test]
+    fn lsh_candidate_pairs_finds_shared_band() {
+        let signatures: Vec<u32> = vec![
+            1, 2, 3, 4, // signature 0
+            1, 2, 9, 9, // signature 1: shares band 0 ([1, 2]) with signature 0
+            9, 9, 7, 8, // signature 2: shares no band with either
+        ];
+        let pairs = lsh_candidate_pairs(&signatures, 3, 4, 2, 0).unwrap();
+        assert_eq!(pairs.len(), 1);
+        assert_eq!((pairs[0].first, pairs[0].second), (0, 1));
+        assert_eq!(pairs[0].similarity, 0.5);
+    }
+
+    #[test]
+    fn lsh_candidate_pairs_deduplicates_across_bands() {
+        // Identical signatures collide in every band; they must still appear only once.
+        let signatures: Vec<u32> = vec![1, 2, 3, 4, 1, 2, 3, 4];
+        let pairs = lsh_candidate_pairs(&signatures, 2, 4, 2, 0).unwrap();
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0].similarity, 1.0);
+    }
+
+    #[test]
+    fn lsh_candidate_pairs_rejects_non_divisible_bands() {
+        let signatures: Vec<u32> = vec![1, 2, 3];
+        let result = lsh_candidate_pairs(&signatures, 1, 3, 2, 0);
+        assert!(matches!(result, Err(Error { status: SzStatus::UnexpectedDimensions, .. })));
+    }
+
+    #[test]
+    fn lsh_candidate_pairs_rejects_mismatched_signature_buffer() {
+        let signatures: Vec<u32> = vec![1, 2, 3, 4];
+        let result = lsh_candidate_pairs(&signatures, 2, 4, 2, 0);
+        assert!(matches!(result, Err(Error { status: SzStatus::UnexpectedDimensions, .. })));
+    }
+
+    #[test]
+    fn fingerprints_builder_rejects_bands_not_dividing_dimensions() {
+        let device = DeviceScope::default().unwrap();
+        let result = Fingerprints::builder().dimensions(10).bands(3).build(&device);
+        assert!(matches!(result, Err(Error { status: SzStatus::UnexpectedDimensions, .. })));
+    }
+
+    #[test]
+    fn fingerprints_candidate_pairs_uses_configured_bands() {
+        let device = DeviceScope::default().unwrap();
+        let engine = Fingerprints::builder().dimensions(4).bands(2).build(&device).unwrap();
+        let signatures: Vec<u32> = vec![
+            1, 2, 3, 4, // signature 0
+            1, 2, 9, 9, // signature 1: shares band 0 with signature 0
+            9, 9, 7, 8, // signature 2: shares no band with either
+        ];
+        let pairs = engine.candidate_pairs(&signatures, 4, 0).unwrap();
+        assert_eq!(pairs.len(), 1);
+        assert_eq!((pairs[0].first, pairs[0].second), (0, 1));
     }
 
     #[test]
@@ -2965,6 +6984,66 @@ mod tests {
         }
     }
 
+    #[test]
+    fn device_set_partitions_match_single_device_results() {
+        let cpu_a = match DeviceScope::cpu_cores(1) {
+            Ok(device) => device,
+            Err(_) => return,
+        };
+        let cpu_b = match DeviceScope::cpu_cores(1) {
+            Ok(device) => device,
+            Err(_) => return,
+        };
+        let reference_device = match DeviceScope::default() {
+            Ok(device) => device,
+            Err(_) => return,
+        };
+
+        let engine = match LevenshteinDistances::new(&reference_device, 0, 1, 1, 1) {
+            Ok(engine) => engine,
+            Err(_) => return,
+        };
+
+        let set = DeviceSet::new(vec![cpu_a, cpu_b]);
+        let strings_a = vec!["kitten", "saturday", "same", "flaw"];
+        let strings_b = vec!["sitting", "sunday", "same", "lawn"];
+
+        let split_distances = set.compute_levenshtein(&engine, &strings_a, &strings_b).unwrap();
+        let reference_distances = engine.compute(&reference_device, &strings_a, &strings_b).unwrap();
+        assert_eq!(&split_distances[..], &reference_distances[..]);
+    }
+
+    #[test]
+    fn device_set_partition_ranges_cover_all_pairs() {
+        let ranges = DeviceSet::partition_ranges(10, &[1.0, 3.0]);
+        assert_eq!(ranges.first().unwrap().0, 0);
+        assert_eq!(ranges.last().unwrap().1, 10);
+        for window in ranges.windows(2) {
+            assert_eq!(window[0].1, window[1].0, "partitions must be contiguous with no gaps");
+        }
+    }
+
+    #[test]
+    fn device_set_empty_batch_returns_empty_results() {
+        let cpu = match DeviceScope::cpu_cores(1) {
+            Ok(device) => device,
+            Err(_) => return,
+        };
+        let reference_device = match DeviceScope::default() {
+            Ok(device) => device,
+            Err(_) => return,
+        };
+        let engine = match LevenshteinDistances::new(&reference_device, 0, 1, 1, 1) {
+            Ok(engine) => engine,
+            Err(_) => return,
+        };
+
+        let set = DeviceSet::new(vec![cpu]);
+        let empty: Vec<&str> = Vec::new();
+        let distances = set.compute_levenshtein(&engine, &empty, &empty).unwrap();
+        assert!(distances.is_empty());
+    }
+
     #[test]
     fn levenshtein_utf8_engine() {
         let device_result = DeviceScope::default();
@@ -2995,6 +7074,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn levenshtein_utf8_compute_bounded_matches_exact_within_threshold() {
+        let device_result = DeviceScope::default();
+        if device_result.is_err() {
+            println!("Skipping bounded Levenshtein test - device initialization failed");
+            return;
+        }
+        let device = device_result.unwrap();
+
+        let engine_result = LevenshteinDistancesUtf8::new(&device, 0, 1, 1, 1);
+        if engine_result.is_err() {
+            println!("Skipping bounded Levenshtein test - engine initialization failed");
+            return;
+        }
+        let engine = engine_result.unwrap();
+
+        let strings_a = vec!["kitten", "hello", "„Åì„Çì„Å´„Å°„ÅØ"];
+        let strings_b = vec!["sitting", "hello", "„Åì„Çì„Å∞„Çì„ÅØ"];
+        let bounded = engine.compute_bounded(&strings_a, &strings_b, 10);
+        assert_eq!(bounded, vec![3, 0, 3]);
+    }
+
+    #[test]
+    fn levenshtein_utf8_compute_bounded_returns_sentinel_past_threshold() {
+        let device_result = DeviceScope::default();
+        if device_result.is_err() {
+            println!("Skipping bounded Levenshtein test - device initialization failed");
+            return;
+        }
+        let device = device_result.unwrap();
+
+        let engine_result = LevenshteinDistancesUtf8::new(&device, 0, 1, 1, 1);
+        if engine_result.is_err() {
+            println!("Skipping bounded Levenshtein test - engine initialization failed");
+            return;
+        }
+        let engine = engine_result.unwrap();
+
+        // "kitten" -> "sitting" has a true distance of 3, so a band of 1 can't reach it.
+        let strings_a = vec!["kitten"];
+        let strings_b = vec!["sitting"];
+        let bounded = engine.compute_bounded(&strings_a, &strings_b, 1);
+        assert_eq!(bounded, vec![2]); // sentinel: max_distance + 1
+    }
+
     #[test]
     fn needleman_wunsch_engine() {
         let device_result = DeviceScope::default();
@@ -3030,6 +7154,67 @@ mod tests {
         }
     }
 
+    #[test]
+    fn needleman_wunsch_compute_alignments_matches_score_and_spans_whole_sequences() {
+        let device = match DeviceScope::default() {
+            Ok(d) => d,
+            Err(_) => return, // skip if device unavailable
+        };
+        let mut matrix = [[-1i8; 256]; 256];
+        for i in 0..256 {
+            matrix[i][i] = 2;
+        }
+        let engine = match NeedlemanWunschScores::new(&device, &matrix, -2, -1) {
+            Ok(e) => e,
+            Err(_) => return,
+        };
+
+        let sequences_a = vec!["kitten"];
+        let sequences_b = vec!["sitting"];
+        let alignments = engine.compute_alignments(&sequences_a, &sequences_b);
+        assert_eq!(alignments.len(), 1);
+
+        let alignment = &alignments[0];
+        assert_eq!(alignment.start_a, 0);
+        assert_eq!(alignment.start_b, 0);
+        assert_eq!(alignment.end_a, "kitten".len());
+        assert_eq!(alignment.end_b, "sitting".len());
+
+        let (mut consumed_a, mut consumed_b) = (0usize, 0usize);
+        for op in &alignment.ops {
+            match *op {
+                AlignOp::Match(n) | AlignOp::Mismatch(n) => {
+                    consumed_a += n;
+                    consumed_b += n;
+                }
+                AlignOp::Delete(n) => consumed_a += n,
+                AlignOp::Insert(n) => consumed_b += n,
+            }
+        }
+        assert_eq!(consumed_a, "kitten".len());
+        assert_eq!(consumed_b, "sitting".len());
+
+        if let Ok(scores) = engine.compute(&device, &sequences_a, &sequences_b) {
+            assert_eq!(scores[0], alignment.score);
+        }
+
+        let mut ranges_consumed_a = 0usize;
+        let mut ranges_consumed_b = 0usize;
+        for (a_range, b_range, op) in alignment.ranges() {
+            match op {
+                AlignOp::Match(_) | AlignOp::Mismatch(_) => {
+                    assert_eq!(a_range.len(), b_range.len());
+                }
+                AlignOp::Delete(_) => assert!(b_range.is_empty()),
+                AlignOp::Insert(_) => assert!(a_range.is_empty()),
+            }
+            ranges_consumed_a = ranges_consumed_a.max(a_range.end);
+            ranges_consumed_b = ranges_consumed_b.max(b_range.end);
+        }
+        assert_eq!(ranges_consumed_a, alignment.end_a);
+        assert_eq!(ranges_consumed_b, alignment.end_b);
+    }
+
     #[test]
     fn smith_waterman_engine() {
         let device_result = DeviceScope::default();
@@ -3065,6 +7250,98 @@ mod tests {
         }
     }
 
+    #[test]
+    fn smith_waterman_compute_alignments_finds_best_local_substring() {
+        let device = match DeviceScope::default() {
+            Ok(d) => d,
+            Err(_) => return, // skip if device unavailable
+        };
+        let mut matrix = [[-1i8; 256]; 256];
+        for i in 0..256 {
+            matrix[i][i] = 2;
+        }
+        let engine = match SmithWatermanScores::new(&device, &matrix, -5, -2) {
+            Ok(e) => e,
+            Err(_) => return,
+        };
+
+        let sequences_a = vec!["XXXXACGTACGTXXXX"];
+        let sequences_b = vec!["ACGTACGT"];
+        let alignments = engine.compute_alignments(&sequences_a, &sequences_b);
+        assert_eq!(alignments.len(), 1);
+
+        let alignment = &alignments[0];
+        assert_eq!(alignment.start_a, 4);
+        assert_eq!(alignment.end_a, 12);
+        assert_eq!(alignment.start_b, 0);
+        assert_eq!(alignment.end_b, 8);
+        assert_eq!(alignment.ops, vec![AlignOp::Match(8)]);
+    }
+
+    #[test]
+    fn translate_six_frames_covers_forward_and_reverse_frames() {
+        // "ATG GCA TAA" -> Met-Ala-Stop on the forward, frame-1 reading.
+        let code = GeneticCode::standard();
+        let frames = translate_six_frames(b"ATGGCATAA", &code);
+        assert_eq!(frames[0], TranslatedFrame { frame: 1, protein: b"MA*".to_vec() });
+        // Frame 2 drops the leading base and reads "TGG CAT AA" (trailing "AA" incomplete).
+        assert_eq!(frames[1], TranslatedFrame { frame: 2, protein: b"WH".to_vec() });
+        // Reverse complement of "ATGGCATAA" is "TTATGCCAT"; frame -1 reads "TTA TGC CAT".
+        assert_eq!(frames[3].frame, -1);
+        assert_eq!(frames[3].protein, b"LCH".to_vec());
+    }
+
+    #[test]
+    fn needleman_wunsch_compute_translated_alignments_picks_best_frame() {
+        let device = match DeviceScope::default() {
+            Ok(d) => d,
+            Err(_) => return, // skip if device unavailable
+        };
+        let matrix = SubstitutionMatrix::identity(2, -1);
+        let engine = match NeedlemanWunschScores::new(&device, &matrix, -2, -1) {
+            Ok(e) => e,
+            Err(_) => return,
+        };
+
+        let code = GeneticCode::standard();
+        let dna_queries = vec!["ATGGCATAA".to_string()];
+        let protein_references = vec!["MA*".to_string()];
+        let alignments = engine.compute_translated_alignments(&dna_queries, &protein_references, &code);
+        assert_eq!(alignments.len(), 1);
+        assert_eq!(alignments[0].frame, 1);
+        assert_eq!(alignments[0].alignment.ops, vec![AlignOp::Match(3)]);
+    }
+
+    #[test]
+    fn needleman_wunsch_semi_global_a_fits_probe_into_reference() {
+        let device = match DeviceScope::default() {
+            Ok(d) => d,
+            Err(_) => return, // skip if device unavailable
+        };
+        let mut matrix = [[-1i8; 256]; 256];
+        for i in 0..256 {
+            matrix[i][i] = 2;
+        }
+        let engine = match NeedlemanWunschScores::with_mode(&device, &matrix, -5, -2, AlignmentMode::SemiGlobalA) {
+            Ok(e) => e,
+            Err(_) => return,
+        };
+
+        // `a` is a long reference with the short probe `b` embedded in the middle; SemiGlobalA
+        // frees `a`'s leading/trailing ends so the probe doesn't get penalized for not spanning it.
+        let sequences_a = vec!["XXXXACGTACGTXXXX"];
+        let sequences_b = vec!["ACGTACGT"];
+        let alignments = engine.compute_alignments(&sequences_a, &sequences_b);
+        assert_eq!(alignments.len(), 1);
+
+        let alignment = &alignments[0];
+        assert_eq!(alignment.start_a, 4);
+        assert_eq!(alignment.end_a, 12);
+        assert_eq!(alignment.start_b, 0);
+        assert_eq!(alignment.end_b, "ACGTACGT".len());
+        assert_eq!(alignment.ops, vec![AlignOp::Match(8)]);
+    }
+
     #[test]
     fn unified_allocator() {
         // Test basic allocation
@@ -3113,6 +7390,75 @@ mod tests {
         }
     }
 
+    #[test]
+    fn metal_device_reports_as_gpu() {
+        match DeviceScope::metal_device(0) {
+            Ok(device) => {
+                assert!(device.is_gpu());
+                assert_eq!(device.get_gpu_device().unwrap(), 0);
+            }
+            Err(e) => println!("Skipping Metal test - device unavailable: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn metal_device_accepts_unified_tapes_zero_copy() {
+        // Apple Silicon GPUs share unified memory with the CPU, so UnifiedAlloc-backed tapes
+        // should be usable directly by the *_u32tape entry points with no extra host-device copy.
+        let device = match DeviceScope::metal_device(0) {
+            Ok(device) => device,
+            Err(_) => return,
+        };
+        let engine = match LevenshteinDistances::new(&device, 0, 1, 1, 1) {
+            Ok(engine) => engine,
+            Err(_) => return,
+        };
+
+        let a = [b"kitten".as_ref(), b"saturday".as_ref()];
+        let b = [b"sitting".as_ref(), b"sunday".as_ref()];
+
+        let mut tape_a = BytesTape::<u32, UnifiedAlloc>::new_in(UnifiedAlloc);
+        tape_a.extend(a).unwrap();
+        let mut tape_b = BytesTape::<u32, UnifiedAlloc>::new_in(UnifiedAlloc);
+        tape_b.extend(b).unwrap();
+
+        let mut results: UnifiedVec<usize> = UnifiedVec::with_capacity_in(2, UnifiedAlloc);
+        results.resize(2, 0);
+
+        let result = engine.compute_into(&device, AnyBytesTape::Tape32(tape_a), AnyBytesTape::Tape32(tape_b), &mut results);
+        if result.is_ok() {
+            assert_eq!(&results[..], &[3, 3]);
+        }
+    }
+
+    #[test]
+    fn enumerate_lists_at_least_the_cpu_backend() {
+        let descriptors = match DeviceScope::enumerate() {
+            Ok(descriptors) => descriptors,
+            Err(e) => {
+                println!("Skipping enumerate test - enumeration failed: {:?}", e);
+                return;
+            }
+        };
+        assert!(!descriptors.is_empty(), "enumerate should report at least the CPU backend");
+        assert!(descriptors.iter().any(|descriptor| descriptor.kind == DeviceKind::CpuCores));
+    }
+
+    #[test]
+    fn from_descriptor_round_trips_a_cpu_descriptor() {
+        let descriptors = match DeviceScope::enumerate() {
+            Ok(descriptors) => descriptors,
+            Err(_) => return,
+        };
+        let cpu_descriptor = match descriptors.iter().find(|descriptor| descriptor.kind == DeviceKind::CpuCores) {
+            Some(descriptor) => descriptor,
+            None => return,
+        };
+
+        let device = DeviceScope::from_descriptor(cpu_descriptor).unwrap();
+        assert!(!device.is_gpu());
+    }
+
     #[test]
     fn thread_safety() {
         use std::sync::Arc;
@@ -3285,6 +7631,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn error_costs_preset_matches_substitution_matrix() {
+        let blosum62 = error_costs_256x256_preset(SubstitutionMatrixPreset::Blosum62, -128);
+        assert_eq!(blosum62, *SubstitutionMatrix::blosum62());
+        assert_eq!(blosum62[b'A' as usize][b'A' as usize], 4);
+
+        let blosum45 = error_costs_256x256_preset(SubstitutionMatrixPreset::Blosum45, -1);
+        assert_eq!(blosum45[b'C' as usize][b'C' as usize], 12);
+        assert_eq!(blosum45[0][0], -1, "off-alphabet byte pairs should use the override score");
+
+        let pam250 = error_costs_256x256_preset(SubstitutionMatrixPreset::Pam250, -128);
+        assert_eq!(pam250, *SubstitutionMatrix::pam250());
+
+        let dna_iupac = error_costs_256x256_preset(SubstitutionMatrixPreset::DnaIupac, -128);
+        assert_eq!(dna_iupac, *SubstitutionMatrix::nuc44());
+    }
+
+    #[test]
+    fn fingerprints_similarity_matrix_and_nearest_neighbors() {
+        let device_result = DeviceScope::default();
+        if device_result.is_err() {
+            println!("Skipping fingerprints similarity test - device initialization failed");
+            return;
+        }
+        let device = device_result.unwrap();
+
+        let engine_result = Fingerprints::builder().dimensions(64).build(&device);
+        if engine_result.is_err() {
+            println!("Skipping fingerprints similarity test - engine initialization failed");
+            return;
+        }
+        let engine = engine_result.unwrap();
+
+        let corpus = vec!["the quick brown fox", "the quick brown fox", "completely different"];
+        let result = engine.compute(&device, &corpus, 64);
+        let (hashes, _counts) = match result {
+            Ok(pair) => pair,
+            Err(e) => {
+                println!("Skipping fingerprints similarity test - compute failed: {:?}", e);
+                return;
+            }
+        };
+
+        let matrix = engine.similarity_matrix(&device, &hashes, 64).unwrap();
+        assert_eq!(matrix.len(), corpus.len() * corpus.len());
+        assert_eq!(matrix[0 * corpus.len() + 0], 1.0);
+        assert_eq!(matrix[0 * corpus.len() + 1], matrix[1 * corpus.len() + 0]);
+        assert!(matrix[0 * corpus.len() + 1] >= matrix[0 * corpus.len() + 2]);
+
+        let query = Fingerprints::signature(&hashes, 64, 0).to_vec();
+        let neighbors = engine.nearest_neighbors(&device, &query, &hashes, 64, 2).unwrap();
+        assert_eq!(neighbors.len(), 2);
+        assert_eq!(neighbors[0].0, 0, "the query itself should be its own closest neighbor");
+        assert!(neighbors[0].1 >= neighbors[1].1);
+    }
+
     #[test]
     fn levenshtein_compute_into_u32_bytes() {
         let device = match DeviceScope::default() {
@@ -3351,4 +7753,125 @@ mod tests {
             assert_eq!(&results[..], &[2, 1]);
         }
     }
+
+    #[test]
+    fn levenshtein_broadcast_into_scores_query_against_every_reference() {
+        let device = match DeviceScope::default() {
+            Ok(d) => d,
+            Err(_) => return, // skip if device unavailable
+        };
+        let engine = match LevenshteinDistances::new(&device, 0, 1, 1, 1) {
+            Ok(e) => e,
+            Err(_) => return,
+        };
+
+        let mut query_tape = BytesTape::<u32, UnifiedAlloc>::new_in(UnifiedAlloc);
+        query_tape.extend([b"kitten".as_ref()]).unwrap();
+        let query = AnyBytesTape::Tape32(query_tape);
+
+        let mut reference_a = BytesTape::<u32, UnifiedAlloc>::new_in(UnifiedAlloc);
+        reference_a.extend([b"sitting".as_ref()]).unwrap();
+        let mut reference_b = BytesTape::<u32, UnifiedAlloc>::new_in(UnifiedAlloc);
+        reference_b.extend([b"kitten".as_ref()]).unwrap();
+        let references = [AnyBytesTape::Tape32(reference_a), AnyBytesTape::Tape32(reference_b)];
+
+        let devices = [device];
+        let res = engine.broadcast_into(&devices, &query, &references);
+        if let Ok(columns) = res {
+            assert_eq!(columns.len(), 2);
+            assert_eq!(&columns[0][..], &[3]);
+            assert_eq!(&columns[1][..], &[0], "identical strings should be zero distance apart");
+        }
+    }
+
+    #[test]
+    fn levenshtein_submit_into_matches_compute_into() {
+        let device = match DeviceScope::default() {
+            Ok(d) => d,
+            Err(_) => return, // skip if device unavailable
+        };
+        let engine = match LevenshteinDistances::new(&device, 0, 1, 1, 1) {
+            Ok(e) => e,
+            Err(_) => return,
+        };
+
+        let a = [b"kitten".as_ref(), b"saturday".as_ref()];
+        let b = [b"sitting".as_ref(), b"sunday".as_ref()];
+
+        let mut ta = BytesTape::<u32, UnifiedAlloc>::new_in(UnifiedAlloc);
+        ta.extend(a).unwrap();
+        let mut tb = BytesTape::<u32, UnifiedAlloc>::new_in(UnifiedAlloc);
+        tb.extend(b).unwrap();
+
+        let mut results: UnifiedVec<usize> = UnifiedVec::with_capacity_in(2, UnifiedAlloc);
+        results.resize(2, 0);
+
+        let completion = engine.submit_into(
+            &device,
+            AnyBytesTape::Tape32(ta),
+            AnyBytesTape::Tape32(tb),
+            &mut results,
+        );
+        let mut completion = match completion {
+            Ok(c) => c,
+            Err(_) => return,
+        };
+        let res = completion.wait();
+        drop(completion);
+        if let Ok(()) = res {
+            assert_eq!(&results[..], &[3, 3]);
+        }
+    }
+
+    #[test]
+    fn pooled_alloc_host_pageable_round_trips_allocation() {
+        let device = match DeviceScope::default() {
+            Ok(d) => d,
+            Err(_) => return, // skip if device unavailable
+        };
+        let pool = device.allocator(AllocationMode::HostPageable).unwrap();
+        let layout = std::alloc::Layout::from_size_align(128, 8).unwrap();
+
+        let block = pool.allocate(layout).unwrap();
+        assert_eq!(block.len(), layout.size());
+        unsafe { pool.deallocate(block.cast(), layout) };
+
+        // The freed block should be handed back out for a same-class request.
+        let block_again = pool.allocate(layout).unwrap();
+        assert_eq!(block_again.len(), layout.size());
+        unsafe { pool.deallocate(block_again.cast(), layout) };
+    }
+
+    #[test]
+    fn pooled_alloc_pinned_requires_gpu() {
+        let cpu = match DeviceScope::cpu_cores(1) {
+            Ok(d) => d,
+            Err(_) => return, // skip if device unavailable
+        };
+        if cpu.is_gpu() {
+            return; // unexpected on this host, nothing to assert
+        }
+        let err = cpu.allocator(AllocationMode::Pinned).unwrap_err();
+        assert_eq!(err.status, SzStatus::MissingGpu);
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn tape_writer_assembles_entry_from_fragments() {
+        use bytes::{Buf, BufMut};
+
+        let mut tape = BytesTape::<u32, UnifiedAlloc>::new_in(UnifiedAlloc);
+        tape.extend(&["prefix"]).unwrap();
+
+        let mut writer = tape.begin_entry();
+        writer.put_slice(b"hello, ");
+        writer.put_slice(b"world");
+        writer.put_bytes(b'!', 3);
+        writer.commit().unwrap();
+
+        let tape = AnyBytesTape::Tape32(tape);
+        let entry = tape.entry_as_buf(1).unwrap();
+        assert_eq!(entry.chunk(), b"hello, world!!!");
+        assert!(tape.entry_as_buf(2).is_none());
+    }
 }